@@ -0,0 +1,362 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::{ClockDivider, ClockDomain, ClockSource, Pll, Source};
+use crate::regex;
+use crate::util::{text_source, RegexMap};
+
+const MHZ: u32 = 1_000_000;
+
+/// PLL input and output ranges of a family, from the `BSP_FEATURE_CGC_PLL_*` defines in its
+/// `bsp_feature.h`.
+#[derive(Debug, Clone, Default)]
+pub struct ClockInfo {
+    pub pll_input: (Option<u32>, Option<u32>),
+    pub pll_output: (Option<u32>, Option<u32>),
+    pub source: Option<Source>,
+}
+
+/// What the register data and `bsp_feature.h` don't say, after the clock and electrical
+/// characteristics chapters of the hardware manuals.
+pub(crate) struct ClockLimits {
+    /// Frequencies HOCO can be set to in `OFS1.HOCOFRQ`.
+    pub hoco: &'static [u32],
+    /// Maximum frequency of each domain.
+    pub max: &'static [(&'static str, u32)],
+    /// PLL multiplication range, if known. How `PLLCCR.PLLMUL` encodes it differs between families.
+    pub pll_multiplier: Option<(f64, f64)>,
+}
+
+const HOCO_16_20: &[u32] = &[16 * MHZ, 18 * MHZ, 20 * MHZ];
+const HOCO_24_64: &[u32] = &[24 * MHZ, 32 * MHZ, 48 * MHZ, 64 * MHZ];
+
+pub(crate) static CLOCK_LIMITS: RegexMap<ClockLimits> = RegexMap::new(&[
+    (
+        "R7FA6M[45].*|R7FA6E1.*",
+        ClockLimits {
+            hoco: HOCO_16_20,
+            max: &[
+                ("ICLK", 200 * MHZ),
+                ("PCLKA", 100 * MHZ),
+                ("PCLKB", 50 * MHZ),
+                ("PCLKC", 50 * MHZ),
+                ("PCLKD", 100 * MHZ),
+                ("BCLK", 100 * MHZ),
+                ("FCLK", 50 * MHZ),
+            ],
+            pll_multiplier: Some((10.0, 30.0)),
+        },
+    ),
+    (
+        "R7FA4M[23].*|R7FA4E1.*",
+        ClockLimits {
+            hoco: HOCO_16_20,
+            max: &[
+                ("ICLK", 100 * MHZ),
+                ("PCLKA", 100 * MHZ),
+                ("PCLKB", 50 * MHZ),
+                ("PCLKC", 50 * MHZ),
+                ("PCLKD", 100 * MHZ),
+                ("FCLK", 50 * MHZ),
+            ],
+            pll_multiplier: Some((10.0, 30.0)),
+        },
+    ),
+    (
+        "R7FA6M[123].*|R7FA6T1.*",
+        ClockLimits {
+            hoco: HOCO_16_20,
+            max: &[
+                ("ICLK", 120 * MHZ),
+                ("PCLKA", 120 * MHZ),
+                ("PCLKB", 60 * MHZ),
+                ("PCLKC", 60 * MHZ),
+                ("PCLKD", 120 * MHZ),
+                ("BCLK", 120 * MHZ),
+                ("FCLK", 60 * MHZ),
+                ("UCLK", 48 * MHZ),
+            ],
+            pll_multiplier: Some((10.0, 30.0)),
+        },
+    ),
+    (
+        "R7FA4M1.*|R7FA4W1.*",
+        ClockLimits {
+            hoco: HOCO_24_64,
+            max: &[
+                ("ICLK", 48 * MHZ),
+                ("PCLKA", 48 * MHZ),
+                ("PCLKB", 32 * MHZ),
+                ("PCLKC", 64 * MHZ),
+                ("PCLKD", 64 * MHZ),
+                ("FCLK", 32 * MHZ),
+            ],
+            pll_multiplier: None,
+        },
+    ),
+    (
+        "R7FA2(?:A1|E1|E2|L1|T1).*",
+        ClockLimits {
+            hoco: HOCO_24_64,
+            max: &[("ICLK", 48 * MHZ), ("PCLKB", 32 * MHZ), ("PCLKD", 64 * MHZ), ("FCLK", 32 * MHZ)],
+            pll_multiplier: None,
+        },
+    ),
+    (
+        "R7FA8(?:D1|M1|T1).*",
+        ClockLimits {
+            hoco: &[16 * MHZ, 18 * MHZ, 20 * MHZ, 24 * MHZ, 32 * MHZ, 48 * MHZ],
+            max: &[
+                ("CPUCLK", 480 * MHZ),
+                ("ICLK", 240 * MHZ),
+                ("PCLKA", 120 * MHZ),
+                ("PCLKB", 60 * MHZ),
+                ("PCLKC", 60 * MHZ),
+                ("PCLKD", 120 * MHZ),
+                ("PCLKE", 240 * MHZ),
+                ("BCLK", 120 * MHZ),
+                ("FCLK", 60 * MHZ),
+            ],
+            pll_multiplier: None,
+        },
+    ),
+]);
+
+/// The clock domain each peripheral runs on, by `CHIP:PERIPHERAL` as for `PERIMAP`. Keyed per
+/// family from the hardware manuals, for every family `PERIMAP` covers, so a new family gets a
+/// warning and no domain until its manual has been checked. On all of these, GPT counts PCLKD
+/// (its core clock), the system registers are on the CPU bus and ELC and the ports are on PCLKB.
+/// SCI is on PCLKA, or on PCLKB on RA2, which has no PCLKA.
+pub(crate) static PERIPHERAL_CLOCKS: RegexMap<&str> = RegexMap::new(&[
+    // RA6M4, RA6M5, RA6E1
+    ("(?:R7FA6M[45]|R7FA6E1).*:GPT.*", "PCLKD"),
+    ("(?:R7FA6M[45]|R7FA6E1).*:SCI\\d+", "PCLKA"),
+    ("(?:R7FA6M[45]|R7FA6E1).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA6M[45]|R7FA6E1).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA4M2, RA4M3, RA4E1
+    ("(?:R7FA4M[23]|R7FA4E1).*:GPT.*", "PCLKD"),
+    ("(?:R7FA4M[23]|R7FA4E1).*:SCI\\d+", "PCLKA"),
+    ("(?:R7FA4M[23]|R7FA4E1).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA4M[23]|R7FA4E1).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA6M1, RA6M2, RA6M3, RA6T1
+    ("(?:R7FA6M[123]|R7FA6T1).*:GPT.*", "PCLKD"),
+    ("(?:R7FA6M[123]|R7FA6T1).*:SCI\\d+", "PCLKA"),
+    ("(?:R7FA6M[123]|R7FA6T1).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA6M[123]|R7FA6T1).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA4M1, RA4W1
+    ("(?:R7FA4M1|R7FA4W1).*:GPT.*", "PCLKD"),
+    ("(?:R7FA4M1|R7FA4W1).*:SCI\\d+", "PCLKA"),
+    ("(?:R7FA4M1|R7FA4W1).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA4M1|R7FA4W1).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA2A1, RA2E1, RA2E2, RA2L1, RA2T1
+    ("(?:R7FA2(?:A1|E1|E2|L1|T1)).*:GPT.*", "PCLKD"),
+    ("(?:R7FA2(?:A1|E1|E2|L1|T1)).*:SCI\\d+", "PCLKB"),
+    ("(?:R7FA2(?:A1|E1|E2|L1|T1)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA2(?:A1|E1|E2|L1|T1)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA8D1, RA8M1, RA8T1
+    ("(?:R7FA8(?:D1|M1|T1)).*:GPT.*", "PCLKD"),
+    ("(?:R7FA8(?:D1|M1|T1)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA8(?:D1|M1|T1)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA2A2, RA2E3, RA2L2
+    ("(?:R7FA2(?:A2|E3|L2)).*:GPT.*", "PCLKD"),
+    ("(?:R7FA2(?:A2|E3|L2)).*:SCI\\d+", "PCLKB"),
+    ("(?:R7FA2(?:A2|E3|L2)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA2(?:A2|E3|L2)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA4C1, RA4E2, RA4L1, RA4T1
+    ("(?:R7FA4(?:C1|E2|L1|T1)).*:GPT.*", "PCLKD"),
+    ("(?:R7FA4(?:C1|E2|L1|T1)).*:SCI\\d+", "PCLKA"),
+    ("(?:R7FA4(?:C1|E2|L1|T1)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA4(?:C1|E2|L1|T1)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA6E2, RA6T2, RA6T3
+    ("(?:R7FA6(?:E2|T2|T3)).*:GPT.*", "PCLKD"),
+    ("(?:R7FA6(?:E2|T2|T3)).*:SCI\\d+", "PCLKA"),
+    ("(?:R7FA6(?:E2|T2|T3)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA6(?:E2|T2|T3)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA8E1, RA8E2
+    ("(?:R7FA8E[12]).*:GPT.*", "PCLKD"),
+    ("(?:R7FA8E[12]).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA8E[12]).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA8D2, RA8M2, RA8P1, RA8T2
+    ("(?:R7KA8(?:D2|M2|P1|T2)).*:GPT.*", "PCLKD"),
+    ("(?:R7KA8(?:D2|M2|P1|T2)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7KA8(?:D2|M2|P1|T2)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+    // RA0E1, RA0E2, RA0L1, which have no GPT
+    ("(?:R7FA0(?:E1|E2|L1)).*:(?:SYSC|MSTP|ICU)", "ICLK"),
+    ("(?:R7FA0(?:E1|E2|L1)).*:(?:ELC|PFS|PORT[0-9A-F])", "PCLKB"),
+]);
+
+pub(crate) fn rule_source(table: &str, rule: &str, key: &str) -> Source {
+    Source {
+        file: file!().to_string(),
+        location: None,
+        line: None,
+        rule: Some(format!("{table} {rule:?} matched {key:?}")),
+    }
+}
+
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<BTreeMap<String, ClockInfo>> {
+    let mut family_clocks = BTreeMap::new();
+    let mcu_dir = sources.join("bsp/mcu");
+
+    for entry in fs::read_dir(&mcu_dir).context("failed to read mcu directory")? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            let family_name = path.file_name().unwrap().to_string_lossy().to_string();
+            let feature_h = path.join("bsp_feature.h");
+            if feature_h.exists() {
+                if let Some(clocks) = diagnostics.ok(parse_feature_h(&feature_h), |e| Diagnostic::error(e).file(&feature_h)) {
+                    family_clocks.insert(family_name, clocks);
+                }
+            }
+        }
+    }
+
+    Ok(family_clocks)
+}
+
+fn parse_feature_h(path: &Path) -> anyhow::Result<ClockInfo> {
+    let content = fs::read_to_string(path)?;
+    let mut info = ClockInfo::default();
+
+    let re = regex!(r"#define\s+BSP_FEATURE_CGC_PLL_(SRC|OUT)_(MIN|MAX)_HZ\s+\(([0-9]+)U?\)");
+    for cap in re.captures_iter(&content) {
+        // Families without a PLL define these as 0.
+        let value: u32 = cap[3].parse()?;
+        if value == 0 {
+            continue;
+        }
+        let range = if &cap[1] == "SRC" { &mut info.pll_input } else { &mut info.pll_output };
+        if &cap[2] == "MIN" {
+            range.0 = Some(value);
+        } else {
+            range.1 = Some(value);
+        }
+        let offset = cap.get(0).unwrap().start();
+        info.source.get_or_insert_with(|| text_source(path, &content, offset).with_rule("BSP_FEATURE_CGC_PLL_{SRC,OUT}_{MIN,MAX}_HZ"));
+    }
+
+    Ok(info)
+}
+
+fn fields<'a>(sysc: &'a serde_yaml::Value, fieldset: &str) -> impl Iterator<Item = &'a serde_yaml::Value> {
+    sysc.get(format!("fieldset/{fieldset}"))
+        .and_then(|f| f.get("fields"))
+        .and_then(|f| f.as_sequence())
+        .into_iter()
+        .flatten()
+}
+
+fn str_of<'a>(value: &'a serde_yaml::Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(|v| v.as_str())
+}
+
+/// `(value, description)` of each variant of `enum/<name>`.
+fn enum_variants<'a>(sysc: &'a serde_yaml::Value, name: &str) -> Vec<(u32, &'a str)> {
+    let variants = sysc.get(format!("enum/{name}")).and_then(|e| e.get("variants")).and_then(|v| v.as_sequence());
+    variants
+        .into_iter()
+        .flatten()
+        .filter_map(|v| Some((v.get("value")?.as_u64()?.try_into().ok()?, str_of(v, "description").unwrap_or(""))))
+        .collect()
+}
+
+/// Division ratios from variant descriptions like `x 1/4.` or `/3.`.
+fn dividers(sysc: &serde_yaml::Value, enum_name: &str) -> Vec<ClockDivider> {
+    enum_variants(sysc, enum_name)
+        .into_iter()
+        .filter_map(|(value, description)| {
+            let divider = regex!(r"^(?:x\s*1/|/)([0-9]+)\b").captures(description.trim())?[1].parse().ok()?;
+            Some(ClockDivider { value, divider })
+        })
+        .collect()
+}
+
+/// `SCKSCR.CKSEL` values, the same on every RA part that has the source.
+const SOURCES: &[(&str, u32)] = &[("HOCO", 0), ("MOCO", 1), ("LOCO", 2), ("MOSC", 3), ("SOSC", 4), ("PLL", 5)];
+
+/// System clock sources, from the `CKSEL` enum of the SYSC block; blocks without one are assumed
+/// to have every source but the PLL, if they have no `PLLCCR`.
+pub(crate) fn sources(sysc: &serde_yaml::Value, hoco: &[u32]) -> Vec<ClockSource> {
+    let described: Vec<(&str, u32)> = enum_variants(sysc, "CKSEL")
+        .into_iter()
+        .filter_map(|(value, description)| {
+            let name = match description {
+                d if d.starts_with("HOCO") => "HOCO",
+                d if d.starts_with("MOCO") => "MOCO",
+                d if d.starts_with("LOCO") => "LOCO",
+                d if d.starts_with("Main clock oscillator") => "MOSC",
+                d if d.starts_with("Sub-clock oscillator") => "SOSC",
+                d if d.starts_with("PLL") => "PLL",
+                _ => return None,
+            };
+            Some((name, value))
+        })
+        .collect();
+    let has_pll = sysc.get("fieldset/PLLCCR").is_some();
+    let known = if described.is_empty() {
+        SOURCES.iter().copied().filter(|(name, _)| *name != "PLL" || has_pll).collect()
+    } else {
+        described
+    };
+
+    known
+        .into_iter()
+        .map(|(name, select)| ClockSource {
+            name: name.to_string(),
+            select,
+            frequencies: match name {
+                "HOCO" => hoco.to_vec(),
+                "MOCO" => vec![8 * MHZ],
+                "LOCO" | "SOSC" => vec![32_768],
+                _ => Vec::new(),
+            },
+        })
+        .collect()
+}
+
+/// Clocks divided down from the system clock by the fields of `SCKDIVCR` and `SCKDIVCR2`, named
+/// after the field: `ICK` divides ICLK, `PCKA` PCLKA.
+pub(crate) fn domains(sysc: &serde_yaml::Value, max: &[(&str, u32)]) -> Vec<ClockDomain> {
+    let mut domains = Vec::new();
+    for register in ["SCKDIVCR", "SCKDIVCR2"] {
+        for field in fields(sysc, register) {
+            let Some(field_name) = str_of(field, "name") else { continue };
+            let Some(cap) = regex!(r"^([A-Z]*)CK([A-Z0-9]?)$").captures(field_name) else { continue };
+            let dividers = str_of(field, "enum").map(|e| dividers(sysc, e)).unwrap_or_default();
+            if dividers.is_empty() {
+                continue;
+            }
+            let name = format!("{}CLK{}", &cap[1], &cap[2]);
+            domains.push(ClockDomain {
+                max_frequency: max.iter().find(|(domain, _)| *domain == name).map(|(_, hz)| *hz),
+                name,
+                register: register.to_string(),
+                field: field_name.to_string(),
+                dividers,
+            });
+        }
+    }
+    domains.sort_by(|a, b| a.name.cmp(&b.name));
+    domains
+}
+
+pub(crate) fn pll(sysc: &serde_yaml::Value, info: Option<&ClockInfo>, limits: Option<&ClockLimits>) -> Pll {
+    let input_dividers = fields(sysc, "PLLCCR")
+        .find(|f| str_of(f, "name") == Some("PLIDIV"))
+        .and_then(|f| str_of(f, "enum"))
+        .map(|e| dividers(sysc, e))
+        .unwrap_or_default();
+    let multiplier = limits.and_then(|l| l.pll_multiplier);
+    Pll {
+        input_dividers,
+        input_min_frequency: info.and_then(|i| i.pll_input.0),
+        input_max_frequency: info.and_then(|i| i.pll_input.1),
+        output_min_frequency: info.and_then(|i| i.pll_output.0),
+        output_max_frequency: info.and_then(|i| i.pll_output.1),
+        multiplier_min: multiplier.map(|m| m.0),
+        multiplier_max: multiplier.map(|m| m.1),
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::Source;
+use crate::regex;
+use crate::util::{xml_source, RegexMap};
+
+#[derive(Debug, Clone)]
+pub struct DmaInfo {
+    pub dtc: bool,
+    pub dmac_channels: u32,
+    pub source: Source,
+}
+
+/// Which controllers an event can activate.
+pub(crate) struct DmaTrigger {
+    pub dtc: bool,
+    pub dmac: bool,
+}
+
+/// Events that can't activate the DTC and/or DMAC, after the DTC and DMAC columns of the
+/// interrupt event tables in the hardware manuals. Every other event can activate both.
+pub(crate) static DMA_TRIGGERS: RegexMap<DmaTrigger> = RegexMap::new(&[
+    // Transfer end of a DMAC channel can chain into the DTC, but not into another channel.
+    ("DMAC\\d+_INT", DmaTrigger { dtc: true, dmac: false }),
+    ("DTC_.*", DmaTrigger { dtc: false, dmac: false }),
+    // Error and status interrupts are serviced by the CPU.
+    (".*_(?:ERI|EEI|SPEI|SPII|TEI)", DmaTrigger { dtc: false, dmac: false }),
+    (".*_ERR(?:OR)?(?:_.*)?", DmaTrigger { dtc: false, dmac: false }),
+    ("LVD_.*", DmaTrigger { dtc: false, dmac: false }),
+    ("ICU_SNOOZE_CANCEL", DmaTrigger { dtc: false, dmac: false }),
+    ("FCU_.*", DmaTrigger { dtc: false, dmac: false }),
+]);
+
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<BTreeMap<String, DmaInfo>> {
+    let mut chip_dma = BTreeMap::new();
+    let svd_dir = sources.join("svd");
+
+    for entry in fs::read_dir(&svd_dir).context("failed to read svd directory")? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "svd") {
+            let chip_name = path.file_stem().unwrap().to_string_lossy().to_string();
+            if let Some(dma) = diagnostics.ok(parse_svd(&path), |e| Diagnostic::error(e).file(&path)) {
+                chip_dma.insert(chip_name, dma);
+            }
+        }
+    }
+
+    Ok(chip_dma)
+}
+
+/// Looks for a `DTC` peripheral and counts the `DMACn` channel peripherals.
+fn parse_svd(path: &Path) -> anyhow::Result<DmaInfo> {
+    let content = fs::read_to_string(path)?;
+    let doc = roxmltree::Document::parse(&content)?;
+
+    let mut dtc = false;
+    let mut dmac_channels = 0;
+    let mut first = None;
+    for peri in doc.descendants().filter(|n| n.has_tag_name("peripheral")) {
+        let name = peri.children().find(|n| n.has_tag_name("name")).and_then(|n| n.text()).unwrap_or("");
+        if name == "DTC" {
+            dtc = true;
+        } else if regex!(r"^DMAC[0-9]+$").is_match(name) {
+            dmac_channels += 1;
+        } else {
+            continue;
+        }
+        first.get_or_insert(peri);
+    }
+    let source = match first {
+        Some(peri) => xml_source(path, peri).with_rule("DTC peripheral, DMACn peripherals counted"),
+        None => xml_source(path, doc.root_element()).with_rule("no DTC or DMACn peripherals"),
+    };
+
+    Ok(DmaInfo { dtc, dmac_channels, source })
+}
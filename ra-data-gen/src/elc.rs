@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::{ElcDestination, ElcEvent, Source};
+use crate::regex;
+use crate::util::text_source;
+
+/// The ELC events and destinations of a family, from `elc_event_t` and `elc_peripheral_t`.
+#[derive(Debug, Clone, Default)]
+pub struct ElcInfo {
+    pub events: Vec<(ElcEvent, Source)>,
+    pub destinations: Vec<(ElcDestination, Source)>,
+}
+
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<BTreeMap<String, ElcInfo>> {
+    let mut family_elc = BTreeMap::new();
+    let mcu_dir = sources.join("bsp/mcu");
+
+    for entry in fs::read_dir(&mcu_dir).context("failed to read mcu directory")? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            let family_name = path.file_name().unwrap().to_string_lossy().to_string();
+            let elc_h = path.join("bsp_elc.h");
+            if elc_h.exists() {
+                if let Some(elc) = diagnostics.ok(parse_elc_h(&elc_h), |e| Diagnostic::error(e).file(&elc_h)) {
+                    family_elc.insert(family_name, elc);
+                }
+            }
+        }
+    }
+
+    Ok(family_elc)
+}
+
+fn parse_value(value: &str) -> anyhow::Result<u32> {
+    Ok(match value.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16)?,
+        None => value.parse()?,
+    })
+}
+
+fn parse_elc_h(path: &Path) -> anyhow::Result<ElcInfo> {
+    let content = fs::read_to_string(path)?;
+    let mut elc = ElcInfo::default();
+
+    let re_event = regex!(r"ELC_EV(?:E)?NT_([A-Z0-9_]+)\s*=\s*\((0x[0-9A-F]+|[0-9]+)\),?\s*//\s*(.*)");
+    for cap in re_event.captures_iter(&content) {
+        let name = cap[1].to_string();
+        if name == "NONE" || elc.events.iter().any(|(e, _)| e.name == name) {
+            continue;
+        }
+        let offset = cap.get(0).unwrap().start();
+        elc.events.push((
+            ElcEvent {
+                name,
+                value: parse_value(&cap[2])?,
+                description: Some(cap[3].trim().to_string()),
+            },
+            text_source(path, &content, offset).with_rule("ELC_EVENT"),
+        ));
+    }
+
+    let re_destination = regex!(r"ELC_PERIPHERAL_([A-Z0-9_]+)\s*=\s*\((0x[0-9A-F]+|[0-9]+)\)");
+    for cap in re_destination.captures_iter(&content) {
+        let offset = cap.get(0).unwrap().start();
+        elc.destinations.push((
+            ElcDestination {
+                name: cap[1].to_string(),
+                index: parse_value(&cap[2])?,
+            },
+            text_source(path, &content, offset).with_rule("ELC_PERIPHERAL -> ELSR index"),
+        ));
+    }
+
+    elc.events.sort_by_key(|(e, _)| e.value);
+    elc.destinations.sort_by_key(|(d, _)| d.index);
+    Ok(elc)
+}
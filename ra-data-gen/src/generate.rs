@@ -1,82 +1,207 @@
 use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use anyhow::Context;
 use crate::rzone::Rzones;
-use crate::pinmapping::PinMappings;
-use crate::perimap::PERIMAP;
+use crate::pinmapping::{PinMappings, PIN_FUNCTIONS};
+use crate::perimap::{MEMORY_MAP, PERIMAP};
+use crate::interrupts::{group_slots, EventInfo, IEL_GROUPS};
+use crate::elc::ElcInfo;
+use crate::dma::{DmaInfo, DMA_TRIGGERS};
+use crate::clocks::{ClockInfo, CLOCK_LIMITS, PERIPHERAL_CLOCKS};
+use crate::timer::TimerInfo;
+use crate::resets::ChipResets;
+use crate::regex;
 use ra_data_types::*;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
 
-pub fn generate(
-    rzones: &Rzones,
-    pin_mappings: &PinMappings,
-    family_interrupts: &BTreeMap<String, Vec<Interrupt>>,
-    chip_mstp: &BTreeMap<String, BTreeMap<String, crate::mstp::MstpInfo>>,
-    chip_timers: &BTreeMap<String, BTreeMap<String, u32>>,
-) -> anyhow::Result<()> {
-    let chips_dir = Path::new("./build/data/chips/");
-    let regs_out_dir = Path::new("./build/data/registers/");
+pub struct Options {
+    /// Register YAMLs (`data/registers/`).
+    pub registers_dir: PathBuf,
+    /// Output root; chips, registers and provenance are written to subdirectories.
+    pub out_dir: PathBuf,
+    pub provenance: bool,
+}
+
+impl Options {
+    /// Subdirectories of the output root this run doesn't write, which `--check` leaves out when
+    /// comparing with an existing tree.
+    pub fn unwritten_dirs(&self) -> &'static [&'static str] {
+        if self.provenance {
+            &[]
+        } else {
+            &["provenance"]
+        }
+    }
+}
+
+/// Splits a numbered peripheral name into its group and index. GPT channels keep one group and
+/// numbering across the 32-bit enhanced, 32-bit and 16-bit variants; ports are numbered in hex.
+fn instance(name: &str) -> Option<Instance> {
+    let (group, index) = if let Some(caps) = regex!(r"^(GPT)(?:32EH|32E|32|16)?([0-9]+)$").captures(name) {
+        (caps[1].to_string(), caps[2].parse().ok()?)
+    } else if let Some(caps) = regex!(r"^(PORT)([0-9A-F])$").captures(name) {
+        (caps[1].to_string(), u32::from_str_radix(&caps[2], 16).ok()?)
+    } else {
+        let caps = regex!(r"^([A-Z]+)([0-9]+)$").captures(name)?;
+        (caps[1].to_string(), caps[2].parse().ok()?)
+    };
+    Some(Instance { group, index })
+}
+
+/// Everything parsed from the sources, which `generate` combines into the chip data.
+pub struct Inputs {
+    pub rzones: Rzones,
+    pub pin_mappings: PinMappings,
+    pub family_interrupts: BTreeMap<String, Vec<EventInfo>>,
+    pub family_elc: BTreeMap<String, ElcInfo>,
+    pub chip_mstp: BTreeMap<String, BTreeMap<String, crate::mstp::MstpInfo>>,
+    pub chip_timers: BTreeMap<String, BTreeMap<String, TimerInfo>>,
+    pub chip_resets: ChipResets,
+    pub chip_dma: BTreeMap<String, DmaInfo>,
+    pub family_clocks: BTreeMap<String, ClockInfo>,
+}
+
+impl Inputs {
+    /// Parses everything under `sources`, calling `section` before each step.
+    pub fn parse(sources: &Path, diagnostics: &Diagnostics, mut section: impl FnMut(&str)) -> anyhow::Result<Self> {
+        section("Parsing headers");
+        let (_, rzones) = Rzones::parse(sources, diagnostics)?;
+        section("Parsing pin mappings");
+        let pin_mappings = PinMappings::parse(sources, diagnostics)?;
+        section("Parsing interrupts");
+        let family_interrupts = crate::interrupts::parse_all(sources, diagnostics)?;
+        section("Parsing ELC");
+        let family_elc = crate::elc::parse_all(sources, diagnostics)?;
+        section("Parsing MSTP");
+        let chip_mstp = crate::mstp::parse_all(sources, diagnostics)?;
+        section("Parsing Timers");
+        let chip_timers = crate::timer::parse_all(sources, diagnostics)?;
+        section("Parsing reset values");
+        let chip_resets = crate::resets::parse_all(sources, diagnostics)?;
+        section("Parsing DMA controllers");
+        let chip_dma = crate::dma::parse_all(sources, diagnostics)?;
+        section("Parsing clocks");
+        let family_clocks = crate::clocks::parse_all(sources, diagnostics)?;
+        Ok(Self {
+            rzones,
+            pin_mappings,
+            family_interrupts,
+            family_elc,
+            chip_mstp,
+            chip_timers,
+            chip_resets,
+            chip_dma,
+            family_clocks,
+        })
+    }
+
+    /// Names of the chips whose rzone parsed.
+    pub fn chips(&self) -> Vec<String> {
+        self.rzones.rzones.keys().cloned().collect()
+    }
+}
+
+pub fn generate(inputs: &Inputs, options: &Options, diagnostics: &Diagnostics) -> anyhow::Result<()> {
+    let Inputs {
+        rzones,
+        pin_mappings,
+        family_interrupts,
+        family_elc,
+        chip_mstp,
+        chip_timers,
+        chip_resets,
+        chip_dma,
+        family_clocks,
+    } = inputs;
+    let provenance = options.provenance;
+    let chips_dir = &options.out_dir.join("chips");
+    let regs_out_dir = &options.out_dir.join("registers");
+    let provenance_dir = &options.out_dir.join("provenance");
     fs::create_dir_all(chips_dir).context("failed to create chips directory")?;
     fs::create_dir_all(regs_out_dir).context("failed to create registers output directory")?;
+    if provenance {
+        fs::create_dir_all(provenance_dir).context("failed to create provenance directory")?;
+    }
 
-    let registers_dir = Path::new("data/registers/");
-    let mut available_registers = std::collections::HashSet::new();
+    let registers_dir = options.registers_dir.as_path();
+    // Register block name -> its data, written out once the reset values are known.
+    let mut available_registers = BTreeMap::new();
     
     // Helper function to process YAML files recursively
     fn process_register_dir(
         dir: &Path, 
-        regs_out_dir: &Path, 
-        available_registers: &mut std::collections::HashSet<String>
+        available_registers: &mut BTreeMap<String, serde_yaml::Value>
     ) -> anyhow::Result<()> {
         if !dir.exists() {
             return Ok(());
         }
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
+        // Sorted, so that if two directories hold a block with the same name, the same one always wins.
+        let mut paths = fs::read_dir(dir)?.map(|e| e.map(|e| e.path())).collect::<Result<Vec<_>, _>>()?;
+        paths.sort();
+        for path in paths {
             if path.is_dir() {
                 // Recursively process subdirectories
-                process_register_dir(&path, regs_out_dir, available_registers)?;
+                process_register_dir(&path, available_registers)?;
             } else if path.extension().map_or(false, |ext| ext == "yaml") {
                 if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                    available_registers.insert(stem.to_string());
-                    
-                    // Convert YAML to JSON for the build/data/registers directory
                     let content = fs::read_to_string(&path)?;
-                    let yaml_val: serde_yaml::Value = serde_yaml::from_str(&content)?;
-                    let json_content = serde_json::to_string_pretty(&yaml_val)?;
-                    fs::write(regs_out_dir.join(format!("{}.json", stem)), json_content)?;
+                    available_registers.insert(stem.to_string(), serde_yaml::from_str(&content)?);
                 }
             }
         }
         Ok(())
     }
     
-    process_register_dir(registers_dir, regs_out_dir, &mut available_registers)?;
+    process_register_dir(registers_dir, &mut available_registers)?;
 
-    let generate_chip = |(name, parsed): (&String, &crate::rzone::ParsedRzone)| -> anyhow::Result<()> {
+    // Returns the reset values of the chip's registers as (register block, register, value).
+    let generate_chip = |(name, parsed): (&String, &crate::rzone::ParsedRzone)| -> anyhow::Result<Vec<(String, String, u64)>> {
+        let mut resets = Vec::new();
         let mut packages = Vec::new();
+        let mut prov = ChipProvenance {
+            chip: name.clone(),
+            ..Default::default()
+        };
         
-        if let Some(mapping) = pin_mappings.get_for_chip(name) {
+        let mappings = pin_mappings.get_for_chip(name);
+        for mapping in &mappings {
+            if prov.packages.contains_key(&mapping.name) {
+                diagnostics.report(
+                    Diagnostic::warning(format!("more than one pin mapping for package {}, keeping the first", mapping.name))
+                        .chip(name)
+                        .file(&mapping.source.file),
+                );
+                continue;
+            }
+            prov.packages.insert(mapping.name.clone(), mapping.source.clone());
             packages.push(Package {
                 chip: name.clone(),
                 package: mapping.name.clone(),
                 pins: mapping.pins.iter().map(|p| Pin {
                     position: p.position.clone(),
                     signals: p.signals.clone(),
+                    port: p.port,
+                    number: p.number,
+                    functions: pin_functions(name, p),
                 }).collect(),
             });
         }
+        if mappings.is_empty() {
+            diagnostics.report(Diagnostic::warning("no pin mapping matches this chip").chip(name));
+        }
+        if packages.iter().flat_map(|p| &p.pins).all(|p| p.functions.is_empty()) {
+            diagnostics.report(
+                Diagnostic::warning("no PIN_FUNCTIONS entry matches a pin of this chip, leaving out its alternate functions")
+                    .chip(name),
+            );
+        }
 
         let mut peripherals = Vec::new();
-        let mstp_map = chip_mstp.iter()
-            .find(|(k, _)| name.starts_with(*k) || 
-                 (name.len() >= 7 && k.len() >= 7 && name[..7] == k[..7]))
-            .map(|(_, v)| v);
-        let timer_map = chip_timers.iter()
-            .find(|(k, _)| name.starts_with(*k) ||
-                 (name.len() >= 7 && k.len() >= 7 && name[..7] == k[..7]))
-            .map(|(_, v)| v);
+        let mstp_map = find_for_chip(chip_mstp, name);
+        let timer_map = find_for_chip(chip_timers, name);
+        let dma_info = find_for_chip(chip_dma, name);
+        let reset_map = find_for_chip(chip_resets, name);
 
         for p in &parsed.peripherals {
             // Normalize peripheral name (e.g., SYSTEM -> SYSC, PFS_A/PFS_B/PFS_NS -> PFS)
@@ -86,14 +211,39 @@ pub fn generate(
                 _ => &p.name,
             };
             let key = format!("{}:{}", name, peri_name);
-            if let Some(info) = PERIMAP.get(&key) {
+            if let Some((rule, info)) = PERIMAP.get_with_rule(&key) {
                 let reg_key = format!("{}_{}", info.peri_type, info.version);
-                if available_registers.contains(&reg_key) {
-                    let mstp = mstp_map.and_then(|m| m.get(&p.name)).map(|m| Mstp {
+                if available_registers.contains_key(&reg_key) {
+                    let mstp_info = mstp_map.and_then(|m| m.get(&p.name));
+                    let mstp = mstp_info.map(|m| Mstp {
                         register: m.register.clone(),
                         bit: m.bit,
                     });
-                    let bit_width = timer_map.and_then(|m| m.get(&p.name)).cloned();
+                    let timer_info = timer_map.and_then(|m| m.get(&p.name));
+                    let bit_width = timer_info.map(|t| t.bit_width);
+                    let clock = PERIPHERAL_CLOCKS.get_with_rule(&key);
+                    if clock.is_none() {
+                        diagnostics.report(
+                            Diagnostic::warning(format!("{key} has no PERIPHERAL_CLOCKS entry, leaving out its clock domain"))
+                                .chip(name),
+                        );
+                    }
+                    for (register, value) in reset_map.and_then(|m| m.get(&p.name)).into_iter().flatten() {
+                        resets.push((reg_key.clone(), register.clone(), *value));
+                    }
+
+                    let address_source = if peri_name != p.name {
+                        p.source.clone().with_rule(format!("{} normalized to {}", p.name, peri_name))
+                    } else {
+                        p.source.clone()
+                    };
+                    prov.peripherals.insert(peri_name.to_string(), PeripheralProvenance {
+                        address: address_source,
+                        version: crate::perimap::rule_source(rule, &key),
+                        mstp: mstp_info.map(|m| m.source.clone()),
+                        bit_width: timer_info.map(|t| t.source.clone()),
+                        clock: clock.map(|(rule, _)| crate::clocks::rule_source("PERIPHERAL_CLOCKS", rule, &key)),
+                    });
 
                     peripherals.push(Peripheral {
                         name: peri_name.to_string(),
@@ -102,7 +252,15 @@ pub fn generate(
                         version: info.version.to_string(),
                         mstp,
                         bit_width,
+                        clock: clock.map(|(_, domain)| domain.to_string()),
+                        instance: instance(peri_name),
                     });
+                } else {
+                    diagnostics.report(
+                        Diagnostic::warning(format!("{key} maps to {reg_key}, but there is no such register block"))
+                            .file(registers_dir)
+                            .chip(name),
+                    );
                 }
             }
         }
@@ -116,39 +274,322 @@ pub fn generate(
             }
         };
 
-        let interrupts = family_interrupts.get(&family_dir).cloned().unwrap_or_default();
+        let events = match family_interrupts.get(&family_dir) {
+            Some(events) => events.as_slice(),
+            None => {
+                diagnostics.report(Diagnostic::warning(format!("no bsp_elc.h for family {family_dir}")).chip(name));
+                &[]
+            }
+        };
+        let dma = match dma_info {
+            Some(info) => {
+                prov.dma = Some(info.source.clone());
+                Dma { dtc: info.dtc, dmac_channels: info.dmac_channels }
+            }
+            None => Dma { dtc: false, dmac_channels: 0 },
+        };
+
+        // Each IEL slot needs an IELSR register to link an event to it.
+        let mut slot_count = parsed.interrupt_count;
+        let icu = peripherals.iter().find(|p| p.peri_type == "icu");
+        if let Some(icu) = icu {
+            let reg_key = format!("{}_{}", icu.peri_type, icu.version);
+            match available_registers.get(&reg_key).and_then(|r| register_array_len(r, "IELSR")) {
+                Some(ielsr_count) if ielsr_count != parsed.interrupt_count => {
+                    diagnostics.report(
+                        Diagnostic::warning(format!(
+                            "{} IELs in the rzone, but {reg_key} has {ielsr_count} IELSR registers",
+                            parsed.interrupt_count
+                        ))
+                        .chip(name),
+                    );
+                    slot_count = slot_count.min(ielsr_count);
+                }
+                Some(_) => {}
+                None => diagnostics.report(Diagnostic::warning(format!("{reg_key} has no IELSR array")).chip(name)),
+            }
+        }
+        if events.iter().any(|e| !e.groups.is_empty()) && slot_count % IEL_GROUPS != 0 {
+            diagnostics.report(
+                Diagnostic::warning(format!("{slot_count} IEL slots don't split evenly into {IEL_GROUPS} event groups"))
+                    .chip(name),
+            );
+        }
+
+        let mut interrupts = Vec::new();
+        for e in events {
+            let irq_number = (!e.groups.is_empty()).then(|| group_slots(&e.groups, slot_count));
+            if irq_number.as_ref().is_some_and(Vec::is_empty) {
+                diagnostics.report(
+                    Diagnostic::warning(format!("{} is only in IEL groups {:?}, which have no slots here", e.interrupt.name, e.groups))
+                        .chip(name),
+                );
+                continue;
+            }
+            let trigger = DMA_TRIGGERS.get_with_rule(&e.interrupt.name).map(|(_, t)| t);
+            interrupts.push(Interrupt {
+                irq_number,
+                dtc: dma.dtc && trigger.is_none_or(|t| t.dtc),
+                dmac: dma.dmac_channels > 0 && trigger.is_none_or(|t| t.dmac),
+                ..e.interrupt.clone()
+            });
+            prov.events.insert(e.interrupt.name.clone(), e.source.clone());
+        }
+
+        // Families whose bsp_elc.h has no `elc_peripheral_t` have nothing to link events to.
+        let elc = family_elc.get(&family_dir).filter(|elc| !elc.destinations.is_empty()).map(|elc| {
+            for (e, source) in &elc.events {
+                prov.elc_events.insert(e.name.clone(), source.clone());
+            }
+            for (d, source) in &elc.destinations {
+                prov.elc_destinations.insert(d.name.clone(), source.clone());
+            }
+            Elc {
+                events: elc.events.iter().map(|(e, _)| e.clone()).collect(),
+                destinations: elc.destinations.iter().map(|(d, _)| d.clone()).collect(),
+            }
+        });
+
+        let sysc = peripherals.iter().find(|p| p.peri_type == "sysc");
+        let sysc_registers = sysc.and_then(|p| {
+            let reg_key = format!("{}_{}", p.peri_type, p.version);
+            Some((available_registers.get(&reg_key)?, registers_dir.join(&p.peri_type).join(format!("{reg_key}.yaml"))))
+        });
+        let clocks = sysc_registers.and_then(|(sysc, path)| {
+            let limits = match CLOCK_LIMITS.get_with_rule(name) {
+                Some((rule, limits)) => {
+                    prov.clocks.insert("limits".to_string(), crate::clocks::rule_source("CLOCK_LIMITS", rule, name));
+                    Some(limits)
+                }
+                None => {
+                    diagnostics.report(
+                        Diagnostic::warning(
+                            "no CLOCK_LIMITS entry matches this chip, leaving out its HOCO frequencies, domain maximums and PLL multiplier range",
+                        )
+                        .chip(name),
+                    );
+                    None
+                }
+            };
+            let sources = crate::clocks::sources(sysc, limits.map(|l| l.hoco).unwrap_or_default());
+            if sources.is_empty() {
+                return None;
+            }
+            let yaml_source = |location: &str, rule: &str| Source {
+                file: path.display().to_string(),
+                location: Some(location.to_string()),
+                line: None,
+                rule: Some(rule.to_string()),
+            };
+            prov.clocks.insert("sources".to_string(), yaml_source("enum/CKSEL", "SCKSCR.CKSEL variants"));
+            prov.clocks.insert("domains".to_string(), yaml_source("fieldset/SCKDIVCR", "SCKDIVCR fields -> xCLK, enum variants -> dividers"));
+            let pll = sources.iter().any(|s| s.name == "PLL").then(|| {
+                let info = family_clocks.get(&family_dir);
+                if let Some(source) = info.and_then(|i| i.source.clone()) {
+                    prov.clocks.insert("pll".to_string(), source);
+                }
+                crate::clocks::pll(sysc, info, limits)
+            });
+            Some(Clocks {
+                domains: crate::clocks::domains(sysc, limits.map(|l| l.max).unwrap_or_default()),
+                sources,
+                pll,
+                manual_limits: limits.is_some(),
+            })
+        });
+
+        // The domains are only known for chips whose SYSC block describes SCKDIVCR.
+        let domains: Vec<&str> = clocks.iter().flat_map(|c| &c.domains).map(|d| d.name.as_str()).collect();
+        for p in &peripherals {
+            if let Some(clock) = p.clock.as_deref().filter(|c| !domains.is_empty() && !domains.contains(c)) {
+                diagnostics.report(
+                    Diagnostic::warning(format!("{} runs on {clock}, which isn't one of the chip's clock domains", p.name))
+                        .chip(name),
+                );
+            }
+        }
+
+        let mut memory = Vec::new();
+        for m in &parsed.memories {
+            let key = format!("{}:{}", name, m.name);
+            let mapped = MEMORY_MAP.get_with_rule(&key);
+            let registers = mapped.and_then(|(_, info)| info.registers.as_ref());
+            let registers = registers.map(|r| format!("{}_{}", r.peri_type, r.version)).filter(|reg_key| {
+                let Some(registers) = available_registers.get(reg_key) else {
+                    diagnostics.report(
+                        Diagnostic::warning(format!("{} is laid out by {reg_key}, which has no register file", m.name)).chip(name),
+                    );
+                    return false;
+                };
+                // A block running past the region would have firmware program the wrong words.
+                let extent = block_extent(registers);
+                if extent > m.size {
+                    diagnostics.report(
+                        Diagnostic::error(format!("{reg_key} needs {extent:#x} bytes but {} is only {:#x}", m.name, m.size))
+                            .chip(name),
+                    );
+                }
+                extent <= m.size
+            });
+            let source = match mapped {
+                Some((rule, _)) => m.source.clone().with_rule(format!("MEMORY_MAP {rule:?} matched {key:?}")),
+                None => m.source.clone(),
+            };
+            prov.memory.insert(m.name.clone(), source);
+            memory.push(Memory {
+                name: m.name.clone(),
+                kind: mapped.map_or(m.kind.clone(), |(_, info)| info.kind.to_string()),
+                address: m.address,
+                size: m.size,
+                registers,
+            });
+        }
 
         let chip = Chip {
             name: name.clone(),
             family: parsed.family.clone(),
             core: parsed.core.clone(),
             interrupt_count: parsed.interrupt_count,
-            memory: parsed.memories.iter().map(|m| Memory {
-                name: m.name.clone(),
-                kind: m.kind.clone(),
-                address: m.address,
-                size: m.size,
-            }).collect(),
+            memory,
             peripherals,
             interrupts,
+            elc,
+            dma,
+            clocks,
             packages,
         };
 
         let file_path = chips_dir.join(format!("{}.json", name));
         let file = fs::File::create(file_path)?;
         serde_json::to_writer_pretty(file, &chip)?;
-        Ok(())
+
+        if provenance {
+            let file = fs::File::create(provenance_dir.join(format!("{}.json", name)))?;
+            serde_json::to_writer_pretty(file, &prov)?;
+        }
+        Ok(resets)
     };
 
     #[cfg(feature = "rayon")]
-    {
+    let chip_resets = {
         use rayon::prelude::*;
-        rzones.rzones.par_iter().try_for_each(generate_chip)?;
-    }
+        rzones.rzones.par_iter().map(generate_chip).collect::<anyhow::Result<Vec<_>>>()?
+    };
     #[cfg(not(feature = "rayon"))]
-    {
-        rzones.rzones.iter().try_for_each(generate_chip)?;
+    let chip_resets = rzones.rzones.iter().map(generate_chip).collect::<anyhow::Result<Vec<_>>>()?;
+
+    // A block is shared by many chips, so it only gets the reset values they all agree on.
+    let mut block_resets: BTreeMap<String, BTreeMap<String, Option<u64>>> = BTreeMap::new();
+    for (chip, resets) in rzones.rzones.keys().zip(chip_resets) {
+        for (block, register, value) in resets {
+            let entry = block_resets.entry(block.clone()).or_default().entry(register.clone()).or_insert(Some(value));
+            if let Some(previous) = *entry {
+                if previous != value {
+                    diagnostics.report(
+                        Diagnostic::warning(format!(
+                            "{block} {register} resets to {value:#x} here but {previous:#x} on other chips, leaving its reset value out"
+                        ))
+                        .chip(chip),
+                    );
+                    *entry = None;
+                }
+            }
+        }
+    }
+
+    for (name, mut registers) in available_registers {
+        if let Some(resets) = block_resets.get(&name) {
+            set_reset_values(&mut registers, resets);
+        }
+        // Convert YAML to JSON for the build/data/registers directory
+        fs::write(regs_out_dir.join(format!("{}.json", name)), serde_json::to_string_pretty(&registers)?)?;
     }
 
     Ok(())
 }
+
+/// The entry of a map keyed by SVD file stem (`R7FA6M5BH`) for the chip `name`: the first key
+/// that is a prefix of the name, or failing that shares its first 7 characters (the series).
+fn find_for_chip<'a, T>(map: &'a BTreeMap<String, T>, name: &str) -> Option<&'a T> {
+    map.iter()
+        .find(|(k, _)| name.starts_with(k.as_str()))
+        .or_else(|| map.iter().find(|(k, _)| name.len() >= 7 && k.len() >= 7 && name[..7] == k[..7]))
+        .map(|(_, v)| v)
+}
+
+/// Length of the `name` register array in the block's top-level register block.
+/// Alternate functions of a port pin from `PIN_FUNCTIONS`.
+fn pin_functions(chip: &str, pin: &crate::pinmapping::Pin) -> Vec<PinFunction> {
+    if pin.port.is_none() {
+        return Vec::new();
+    }
+    let key = format!("{chip}:{}", pin.signals[0].to_uppercase());
+    let functions = PIN_FUNCTIONS.get_with_rule(&key).map(|(_, functions)| *functions).unwrap_or_default();
+    functions
+        .iter()
+        .map(|&(psel, peripheral, signal)| PinFunction {
+            peripheral: peripheral.to_string(),
+            signal: signal.to_string(),
+            psel,
+        })
+        .collect()
+}
+
+fn register_array_len(registers: &serde_yaml::Value, name: &str) -> Option<u32> {
+    let mapping = registers.as_mapping()?;
+    let block = mapping.keys().filter_map(|k| k.as_str()).filter(|k| k.starts_with("block/")).min()?;
+    let items = mapping.get(block)?.get("items")?.as_sequence()?;
+    let item = items.iter().find(|i| i.get("name").and_then(|n| n.as_str()) == Some(name))?;
+    item.get("array")?.get("len")?.as_u64()?.try_into().ok()
+}
+
+/// Bytes the block's top-level register block spans from its start.
+fn block_extent(registers: &serde_yaml::Value) -> u64 {
+    let Some(mapping) = registers.as_mapping() else { return 0 };
+    let Some(block) = mapping.keys().filter_map(|k| k.as_str()).filter(|k| k.starts_with("block/")).min() else {
+        return 0;
+    };
+    let items = mapping.get(block).and_then(|b| b.get("items")).and_then(|i| i.as_sequence());
+    items
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            let offset = item.get("byte_offset").and_then(|o| o.as_u64()).unwrap_or(0);
+            let bytes = item.get("bit_size").and_then(|b| b.as_u64()).unwrap_or(32).div_ceil(8);
+            let array = item.get("array");
+            let len = array.and_then(|a| a.get("len")).and_then(|l| l.as_u64()).unwrap_or(1);
+            let stride = array.and_then(|a| a.get("stride")).and_then(|s| s.as_u64()).unwrap_or(bytes);
+            offset + stride * len.saturating_sub(1) + bytes
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Adds `reset_value` to the items of the block's top-level register block that have one.
+fn set_reset_values(registers: &mut serde_yaml::Value, resets: &BTreeMap<String, Option<u64>>) {
+    let Some(mapping) = registers.as_mapping_mut() else { return };
+    // The top-level block is the first one by name, as for the PAC generator.
+    let Some(block) = mapping.keys().filter_map(|k| k.as_str()).filter(|k| k.starts_with("block/")).min().map(str::to_string) else {
+        return;
+    };
+    let items = mapping.get_mut(block.as_str()).and_then(|b| b.get_mut("items")).and_then(|i| i.as_sequence_mut());
+    for item in items.into_iter().flatten() {
+        let value = item.get("name").and_then(|n| n.as_str()).and_then(|n| resets.get(n)).copied().flatten();
+        if let (Some(value), Some(item)) = (value, item.as_mapping_mut()) {
+            item.insert("reset_value".into(), value.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_for_chip_prefers_a_prefix_over_the_series() {
+        let map = BTreeMap::from([("R7FA6M5AH".to_string(), "AH"), ("R7FA6M5BH".to_string(), "BH")]);
+        assert_eq!(find_for_chip(&map, "R7FA6M5BH3CFC"), Some(&"BH"));
+        assert_eq!(find_for_chip(&map, "R7FA6M5CH3CFC"), Some(&"AH"));
+        assert_eq!(find_for_chip(&map, "R7FA6M4AF3CFB"), None);
+    }
+}
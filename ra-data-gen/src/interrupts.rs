@@ -2,22 +2,42 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use anyhow::Context;
-use ra_data_types::Interrupt;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::{Interrupt, Source};
 use crate::regex;
+use crate::util::text_source;
+
+/// On parts with grouped events, `IELSRn` belongs to group `n % IEL_GROUPS`.
+pub const IEL_GROUPS: u32 = 8;
+
+#[derive(Debug, Clone)]
+pub struct EventInfo {
+    pub interrupt: Interrupt,
+    /// IEL groups the event can be linked in, from its `_GROUPn` suffixes. Empty if any slot can
+    /// be used; the slots themselves depend on the chip's IEL count.
+    pub groups: Vec<u32>,
+    pub source: Source,
+}
+
+/// The IEL slots below `slot_count` in any of `groups`.
+pub fn group_slots(groups: &[u32], slot_count: u32) -> Vec<u32> {
+    (0..slot_count).filter(|slot| groups.contains(&(slot % IEL_GROUPS))).collect()
+}
 
-pub fn parse_all() -> anyhow::Result<BTreeMap<String, Vec<Interrupt>>> {
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<BTreeMap<String, Vec<EventInfo>>> {
     let mut family_interrupts = BTreeMap::new();
-    let mcu_dir = Path::new("sources/bsp/mcu");
+    let mcu_dir = sources.join("bsp/mcu");
 
-    for entry in fs::read_dir(mcu_dir).context("failed to read mcu directory")? {
+    for entry in fs::read_dir(&mcu_dir).context("failed to read mcu directory")? {
         let entry = entry?;
         let path = entry.path();
         if path.is_dir() {
             let family_name = path.file_name().unwrap().to_string_lossy().to_string();
             let elc_h = path.join("bsp_elc.h");
             if elc_h.exists() {
-                let interrupts = parse_elc_h(&elc_h)?;
-                family_interrupts.insert(family_name, interrupts);
+                if let Some(interrupts) = diagnostics.ok(parse_elc_h(&elc_h), |e| Diagnostic::error(e).file(&elc_h)) {
+                    family_interrupts.insert(family_name, interrupts);
+                }
             }
         }
     }
@@ -25,14 +45,15 @@ pub fn parse_all() -> anyhow::Result<BTreeMap<String, Vec<Interrupt>>> {
     Ok(family_interrupts)
 }
 
-fn parse_elc_h(path: &Path) -> anyhow::Result<Vec<Interrupt>> {
+fn parse_elc_h(path: &Path) -> anyhow::Result<Vec<EventInfo>> {
     let content = fs::read_to_string(path)?;
-    let mut interrupts: BTreeMap<String, Interrupt> = BTreeMap::new();
+    let mut interrupts: BTreeMap<String, EventInfo> = BTreeMap::new();
 
     let re_icu = regex!(r"ICU_EVENT_([A-Z0-9_]+)\s*=\s*\((0x[0-9A-F]+|[0-9]+)\),?\s*//\s*(.*)");
 
     for cap in re_icu.captures_iter(&content) {
         let raw_name = cap[1].to_string();
+        let offset = cap.get(0).unwrap().start();
         let value_str = &cap[2];
         let description = cap[3].trim().to_string();
 
@@ -46,29 +67,31 @@ fn parse_elc_h(path: &Path) -> anyhow::Result<Vec<Interrupt>> {
             continue;
         }
 
-        let (name, irq_number) = if let Some(idx) = raw_name.rfind("_GROUP") {
-            let suffix = &raw_name[idx..];
-            if suffix.len() == 7 && suffix.as_bytes()[6].is_ascii_digit() {
-                let group_digit = (suffix.as_bytes()[6] as char).to_digit(10).unwrap();
-                (raw_name[..idx].to_string(), Some(vec![group_digit, group_digit + 8, group_digit + 16, group_digit + 24]))
-            } else {
-                (raw_name, None)
+        let source = text_source(path, &content, offset);
+        let mut rule = "ICU_EVENT".to_string();
+        let (name, group) = match regex!(r"^(.+)_GROUP([0-9])$").captures(&raw_name) {
+            Some(cap) => {
+                let group: u32 = cap[2].parse()?;
+                if group >= IEL_GROUPS {
+                    anyhow::bail!("{raw_name}: there are only {IEL_GROUPS} IEL groups");
+                }
+                let line = source.line.unwrap_or_default();
+                rule = format!("ICU_EVENT _GROUPn -> IELSR slots with index % 8 == n: GROUP{group} at line {line}");
+                (cap[1].to_string(), Some(group))
             }
-        } else {
-            (raw_name, None)
+            None => (raw_name, None),
         };
 
-        if let Some(existing) = interrupts.get_mut(&name) {
-            match (&mut existing.irq_number, irq_number) {
-                (Some(existing_irqs), Some(new_irqs)) => {
-                    existing_irqs.extend(new_irqs);
-                    existing_irqs.sort();
-                    existing_irqs.dedup();
+        if let Some(EventInfo { interrupt: existing, groups, source: existing_source }) = interrupts.get_mut(&name) {
+            if let Some(group) = group {
+                groups.push(group);
+                groups.sort();
+                groups.dedup();
+                // The source stays the first definition; the rule lists where every group came from.
+                if let Some(existing_rule) = &mut existing_source.rule {
+                    let line = source.line.unwrap_or_default();
+                    existing_rule.push_str(&format!(", GROUP{group} at line {line}"));
                 }
-                (None, Some(new_irqs)) => {
-                    existing.irq_number = Some(new_irqs);
-                }
-                _ => {}
             }
             if let Some(existing_desc) = &mut existing.description {
                 existing_desc.push_str(" / ");
@@ -77,11 +100,17 @@ fn parse_elc_h(path: &Path) -> anyhow::Result<Vec<Interrupt>> {
                 existing.description = Some(description);
             }
         } else {
-            interrupts.insert(name.clone(), Interrupt {
-                name,
-                value,
-                description: Some(description),
-                irq_number,
+            interrupts.insert(name.clone(), EventInfo {
+                interrupt: Interrupt {
+                    name,
+                    value,
+                    description: Some(description),
+                    irq_number: None,
+                    dtc: false,
+                    dmac: false,
+                },
+                groups: group.into_iter().collect(),
+                source: source.with_rule(rule),
             });
         }
     }
@@ -95,6 +124,7 @@ fn parse_elc_h(path: &Path) -> anyhow::Result<Vec<Interrupt>> {
             continue;
         }
 
+        let offset = cap.get(0).unwrap().start();
         let value_str = &cap[2];
         let description = cap[3].trim().to_string();
 
@@ -104,11 +134,17 @@ fn parse_elc_h(path: &Path) -> anyhow::Result<Vec<Interrupt>> {
             value_str.parse()?
         };
 
-        interrupts.insert(name.clone(), Interrupt {
-            name,
-            value,
-            description: Some(description),
-            irq_number: None,
+        interrupts.insert(name.clone(), EventInfo {
+            interrupt: Interrupt {
+                name,
+                value,
+                description: Some(description),
+                irq_number: None,
+                dtc: false,
+                dmac: false,
+            },
+            groups: Vec::new(),
+            source: text_source(path, &content, offset).with_rule("ELC_EVENT"),
         });
     }
 
@@ -0,0 +1,22 @@
+pub mod rzone;
+pub mod generate;
+pub mod pinmapping;
+mod util;
+mod perimap;
+pub mod interrupts;
+pub mod elc;
+pub mod dma;
+pub mod clocks;
+pub mod mstp;
+pub mod timer;
+pub mod resets;
+
+#[macro_export]
+macro_rules! regex {
+    ($re:literal) => {{
+        ::ref_thread_local::ref_thread_local! {
+            static managed REGEX: ::regex::Regex = ::regex::Regex::new($re).unwrap();
+        }
+        <REGEX as ::ref_thread_local::RefThreadLocal<::regex::Regex>>::borrow(&REGEX)
+    }};
+}
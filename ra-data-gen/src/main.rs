@@ -1,21 +1,8 @@
-mod rzone;
-mod generate;
-mod pinmapping;
-mod util;
-mod perimap;
-mod interrupts;
-mod mstp;
-mod timer;
-
-#[macro_export]
-macro_rules! regex {
-    ($re:literal) => {{
-        ::ref_thread_local::ref_thread_local! {
-            static managed REGEX: ::regex::Regex = ::regex::Regex::new($re).unwrap();
-        }
-        <REGEX as ::ref_thread_local::RefThreadLocal<::regex::Regex>>::borrow(&REGEX)
-    }};
-}
+use std::path::Path;
+
+use ra_data_gen::generate;
+use ra_data_types::check;
+use ra_data_types::diagnostics::{Deny, Diagnostic, Diagnostics};
 
 struct Stopwatch {
     start: std::time::Instant,
@@ -57,32 +44,61 @@ impl Stopwatch {
 fn main() -> anyhow::Result<()> {
     pretty_env_logger::init();
 
-    let mut stopwatch = Stopwatch::new();
-
-    stopwatch.section("Parsing headers");
-    let (chips, rzones) = rzone::Rzones::parse()?;
-
-    println!("Parsed {} chips", chips.len());
-
-    stopwatch.section("Parsing pin mappings");
-    let pin_mappings = pinmapping::PinMappings::parse()?;
-
-    stopwatch.section("Parsing interrupts");
-    let family_interrupts = interrupts::parse_all()?;
+    let mut provenance = false;
+    let mut check = false;
+    let mut deny = Deny::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--provenance" => provenance = true,
+            "--check" => check = true,
+            "--deny" => {
+                let level = args.next().ok_or_else(|| anyhow::anyhow!("--deny needs a level"))?;
+                deny = level.parse().map_err(anyhow::Error::msg)?;
+            }
+            _ => anyhow::bail!("unknown argument {arg:?}"),
+        }
+    }
 
-    stopwatch.section("Parsing MSTP");
-    let chip_mstp = mstp::parse_all()?;
+    let sources = Path::new("sources");
+    let out_dir = Path::new("build/data");
+    // `--check` regenerates into a scratch directory and compares it with `build/data`.
+    let scratch = check.then(|| check::scratch_dir("ra-data-gen")).transpose()?;
+    let options = generate::Options {
+        registers_dir: "data/registers".into(),
+        out_dir: scratch.as_deref().unwrap_or(out_dir).into(),
+        provenance,
+    };
+
+    let diagnostics = Diagnostics::new();
+    let mut stopwatch = Stopwatch::new();
 
-    stopwatch.section("Parsing Timers");
-    let chip_timers = timer::parse_all()?;
+    let inputs = generate::Inputs::parse(sources, &diagnostics, |status| stopwatch.section(status))?;
+    println!("Parsed {} chips", inputs.rzones.rzones.len());
 
     stopwatch.section("Generating data");
-    generate::generate(&rzones, &pin_mappings, &family_interrupts, &chip_mstp, &chip_timers)?;
+    generate::generate(&inputs, &options, &diagnostics)?;
 
     stopwatch.section("Parsing other stuff");
 
     stopwatch.stop();
 
+    let mut changed = false;
+    if check {
+        for (path, change) in check::compare_dirs(out_dir, &options.out_dir, options.unwritten_dirs())? {
+            diagnostics.report(Diagnostic::error(change).file(out_dir.join(path)));
+            changed = true;
+        }
+    }
+
+    diagnostics.print_summary();
+    if changed {
+        anyhow::bail!("{} is out of date, regenerate it with `./d gen`", out_dir.display());
+    }
+    if diagnostics.denied(deny) {
+        anyhow::bail!("stopping because of the diagnostics above");
+    }
+
     Ok(())
 }
 
@@ -2,25 +2,30 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::Source;
 use crate::regex;
+use crate::util::xml_source;
 
 #[derive(Debug, Clone)]
 pub struct MstpInfo {
     pub register: String,
     pub bit: u32,
+    pub source: Source,
 }
 
-pub fn parse_all() -> anyhow::Result<BTreeMap<String, BTreeMap<String, MstpInfo>>> {
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<BTreeMap<String, BTreeMap<String, MstpInfo>>> {
     let mut chip_mstp = BTreeMap::new();
-    let svd_dir = Path::new("sources/svd");
+    let svd_dir = sources.join("svd");
 
-    for entry in fs::read_dir(svd_dir).context("failed to read svd directory")? {
+    for entry in fs::read_dir(&svd_dir).context("failed to read svd directory")? {
         let entry = entry?;
         let path = entry.path();
         if path.extension().map_or(false, |ext| ext == "svd") {
             let chip_name = path.file_stem().unwrap().to_string_lossy().to_string();
-            let mstp_map = parse_svd(&path, &chip_name)?;
-            chip_mstp.insert(chip_name, mstp_map);
+            if let Some(mstp_map) = diagnostics.ok(parse_svd(&path, &chip_name), |e| Diagnostic::error(e).file(&path)) {
+                chip_mstp.insert(chip_name, mstp_map);
+            }
         }
     }
 
@@ -59,6 +64,8 @@ fn parse_svd(path: &Path, _chip_name: &str) -> anyhow::Result<BTreeMap<String, M
                             mstp_map.insert(peri_name, MstpInfo {
                                 register: reg_name.to_string(),
                                 bit,
+                                source: xml_source(path, field)
+                                    .with_rule(format!("field description {:?}", field_desc)),
                             });
                         }
                     }
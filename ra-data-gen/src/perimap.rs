@@ -1,3 +1,5 @@
+use ra_data_types::Source;
+
 use crate::util::RegexMap;
 
 pub struct PeriInfo {
@@ -5,31 +7,41 @@ pub struct PeriInfo {
     pub version: &'static str,
 }
 
+/// Provenance of a PERIMAP lookup: the table itself and the pattern that matched `key`.
+pub fn rule_source(rule: &str, key: &str) -> Source {
+    Source {
+        file: file!().to_string(),
+        location: None,
+        line: None,
+        rule: Some(format!("PERIMAP {rule:?} matched {key:?}")),
+    }
+}
+
 pub static PERIMAP: RegexMap<PeriInfo> = RegexMap::new(&[
     // PORT (GPIO) mappings
     // RA0 family - each subfamily has different pin configs
-    ("R7FA0E1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra0e1" }),
-    ("R7FA0E2.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra0e2" }),
-    ("R7FA0L1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra0l1" }),
+    ("R7FA0E1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra0e1" }),
+    ("R7FA0E2.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra0e2" }),
+    ("R7FA0L1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra0l1" }),
     // RA2 family
-    ("R7FA2A1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra2a1" }),
-    ("R7FA2A2.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra2a2" }),
-    ("R7FA2[ELT].*:PORT\\d+", PeriInfo { peri_type: "port", version: "v1" }),
+    ("R7FA2A1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra2a1" }),
+    ("R7FA2A2.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra2a2" }),
+    ("R7FA2[ELT].*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "v1" }),
     // RA4 family
-    ("R7FA4M1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra4m1" }),
-    ("R7FA4W1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra4w1" }),
-    ("R7FA4[CELT].*:PORT\\d+", PeriInfo { peri_type: "port", version: "v1" }),
+    ("R7FA4M1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra4m1" }),
+    ("R7FA4W1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra4w1" }),
+    ("R7FA4[CELT].*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "v1" }),
     // RA6 family
-    ("R7FA6[MT]1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra4w1" }), // same as RA4W1
-    ("R7FA6T2.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra6t2" }),
-    ("R7FA6[EM].*:PORT\\d+", PeriInfo { peri_type: "port", version: "v1" }),
-    ("R7FA6T3.*:PORT\\d+", PeriInfo { peri_type: "port", version: "v1" }),
+    ("R7FA6[MT]1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra4w1" }), // same as RA4W1
+    ("R7FA6T2.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra6t2" }),
+    ("R7FA6[EM].*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "v1" }),
+    ("R7FA6T3.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "v1" }),
     // RA8 family
-    ("R7FA8D1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra8d1" }),
-    ("R7FA8E.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra8e1" }),
-    ("R7FA8[MT]1.*:PORT\\d+", PeriInfo { peri_type: "port", version: "ra8m1" }),
+    ("R7FA8D1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra8d1" }),
+    ("R7FA8E.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra8e1" }),
+    ("R7FA8[MT]1.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "ra8m1" }),
     // RKA8 family
-    ("R7KA8.*:PORT\\d+", PeriInfo { peri_type: "port", version: "rka8" }),
+    ("R7KA8.*:PORT[0-9A-F]", PeriInfo { peri_type: "port", version: "rka8" }),
 
     // PFS (Pin Function Select)
     // RA0 family uses 16-bit PFS_A (normalized to PFS in generate.rs)
@@ -45,6 +57,9 @@ pub static PERIMAP: RegexMap<PeriInfo> = RegexMap::new(&[
     ("R7FA2.*:GPT(?:32|16)\\d+", PeriInfo { peri_type: "timer", version: "v5" }),
     (".*:GPT\\d+", PeriInfo { peri_type: "timer", version: "v1" }),
 
+    // SCI mappings (Serial Communications Interface); RA8, RKA8 and RA0 have SCI_B or SAU instead
+    ("R7FA(?:2|4M|4W1|4E|4T1|6).*:SCI\\d+", PeriInfo { peri_type: "sci", version: "v1" }),
+
     // ICU mappings (Interrupt Controller Unit)
     // RA0 family
     ("R7FA0E1.*:ICU", PeriInfo { peri_type: "icu", version: "ra0e1" }),
@@ -89,6 +104,13 @@ pub static PERIMAP: RegexMap<PeriInfo> = RegexMap::new(&[
     ("R7KA8[DMP].*:ICU", PeriInfo { peri_type: "icu", version: "rka8d2" }),
     ("R7KA8T.*:ICU", PeriInfo { peri_type: "icu", version: "rka8t2" }),
 
+    // ELC mappings (Event Link Controller); parts with TrustZone add security attribution registers
+    ("R7FA4(?:C1|E[12]|L1|M[23]|T1).*:ELC", PeriInfo { peri_type: "elc", version: "v2" }),
+    ("R7FA6(?:E[12]|M[45]|T[23]).*:ELC", PeriInfo { peri_type: "elc", version: "v2" }),
+    ("R7FA8.*:ELC", PeriInfo { peri_type: "elc", version: "v2" }),
+    ("R7KA8.*:ELC", PeriInfo { peri_type: "elc", version: "v2" }),
+    (".*:ELC", PeriInfo { peri_type: "elc", version: "v1" }),
+
     // System mappings
     ("R7FA[46][ELMT].*:MSTP", PeriInfo { peri_type: "mstp", version: "v2" }),
     ("R7FA8.*:MSTP", PeriInfo { peri_type: "mstp", version: "v2" }),
@@ -142,3 +164,22 @@ pub static PERIMAP: RegexMap<PeriInfo> = RegexMap::new(&[
     ("R7KA8T2.*:SYSC", PeriInfo { peri_type: "sysc", version: "rka8t2" }),
 ]);
 
+
+/// What a special memory region holds, by `CHIP:REGION` with the region's rzone name.
+pub struct MemoryInfo {
+    pub kind: &'static str,
+    /// The register block laying out the region, if its layout is known.
+    pub registers: Option<PeriInfo>,
+}
+
+/// Regions the chip reads at reset. Other regions keep the rzone's `type` as their kind.
+pub static MEMORY_MAP: RegexMap<MemoryInfo> = RegexMap::new(&[
+    // Option-setting memory in the code flash, at 0x400
+    ("R7FA(?:2|4[MW]1).*:OPTION_SETTING", MemoryInfo { kind: "OptionSetting", registers: Some(PeriInfo { peri_type: "ofs", version: "v1" }) }),
+    // Option-setting area of the TrustZone parts, split into a non-secure and a secure part
+    ("R7FA(?:4M[23]|4E1|6M[45]|6E1).*:OPTION_SETTING", MemoryInfo { kind: "OptionSetting", registers: Some(PeriInfo { peri_type: "ofs", version: "v2" }) }),
+    ("R7FA(?:4M[23]|4E1|6M[45]|6E1).*:OPTION_SETTING_S", MemoryInfo { kind: "OptionSetting", registers: Some(PeriInfo { peri_type: "ofs", version: "v2_sec" }) }),
+    // Layout not described yet; still kept apart from the regions firmware may use.
+    (".*:OPTION_SETTING.*", MemoryInfo { kind: "OptionSetting", registers: None }),
+    (".*:ID_CODE", MemoryInfo { kind: "IdCode", registers: Some(PeriInfo { peri_type: "idcode", version: "v1" }) }),
+]);
@@ -1,18 +1,46 @@
+use std::path::Path;
+
 use anyhow::Context;
 use glob::glob;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::Source;
 use regex::Regex;
 use roxmltree::Document;
 
+use crate::regex;
+use crate::util::{xml_source, RegexMap};
+
 #[derive(Debug, Clone)]
 pub struct Pin {
     pub position: String,
     pub signals: Vec<String>,
+    pub port: Option<u8>,
+    pub number: Option<u8>,
+}
+
+/// Peripheral functions a port pin can be switched to, as `(PSEL, peripheral, signal)`, by
+/// `CHIP:PIN`. The pin configurations don't list them; these are from the "Register settings for
+/// input/output pin function" tables of the hardware manuals, which give the `PmnPFS.PSEL` value
+/// of each function.
+pub(crate) static PIN_FUNCTIONS: RegexMap<&[(u8, &str, &str)]> = RegexMap::new(&[
+    // RA6M5
+    ("R7FA6M5.*:P101", &[(0x03, "GPT5", "GTIOC5A"), (0x04, "SCI0", "TXD0"), (0x04, "SCI0", "MOSI0"), (0x04, "SCI0", "SDA0"), (0x05, "SCI1", "CTS1_RTS1"), (0x07, "IIC1", "SDA1")]),
+    ("R7FA6M5.*:P105", &[(0x03, "GPT1", "GTIOC1A")]),
+    ("R7FA6M5.*:P400", &[(0x03, "GPT6", "GTIOC6A"), (0x04, "SCI4", "SCK4"), (0x05, "SCI7", "SCK7"), (0x07, "IIC0", "SCL0")]),
+    ("R7FA6M5.*:P401", &[(0x03, "GPT6", "GTIOC6B"), (0x04, "SCI4", "CTS4_RTS4"), (0x05, "SCI7", "TXD7"), (0x05, "SCI7", "MOSI7"), (0x05, "SCI7", "SDA7"), (0x07, "IIC0", "SDA0")]),
+]);
+
+/// Parses a port pin signal such as `p400` or `pa00` into its port and pin number. Ports are hex.
+fn port_pin(signal: &str) -> Option<(u8, u8)> {
+    let caps = regex!(r"^[pP]([0-9a-fA-F])([0-9]{2})$").captures(signal)?;
+    Some((u8::from_str_radix(&caps[1], 16).ok()?, caps[2].parse().ok()?))
 }
 
 #[derive(Debug, Clone)]
 pub struct Package {
     pub name: String,
     pub pins: Vec<Pin>,
+    pub source: Source,
 }
 
 pub struct PinMappings {
@@ -20,57 +48,15 @@ pub struct PinMappings {
 }
 
 impl PinMappings {
-    pub fn parse() -> anyhow::Result<Self> {
-        let pattern = "/home/lafonso/study/rust/ra-data/sources/pinmapping/PinCfg*.xml";
-        let files: Vec<_> = glob(pattern)
+    pub fn parse(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<Self> {
+        let pattern = sources.join("pinmapping/PinCfg*.xml");
+        let files: Vec<_> = glob(&pattern.to_string_lossy())
             .context("Failed to read pinmapping glob pattern")?
-            .map(Result::unwrap)
+            .filter_map(|f| diagnostics.ok(f, Diagnostic::warning))
             .collect();
 
         let parse_file = |path: std::path::PathBuf| -> Option<(Regex, Package)> {
-            let filename = path.file_name().unwrap().to_str().unwrap();
-
-            // Extract the pattern from filename: PinCfg(PATTERN).xml
-            let pattern_str = filename
-                .strip_prefix("PinCfg")
-                .unwrap()
-                .strip_suffix(".xml")
-                .unwrap();
-
-            // Convert pattern to regex: replace 'x' with '.'
-            let regex_str = format!("^{}$", pattern_str.replace('x', "."));
-            let regex = Regex::new(&regex_str).ok()?;
-
-            let content = std::fs::read_to_string(&path).ok()?;
-            let doc = Document::parse(&content).ok()?;
-
-            // Find the package
-            let device_node = doc.descendants().find(|n| n.has_tag_name("device"))?;
-            let package_node = device_node.children().find(|n| n.has_tag_name("package"))?;
-            let package_name = package_node.attribute("name").unwrap_or("Unknown").to_string();
-
-            let mut pins = Vec::new();
-            if let Some(layout_node) = package_node.children().find(|n| n.has_tag_name("pinLayout")) {
-                for pin_node in layout_node.children().filter(|n| n.has_tag_name("pin")) {
-                    let position = pin_node.attribute("name").unwrap_or("").to_string();
-                    let signal = pin_node.attribute("ref").unwrap_or("").to_string();
-
-                    if !position.is_empty() && !signal.is_empty() {
-                        pins.push(Pin {
-                            position,
-                            signals: vec![signal],
-                        });
-                    }
-                }
-            }
-
-            Some((
-                regex,
-                Package {
-                    name: package_name,
-                    pins,
-                },
-            ))
+            diagnostics.ok(Self::parse_file(&path), |e| Diagnostic::warning(e).file(&path))
         };
 
         #[cfg(feature = "rayon")]
@@ -85,12 +71,61 @@ impl PinMappings {
         Ok(Self { mappings })
     }
 
-    pub fn get_for_chip(&self, pn: &str) -> Option<&Package> {
-        for (regex, package) in &self.mappings {
-            if regex.is_match(pn) {
-                return Some(package);
+    fn parse_file(path: &Path) -> anyhow::Result<(Regex, Package)> {
+        let filename = path.file_name().unwrap().to_str().unwrap();
+
+        // Extract the pattern from filename: PinCfg(PATTERN).xml
+        let pattern_str = filename
+            .strip_prefix("PinCfg")
+            .unwrap()
+            .strip_suffix(".xml")
+            .unwrap();
+
+        // Convert pattern to regex: replace 'x' with '.'
+        let regex_str = format!("^{}$", pattern_str.replace('x', "."));
+        let regex = Regex::new(&regex_str).context("Invalid pattern in file name")?;
+
+        let content = std::fs::read_to_string(path).context("Failed to read pin configuration")?;
+        let doc = Document::parse(&content).context("Failed to parse XML")?;
+
+        // Find the package
+        let device_node = doc.descendants().find(|n| n.has_tag_name("device")).context("Could not find <device> tag")?;
+        let package_node = device_node.children().find(|n| n.has_tag_name("package")).context("Could not find <package> tag")?;
+        let package_name = package_node.attribute("name").unwrap_or("Unknown").to_string();
+
+        let mut pins = Vec::new();
+        if let Some(layout_node) = package_node.children().find(|n| n.has_tag_name("pinLayout")) {
+            for pin_node in layout_node.children().filter(|n| n.has_tag_name("pin")) {
+                let position = pin_node.attribute("name").unwrap_or("").to_string();
+                let signal = pin_node.attribute("ref").unwrap_or("").to_string();
+
+                if !position.is_empty() && !signal.is_empty() {
+                    let port_pin = port_pin(&signal);
+                    pins.push(Pin {
+                        position,
+                        signals: vec![signal],
+                        port: port_pin.map(|(port, _)| port),
+                        number: port_pin.map(|(_, number)| number),
+                    });
+                }
             }
         }
-        None
+
+        Ok((
+            regex,
+            Package {
+                name: package_name,
+                pins,
+                source: xml_source(path, package_node).with_rule(format!("filename pattern {regex_str}")),
+            },
+        ))
+    }
+
+    /// The packages of every pin configuration whose file name matches the part number, by name.
+    pub fn get_for_chip(&self, pn: &str) -> Vec<&Package> {
+        let mut packages: Vec<_> =
+            self.mappings.iter().filter(|(regex, _)| regex.is_match(pn)).map(|(_, package)| package).collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        packages
     }
 }
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+
+/// Register name -> reset value, per SVD peripheral name, per SVD file stem.
+pub type ChipResets = BTreeMap<String, BTreeMap<String, BTreeMap<String, u64>>>;
+
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<ChipResets> {
+    let mut chip_resets = BTreeMap::new();
+    let svd_dir = sources.join("svd");
+
+    for entry in fs::read_dir(&svd_dir).context("failed to read svd directory")? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == "svd") {
+            let chip_name = path.file_stem().unwrap().to_string_lossy().to_string();
+            if let Some(resets) = diagnostics.ok(parse_svd(&path), |e| Diagnostic::error(e).file(&path)) {
+                chip_resets.insert(chip_name, resets);
+            }
+        }
+    }
+
+    Ok(chip_resets)
+}
+
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    node.children().find(|n| n.has_tag_name(tag)).and_then(|n| n.text()).map(str::trim)
+}
+
+fn parse_number(text: &str) -> Option<u64> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+fn parse_svd(path: &Path) -> anyhow::Result<BTreeMap<String, BTreeMap<String, u64>>> {
+    let content = fs::read_to_string(path)?;
+    let doc = roxmltree::Document::parse(&content)?;
+    let device = doc.root_element();
+
+    let peripherals: Vec<_> = device
+        .descendants()
+        .filter(|n| n.has_tag_name("peripheral"))
+        .collect();
+    let by_name: BTreeMap<_, _> = peripherals
+        .iter()
+        .filter_map(|p| Some((child_text(*p, "name")?, *p)))
+        .collect();
+
+    let mut resets = BTreeMap::new();
+    for peri in &peripherals {
+        let Some(name) = child_text(*peri, "name") else { continue };
+        // Derived peripherals (GPT321 from GPT320, ...) only list what they override.
+        let base = peri.attribute("derivedFrom").and_then(|b| by_name.get(b));
+        let registers = [Some(*peri), base.copied()]
+            .into_iter()
+            .flatten()
+            .find(|p| p.children().any(|c| c.has_tag_name("registers")));
+        let Some(registers) = registers.and_then(|p| p.children().find(|c| c.has_tag_name("registers"))) else {
+            continue;
+        };
+
+        let mut values = BTreeMap::new();
+        // Clusters and `dim` arrays don't match a top-level block item by name, so only plain
+        // registers are read.
+        for reg in registers.children().filter(|n| n.has_tag_name("register") && !n.children().any(|c| c.has_tag_name("dim"))) {
+            let Some(reg_name) = child_text(reg, "name") else { continue };
+            // The reset value and size default to the peripheral's, then the device's.
+            let inherited = |tag| {
+                [Some(reg), Some(*peri), base.copied(), Some(device)]
+                    .into_iter()
+                    .flatten()
+                    .find_map(|n| child_text(n, tag))
+                    .and_then(parse_number)
+            };
+            let Some(value) = inherited("resetValue") else { continue };
+            let size = inherited("size").unwrap_or(32);
+            let mask = if size >= 64 { u64::MAX } else { (1 << size) - 1 };
+            values.insert(reg_name.to_string(), value & mask);
+        }
+        if !values.is_empty() {
+            resets.insert(name.to_string(), values);
+        }
+    }
+
+    Ok(resets)
+}
@@ -1,6 +1,11 @@
 use std::collections::BTreeMap;
+use std::path::Path;
 
 use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::Source;
+
+use crate::util::xml_source;
 
 #[derive(Debug)]
 pub struct Rzones {
@@ -8,8 +13,8 @@ pub struct Rzones {
 }
 
 impl Rzones {
-    pub fn parse() -> anyhow::Result<(Vec<String>, Self)> {
-        let rzones = RzonesParsed::parse()?.0;
+    pub fn parse(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<(Vec<String>, Self)> {
+        let rzones = RzonesParsed::parse(sources, diagnostics)?.0;
         let chips = rzones.keys().cloned().collect();
         Ok((
             chips,
@@ -24,12 +29,14 @@ pub struct Memory {
     pub kind: String,
     pub address: u64,
     pub size: u64,
+    pub source: Source,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Peripheral {
     pub name: String,
     pub address: u64,
+    pub source: Source,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -45,30 +52,33 @@ pub struct ParsedRzone {
 pub struct RzonesParsed(pub BTreeMap<String, ParsedRzone>);
 
 impl RzonesParsed {
-    pub fn parse() -> anyhow::Result<Self> {
-        let files = glob::glob("sources/devices/zone/*.rzone").unwrap().map(Result::unwrap);
+    pub fn parse(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<Self> {
+        let pattern = sources.join("devices/zone/*.rzone");
+        let files = glob::glob(&pattern.to_string_lossy())
+            .context("Failed to read rzone glob pattern")?
+            .filter_map(|f| diagnostics.ok(f, Diagnostic::warning));
 
         let for_each_file = |f: std::path::PathBuf| {
             let ff = f.file_name().unwrap().to_string_lossy();
             let ff = ff.strip_suffix(".rzone").unwrap();
-            let parsed_header = ParsedRzone::parse(&f).unwrap();
-            (ff.to_string(), parsed_header)
+            let parsed_header = diagnostics.ok(ParsedRzone::parse(&f), |e| Diagnostic::error(e).file(&f).chip(ff))?;
+            Some((ff.to_string(), parsed_header))
         };
 
         #[cfg(feature = "rayon")]
         {
             use rayon::prelude::*;
-            Ok(Self(files.par_bridge().map(for_each_file).collect()))
+            Ok(Self(files.par_bridge().filter_map(for_each_file).collect()))
         }
         #[cfg(not(feature = "rayon"))]
         {
-            Ok(Self(files.map(for_each_file).collect()))
+            Ok(Self(files.filter_map(for_each_file).collect()))
         }
     }
 }
 
 impl ParsedRzone {
-    fn parse(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+    pub fn parse(path: impl AsRef<Path>) -> anyhow::Result<Self> {
         let content = std::fs::read_to_string(path.as_ref())
             .with_context(|| format!("Failed to read rzone file {:?}", path.as_ref()))?;
 
@@ -125,6 +135,7 @@ impl ParsedRzone {
                     kind,
                     address,
                     size,
+                    source: xml_source(path.as_ref(), memory_node),
                 });
             }
         }
@@ -143,7 +154,8 @@ impl ParsedRzone {
                     };
 
                     if !name.is_empty() {
-                        peripherals.push(Peripheral { name, address });
+                        let source = xml_source(path.as_ref(), node);
+                        peripherals.push(Peripheral { name, address, source });
                     }
                 }
             }
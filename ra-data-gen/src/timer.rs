@@ -2,25 +2,35 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use anyhow::Context;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::Source;
+use crate::util::xml_source;
 
-pub fn parse_all() -> anyhow::Result<BTreeMap<String, BTreeMap<String, u32>>> {
+#[derive(Debug, Clone)]
+pub struct TimerInfo {
+    pub bit_width: u32,
+    pub source: Source,
+}
+
+pub fn parse_all(sources: &Path, diagnostics: &Diagnostics) -> anyhow::Result<BTreeMap<String, BTreeMap<String, TimerInfo>>> {
     let mut chip_timers = BTreeMap::new();
-    let svd_dir = Path::new("sources/svd");
+    let svd_dir = sources.join("svd");
 
-    for entry in fs::read_dir(svd_dir).context("failed to read svd directory")? {
+    for entry in fs::read_dir(&svd_dir).context("failed to read svd directory")? {
         let entry = entry?;
         let path = entry.path();
         if path.extension().map_or(false, |ext| ext == "svd") {
             let chip_name = path.file_stem().unwrap().to_string_lossy().to_string();
-            let timer_map = parse_svd(&path)?;
-            chip_timers.insert(chip_name, timer_map);
+            if let Some(timer_map) = diagnostics.ok(parse_svd(&path), |e| Diagnostic::error(e).file(&path)) {
+                chip_timers.insert(chip_name, timer_map);
+            }
         }
     }
 
     Ok(chip_timers)
 }
 
-fn parse_svd(path: &Path) -> anyhow::Result<BTreeMap<String, u32>> {
+fn parse_svd(path: &Path) -> anyhow::Result<BTreeMap<String, TimerInfo>> {
     let content = fs::read_to_string(path)?;
     let doc = roxmltree::Document::parse(&content)?;
     
@@ -31,6 +41,7 @@ fn parse_svd(path: &Path) -> anyhow::Result<BTreeMap<String, u32>> {
         
         if name.contains("GPT") {
             // Determine bit width from name if possible
+            let mut source = xml_source(path, peri).with_rule("width from peripheral name");
             let mut bit_width = if name.contains("32") {
                 Some(32)
             } else if name.contains("16") {
@@ -43,6 +54,7 @@ fn parse_svd(path: &Path) -> anyhow::Result<BTreeMap<String, u32>> {
             if bit_width.is_none() {
                 let gtcnt = peri.descendants().find(|n| n.has_tag_name("register") && n.children().any(|c| c.has_tag_name("name") && c.text() == Some("GTCNT")));
                 if let Some(reg) = gtcnt {
+                    source = xml_source(path, reg).with_rule("width from GTCNT size");
                     bit_width = reg.children().find(|n| n.has_tag_name("size")).and_then(|n| n.text())
                         .and_then(|t| {
                             if t.starts_with("0x") {
@@ -71,7 +83,7 @@ fn parse_svd(path: &Path) -> anyhow::Result<BTreeMap<String, u32>> {
                     name.to_string()
                 };
                 
-                timer_map.insert(normalized_name, bw);
+                timer_map.insert(normalized_name, TimerInfo { bit_width: bw, source });
             }
         }
     }
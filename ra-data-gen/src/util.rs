@@ -1,5 +1,7 @@
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Mutex, OnceLock};
+use ra_data_types::Source;
 use regex::Regex;
 
 pub struct RegexMap<'a, T> {
@@ -17,7 +19,8 @@ impl<'a, T> RegexMap<'a, T> {
         }
     }
 
-    pub fn get(&self, key: &str) -> Option<&'a T> {
+    /// Returns the value for `key` along with the pattern of the entry that matched.
+    pub fn get_with_rule(&self, key: &str) -> Option<(&'a str, &'a T)> {
         if let Some(&val) = self
             .cache
             .lock()
@@ -25,7 +28,7 @@ impl<'a, T> RegexMap<'a, T> {
             .get_or_insert_with(Default::default)
             .get(key)
         {
-            return val.map(|i| &self.map[i].1);
+            return val.map(|i| (self.map[i].0, &self.map[i].1));
         }
         let val = self.get_uncached(key);
         self.cache
@@ -34,7 +37,7 @@ impl<'a, T> RegexMap<'a, T> {
             .as_mut()
             .unwrap()
             .insert(key.to_string(), val);
-        val.map(|i| &self.map[i].1)
+        val.map(|i| (self.map[i].0, &self.map[i].1))
     }
 
     fn get_uncached(&self, key: &str) -> Option<usize> {
@@ -53,3 +56,44 @@ impl<'a, T> RegexMap<'a, T> {
         None
     }
 }
+
+/// Source of an XML node, located by an XPath-like path and its line number.
+pub fn xml_source(path: &Path, node: roxmltree::Node) -> Source {
+    let line = node.document().text_pos_at(node.range().start).row;
+    Source {
+        file: path.display().to_string(),
+        location: Some(xpath(node)),
+        line: Some(line),
+        rule: None,
+    }
+}
+
+/// Source of a match in a text file (e.g. a C header), located by line number.
+pub fn text_source(path: &Path, content: &str, offset: usize) -> Source {
+    let line = content[..offset].matches('\n').count() as u32 + 1;
+    Source {
+        file: path.display().to_string(),
+        location: None,
+        line: Some(line),
+        rule: None,
+    }
+}
+
+fn xpath(node: roxmltree::Node) -> String {
+    let mut segments: Vec<String> = node
+        .ancestors()
+        .filter(|n| n.is_element())
+        .map(|n| {
+            let tag = n.tag_name().name();
+            if let Some(name) = n.attribute("name") {
+                format!("{tag}[@name='{name}']")
+            } else if let Some(name) = n.children().find(|c| c.has_tag_name("name")).and_then(|c| c.text()) {
+                format!("{tag}[name='{name}']")
+            } else {
+                tag.to_string()
+            }
+        })
+        .collect();
+    segments.reverse();
+    format!("/{}", segments.join("/"))
+}
@@ -0,0 +1,32 @@
+/* Trimmed from the FSP RA2E1 bsp_elc.h: grouped ICU events. */
+
+typedef enum e_icu_event_ra2e1
+{
+    ICU_EVENT_NONE                        = (0x00), // Link disabled
+    ICU_EVENT_ICU_IRQ0_GROUP0             = (0x01), // External pin interrupt 0
+    ICU_EVENT_ICU_IRQ1_GROUP1             = (0x01), // External pin interrupt 1
+    ICU_EVENT_SCI0_RXI_GROUP0             = (0x0D), // Receive data full
+    ICU_EVENT_SCI0_RXI_GROUP4             = (0x0D), // Receive data full
+    ICU_EVENT_SCI0_TXI_GROUP1             = (0x0D), // Transmit data empty
+    ICU_EVENT_SCI0_TXI_GROUP5             = (0x0D), // Transmit data empty
+    ICU_EVENT_GPT0_COUNTER_OVERFLOW_GROUP2 = (0x0E), // Overflow
+    ICU_EVENT_LVD_LVD1_GROUP3             = (0x02), // Voltage monitor 1 interrupt (DEPRECATED)
+} icu_event_t;
+
+typedef enum e_elc_event_ra2e1
+{
+    ELC_EVENT_NONE                        = (0x00), // Link disabled
+    ELC_EVENT_ICU_IRQ0                    = (0x01), // External pin interrupt 0
+    ELC_EVENT_ICU_IRQ1                    = (0x02), // External pin interrupt 1
+    ELC_EVENT_GPT0_COUNTER_OVERFLOW       = (0x3C), // Overflow
+    ELC_EVENT_SCI0_RXI                    = (0x71), // Receive data full
+} elc_event_t;
+
+typedef enum e_elc_peripheral
+{
+    ELC_PERIPHERAL_GPT_A                  = (0),
+    ELC_PERIPHERAL_GPT_B                  = (1),
+    ELC_PERIPHERAL_ADC0                   = (8),
+    ELC_PERIPHERAL_ADC0_B                 = (9),
+    ELC_PERIPHERAL_IOPORT1                = (14),
+} elc_peripheral_t;
@@ -0,0 +1,12 @@
+/* Trimmed from the FSP RA2E1 bsp_feature.h: clock generation circuit features. */
+
+#define BSP_FEATURE_CGC_HAS_BCLK                           (0U)
+#define BSP_FEATURE_CGC_HAS_FCLK                           (0U)
+#define BSP_FEATURE_CGC_HAS_PLL                            (0U)
+#define BSP_FEATURE_CGC_HAS_PLL2                           (0U)
+#define BSP_FEATURE_CGC_LOCO_STABILIZATION_MAX_US          (61U)
+#define BSP_FEATURE_CGC_MOCO_STABILIZATION_MAX_US          (15U)
+#define BSP_FEATURE_CGC_PLL_OUT_MAX_HZ                     (0U)
+#define BSP_FEATURE_CGC_PLL_OUT_MIN_HZ                     (0U)
+#define BSP_FEATURE_CGC_PLL_SRC_MAX_HZ                     (0U)
+#define BSP_FEATURE_CGC_PLL_SRC_MIN_HZ                     (0U)
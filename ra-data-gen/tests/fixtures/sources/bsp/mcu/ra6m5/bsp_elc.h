@@ -0,0 +1,26 @@
+/* Trimmed from the FSP RA6M5 bsp_elc.h. */
+
+typedef enum e_elc_event_ra6m5
+{
+    ELC_EVENT_NONE                        = (0),     // Link disabled
+    ELC_EVENT_ICU_IRQ0                    = (1),     // External pin interrupt 0
+    ELC_EVENT_ICU_IRQ1                    = (2),     // External pin interrupt 1
+    ELC_EVENT_DMAC0_INT                   = (0x20),  // DMAC0 transfer end
+    ELC_EVENT_GPT0_CAPTURE_COMPARE_A      = (0x75),  // Capture/Compare match A
+    ELC_EVENT_GPT0_COUNTER_OVERFLOW       = (0x7B),  // Overflow
+    ELC_EVENT_SCI0_RXI                    = (0x198), // Receive data full
+    ELC_EVENT_SCI0_TXI                    = (0x199), // Transmit data empty
+    ELC_EVENT_SCI0_TEI                    = (0x19A), // Transmit end
+    ELC_EVENT_SCI0_ERI                    = (0x19B), // Receive error
+} elc_event_t;
+
+typedef enum e_elc_peripheral
+{
+    ELC_PERIPHERAL_GPT_A                  = (0),
+    ELC_PERIPHERAL_GPT_B                  = (1),
+    ELC_PERIPHERAL_ADC0                   = (8),
+    ELC_PERIPHERAL_ADC0_B                 = (9),
+    ELC_PERIPHERAL_DAC0                   = (12),
+    ELC_PERIPHERAL_IOPORT1                = (14),
+    ELC_PERIPHERAL_CTSU                   = (18),
+} elc_peripheral_t;
@@ -0,0 +1,14 @@
+/* Trimmed from the FSP RA6M5 bsp_feature.h: clock generation circuit features. */
+
+#define BSP_FEATURE_CGC_HAS_BCLK                           (1U)
+#define BSP_FEATURE_CGC_HAS_FCLK                           (1U)
+#define BSP_FEATURE_CGC_HAS_PLL                            (1U)
+#define BSP_FEATURE_CGC_HAS_PLL2                           (1U)
+#define BSP_FEATURE_CGC_HOCOWTCR_VALUE                     (6U)
+#define BSP_FEATURE_CGC_LOCO_STABILIZATION_MAX_US          (61U)
+#define BSP_FEATURE_CGC_MOCO_STABILIZATION_MAX_US          (15U)
+#define BSP_FEATURE_CGC_PLL_OUT_MAX_HZ                     (200000000U)
+#define BSP_FEATURE_CGC_PLL_OUT_MIN_HZ                     (120000000U)
+#define BSP_FEATURE_CGC_PLL_SRC_MAX_HZ                     (48000000U)
+#define BSP_FEATURE_CGC_PLL_SRC_MIN_HZ                     (8000000U)
+#define BSP_FEATURE_CGC_PLLCCR_TYPE                        (1U)
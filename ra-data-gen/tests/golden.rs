@@ -0,0 +1,192 @@
+//! Golden-file tests: run the extraction pipeline over the trimmed sources in
+//! `tests/fixtures/sources` and compare each chip JSON with `tests/golden/`.
+//!
+//! Run with `UPDATE_GOLDEN=1` to rewrite the golden files after an intended change.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ra_data_gen::generate;
+use ra_data_types::diagnostics::{Diagnostics, Severity};
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn run_pipeline(out_dir: &Path, diagnostics: &Diagnostics) -> anyhow::Result<Vec<String>> {
+    run_pipeline_with(&options(out_dir, true), diagnostics)
+}
+
+fn options(out_dir: &Path, provenance: bool) -> generate::Options {
+    generate::Options {
+        registers_dir: manifest_dir().join("../data/registers"),
+        out_dir: out_dir.to_path_buf(),
+        provenance,
+    }
+}
+
+fn run_pipeline_with(options: &generate::Options, diagnostics: &Diagnostics) -> anyhow::Result<Vec<String>> {
+    let sources = manifest_dir().join("tests/fixtures/sources");
+    let inputs = generate::Inputs::parse(&sources, diagnostics, |_| {})?;
+    generate::generate(&inputs, options, diagnostics)?;
+    Ok(inputs.chips())
+}
+
+fn out_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn chips_match_golden_files() {
+    let out = out_dir("golden");
+    let diagnostics = Diagnostics::new();
+    let chips = run_pipeline(&out, &diagnostics).unwrap();
+    assert_eq!(chips, ["R7FA2E1A92DFM", "R7FA6M5BH3CFC"]);
+
+    let golden_dir = manifest_dir().join("tests/golden");
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut mismatches = Vec::new();
+    for chip in &chips {
+        let file = format!("{chip}.json");
+        let actual = fs::read_to_string(out.join("chips").join(&file)).unwrap();
+        let golden_path = golden_dir.join(&file);
+
+        if update {
+            fs::create_dir_all(&golden_dir).unwrap();
+            fs::write(&golden_path, &actual).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&golden_path)
+            .unwrap_or_else(|_| panic!("missing golden file {golden_path:?}, run with UPDATE_GOLDEN=1"));
+        if actual != expected {
+            mismatches.push(file);
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "generated chip JSON differs from golden files (compare against {:?}, rerun with UPDATE_GOLDEN=1 if intended): {mismatches:?}",
+        out.join("chips"),
+    );
+}
+
+#[test]
+fn provenance_points_at_fixture_sources() {
+    let out = out_dir("provenance");
+    run_pipeline(&out, &Diagnostics::new()).unwrap();
+
+    let content = fs::read_to_string(out.join("provenance/R7FA6M5BH3CFC.json")).unwrap();
+    let prov: ra_data_types::ChipProvenance = serde_json::from_str(&content).unwrap();
+
+    let flash = &prov.memory["FLASH"];
+    assert!(flash.file.ends_with("R7FA6M5BH3CFC.rzone"));
+    assert_eq!(flash.location.as_deref(), Some("/rzone/memories/memory[@name='FLASH']"));
+    assert_eq!(flash.line, Some(8));
+    let ofs = &prov.memory["OPTION_SETTING_S"];
+    assert_eq!(ofs.line, Some(12));
+    assert!(ofs.rule.as_deref().unwrap().starts_with("MEMORY_MAP"));
+
+    let gpt4 = &prov.peripherals["GPT4"];
+    assert!(gpt4.version.rule.as_deref().unwrap().contains(r#"".*:GPT\\d+""#));
+    let mstp = gpt4.mstp.as_ref().unwrap();
+    assert!(mstp.file.ends_with("R7FA6M5BH.svd"));
+    assert_eq!(
+        mstp.location.as_deref(),
+        Some("/device[name='R7FA6M5BH']/peripherals/peripheral[name='MSTP']/registers/register[name='MSTPCRD']/fields/field[name='MSTPD5']"),
+    );
+    assert_eq!(gpt4.bit_width.as_ref().unwrap().rule.as_deref(), Some("width from GTCNT size"));
+
+    let sci0_rxi = &prov.events["SCI0_RXI"];
+    assert!(sci0_rxi.file.ends_with("ra6m5/bsp_elc.h"));
+    assert_eq!(sci0_rxi.line, Some(11));
+
+    let ctsu = &prov.elc_destinations["CTSU"];
+    assert!(ctsu.file.ends_with("ra6m5/bsp_elc.h"));
+    assert_eq!(ctsu.line, Some(25));
+
+    let pll = &prov.clocks["pll"];
+    assert!(pll.file.ends_with("ra6m5/bsp_feature.h"));
+    assert_eq!(pll.line, Some(10));
+}
+
+#[test]
+fn provenance_lists_every_group_of_a_grouped_event() {
+    let out = out_dir("provenance-groups");
+    run_pipeline(&out, &Diagnostics::new()).unwrap();
+
+    let content = fs::read_to_string(out.join("provenance/R7FA2E1A92DFM.json")).unwrap();
+    let prov: ra_data_types::ChipProvenance = serde_json::from_str(&content).unwrap();
+
+    // SCI0_RXI is defined once per group, on lines 8 and 9 of the fixture.
+    let sci0_rxi = &prov.events["SCI0_RXI"];
+    assert!(sci0_rxi.file.ends_with("ra2e1/bsp_elc.h"));
+    assert_eq!(sci0_rxi.line, Some(8));
+    let rule = sci0_rxi.rule.as_deref().unwrap();
+    assert!(rule.ends_with("GROUP0 at line 8, GROUP4 at line 9"), "{rule}");
+}
+
+#[test]
+fn reset_values_are_added_to_register_blocks() {
+    let out = out_dir("resets");
+    run_pipeline(&out, &Diagnostics::new()).unwrap();
+
+    let item = |block: &str, register: &str| {
+        let content = fs::read_to_string(out.join(format!("registers/{block}.json"))).unwrap();
+        let ir: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let (_, top) = ir.as_object().unwrap().iter().find(|(k, _)| k.starts_with("block/")).unwrap();
+        top["items"].as_array().unwrap().iter().find(|i| i["name"] == register).unwrap().clone()
+    };
+
+    assert_eq!(item("mstp_v2", "MSTPCRD")["reset_value"], 0xffff_ffffu64);
+    assert_eq!(item("mstp_v3", "MSTPCRD")["reset_value"], 0xffff_ffffu64);
+    assert_eq!(item("timer_v1", "GTCNT")["reset_value"], 0);
+    assert!(item("mstp_v2", "MSTPCRA").get("reset_value").is_none());
+}
+
+#[test]
+fn broken_rzone_is_reported_and_skipped() {
+    let out = out_dir("diagnostics");
+    let diagnostics = Diagnostics::new();
+    let chips = run_pipeline(&out, &diagnostics).unwrap();
+
+    assert!(!chips.contains(&"R7FA4BROKEN".to_string()));
+    assert!(out.join("chips/R7FA6M5BH3CFC.json").exists());
+
+    let errors: Vec<_> = diagnostics
+        .entries()
+        .into_iter()
+        .filter(|d| d.severity == Severity::Error)
+        .collect();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!(errors[0].chip.as_deref(), Some("R7FA4BROKEN"));
+    assert!(errors[0].file.as_ref().unwrap().ends_with("R7FA4BROKEN.rzone"));
+    assert!(errors[0].message.contains("<processor>"), "{}", errors[0].message);
+}
+
+#[test]
+fn output_is_byte_stable() {
+    let first = out_dir("stable-1");
+    let second = out_dir("stable-2");
+    run_pipeline(&first, &Diagnostics::new()).unwrap();
+    run_pipeline(&second, &Diagnostics::new()).unwrap();
+
+    let changes = ra_data_types::check::compare_dirs(&first, &second, &[]).unwrap();
+    assert!(changes.is_empty(), "{changes:?}");
+}
+
+#[test]
+fn check_without_provenance_ignores_existing_provenance() {
+    let existing = out_dir("check-existing");
+    let fresh = out_dir("check-fresh");
+    run_pipeline(&existing, &Diagnostics::new()).unwrap();
+    let options = options(&fresh, false);
+    run_pipeline_with(&options, &Diagnostics::new()).unwrap();
+
+    assert!(existing.join("provenance").exists());
+    let changes = ra_data_types::check::compare_dirs(&existing, &fresh, options.unwritten_dirs()).unwrap();
+    assert!(changes.is_empty(), "{changes:?}");
+}
@@ -0,0 +1,102 @@
+//! Support for the generators' `--check` mode: regenerate into a scratch
+//! directory and compare it with the output already on disk.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// Generated now, but not present in the existing output.
+    Added,
+    /// Present in the existing output, but no longer generated.
+    Removed,
+    Modified,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added => write!(f, "missing from existing output"),
+            Change::Removed => write!(f, "no longer generated"),
+            Change::Modified => write!(f, "differs from regenerated output"),
+        }
+    }
+}
+
+/// A fresh, empty directory under the system temp dir, removed when the guard is dropped, so an
+/// early return or error doesn't leave it behind.
+pub fn scratch_dir(name: &str) -> io::Result<ScratchDir> {
+    let dir = std::env::temp_dir().join(format!("{name}-check-{}", std::process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+    Ok(ScratchDir(dir))
+}
+
+#[derive(Debug)]
+pub struct ScratchDir(PathBuf);
+
+impl std::ops::Deref for ScratchDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Compares two trees file by file, byte for byte. Returned paths are relative
+/// to the roots, sorted. Top-level entries named in `ignore` are skipped.
+pub fn compare_dirs(existing: &Path, fresh: &Path, ignore: &[&str]) -> io::Result<Vec<(PathBuf, Change)>> {
+    let old = list_files(existing, ignore)?;
+    let new = list_files(fresh, ignore)?;
+
+    let mut changes = Vec::new();
+    for path in old.union(&new) {
+        let change = match (old.contains(path), new.contains(path)) {
+            (true, false) => Change::Removed,
+            (false, true) => Change::Added,
+            _ => {
+                if fs::read(existing.join(path))? == fs::read(fresh.join(path))? {
+                    continue;
+                }
+                Change::Modified
+            }
+        };
+        changes.push((path.clone(), change));
+    }
+    Ok(changes)
+}
+
+fn list_files(root: &Path, ignore: &[&str]) -> io::Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    if !root.exists() {
+        return Ok(files);
+    }
+
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let rel = path.strip_prefix(root).unwrap().to_path_buf();
+            if dir == root && ignore.iter().any(|i| rel == Path::new(i)) {
+                continue;
+            }
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.insert(rel);
+            }
+        }
+    }
+    Ok(files)
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub file: Option<PathBuf>,
+    pub chip: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn warning(message: impl fmt::Display) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn error(message: impl fmt::Display) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    fn new(severity: Severity, message: impl fmt::Display) -> Self {
+        Self {
+            severity,
+            message: message.to_string(),
+            file: None,
+            chip: None,
+        }
+    }
+
+    pub fn file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn chip(mut self, chip: impl Into<String>) -> Self {
+        self.chip = Some(chip.into());
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severity {
+            Severity::Warning => write!(f, "warning")?,
+            Severity::Error => write!(f, "error")?,
+        }
+        if let Some(chip) = &self.chip {
+            write!(f, "[{chip}]")?;
+        }
+        write!(f, ": ")?;
+        if let Some(file) = &self.file {
+            write!(f, "{}: ", file.display())?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Which diagnostics make a run fail, as selected with `--deny`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Deny {
+    Nothing,
+    #[default]
+    Errors,
+    Warnings,
+}
+
+impl FromStr for Deny {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::Nothing),
+            "errors" => Ok(Self::Errors),
+            "warnings" => Ok(Self::Warnings),
+            _ => Err(format!("invalid --deny level {s:?}, expected one of: none, errors, warnings")),
+        }
+    }
+}
+
+/// Collects warnings and errors so extraction can continue past bad inputs
+/// and report everything at the end. Safe to share across rayon workers.
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    entries: Mutex<Vec<Diagnostic>>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn report(&self, diagnostic: Diagnostic) {
+        self.entries.lock().unwrap().push(diagnostic);
+    }
+
+    /// Reports `result`'s error, if any, and turns it into `None`.
+    pub fn ok<T, E: fmt::Display>(&self, result: Result<T, E>, diagnostic: impl FnOnce(String) -> Diagnostic) -> Option<T> {
+        match result {
+            Ok(v) => Some(v),
+            Err(e) => {
+                // `{:#}` prints an `anyhow` error together with its context chain.
+                self.report(diagnostic(format!("{e:#}")));
+                None
+            }
+        }
+    }
+
+    /// All diagnostics so far, errors first, then by chip and file.
+    pub fn entries(&self) -> Vec<Diagnostic> {
+        let mut entries = self.entries.lock().unwrap().clone();
+        entries.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then_with(|| (&a.chip, &a.file, &a.message).cmp(&(&b.chip, &b.file, &b.message)))
+        });
+        entries
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.entries.lock().unwrap().iter().filter(|d| d.severity == severity).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().unwrap().is_empty()
+    }
+
+    /// Prints every diagnostic and a one-line total to stderr.
+    pub fn print_summary(&self) {
+        for d in self.entries() {
+            eprintln!("{d}");
+        }
+        eprintln!(
+            "{} error(s), {} warning(s)",
+            self.count(Severity::Error),
+            self.count(Severity::Warning)
+        );
+    }
+
+    /// Whether the run should fail under the given policy.
+    pub fn denied(&self, deny: Deny) -> bool {
+        match deny {
+            Deny::Nothing => false,
+            Deny::Errors => self.count(Severity::Error) > 0,
+            Deny::Warnings => !self.is_empty(),
+        }
+    }
+}
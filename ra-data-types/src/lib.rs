@@ -1,5 +1,10 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+pub mod check;
+pub mod diagnostics;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Chip {
     pub name: String,
@@ -9,6 +14,9 @@ pub struct Chip {
     pub memory: Vec<Memory>,
     pub peripherals: Vec<Peripheral>,
     pub interrupts: Vec<Interrupt>,
+    pub elc: Option<Elc>,
+    pub dma: Dma,
+    pub clocks: Option<Clocks>,
     pub packages: Vec<Package>,
 }
 
@@ -19,14 +27,104 @@ pub struct Interrupt {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub irq_number: Option<Vec<u32>>,
+    /// Whether the event can activate a DTC transfer / a DMAC channel.
+    pub dtc: bool,
+    pub dmac: bool,
+}
+
+/// The chip's DMA controllers: the Data Transfer Controller and the DMAC's channels.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Dma {
+    pub dtc: bool,
+    pub dmac_channels: u32,
+}
+
+/// The clock tree: system clock sources, the clocks divided down from the system clock, and the
+/// PLL. Frequencies are in Hz.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Clocks {
+    pub sources: Vec<ClockSource>,
+    pub domains: Vec<ClockDomain>,
+    pub pll: Option<Pll>,
+    /// Whether the values only found in the hardware manuals are known: the HOCO frequencies,
+    /// each domain's `max_frequency` and the PLL multiplier range. They're left out otherwise.
+    pub manual_limits: bool,
+}
+
+/// A system clock source, selected by writing `select` to `SCKSCR.CKSEL`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClockSource {
+    pub name: String,
+    pub select: u32,
+    /// Frequencies the source can run at. Empty for the main oscillator and the PLL, which depend
+    /// on the board and the PLL settings.
+    pub frequencies: Vec<u32>,
+}
+
+/// A clock divided down from the system clock by a field of `register`, e.g. PCLKA by
+/// `SCKDIVCR.PCKA`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClockDomain {
+    pub name: String,
+    pub register: String,
+    pub field: String,
+    pub dividers: Vec<ClockDivider>,
+    pub max_frequency: Option<u32>,
+}
+
+/// A division ratio and the field value selecting it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClockDivider {
+    pub value: u32,
+    pub divider: u32,
+}
+
+/// The (first) PLL. Its input is MOSC or HOCO divided by one of `input_dividers`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Pll {
+    pub input_dividers: Vec<ClockDivider>,
+    pub input_min_frequency: Option<u32>,
+    pub input_max_frequency: Option<u32>,
+    pub output_min_frequency: Option<u32>,
+    pub output_max_frequency: Option<u32>,
+    pub multiplier_min: Option<f64>,
+    pub multiplier_max: Option<f64>,
+}
+
+/// The Event Link Controller: the events it can route, and the destinations it routes them to.
+/// Any of the events can be linked to any of the destinations.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Elc {
+    pub events: Vec<ElcEvent>,
+    pub destinations: Vec<ElcDestination>,
+}
+
+/// An event as numbered for the ELC, which can differ from its ICU number (RA2).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ElcEvent {
+    pub name: String,
+    pub value: u32,
+    pub description: Option<String>,
+}
+
+/// A peripheral that can be started by an event, through `ELSR[index]`, e.g. `GPT_A` at 0.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ElcDestination {
+    pub name: String,
+    pub index: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Memory {
     pub name: String,
+    /// `Flash` or `RAM` from the rzone, or `OptionSetting`/`IdCode` for the regions the chip reads
+    /// its configuration from at reset.
     pub kind: String,
     pub address: u64,
     pub size: u64,
+    /// The register block laying out the region's contents, e.g. `ofs_v1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registers: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -38,6 +136,16 @@ pub struct Peripheral {
     pub version: String,
     pub mstp: Option<Mstp>,
     pub bit_width: Option<u32>,
+    /// The clock domain the peripheral runs on, e.g. `PCLKD` for GPT.
+    pub clock: Option<String>,
+    pub instance: Option<Instance>,
+}
+
+/// Position of a numbered peripheral among its siblings, e.g. `GPT32EH3` is instance 3 of `GPT`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Instance {
+    pub group: String,
+    pub index: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -57,6 +165,18 @@ pub struct Package {
 pub struct Pin {
     pub position: String,
     pub signals: Vec<String>,
+    /// For port pins, the port and pin number: `PA05` is port 10, number 5.
+    pub port: Option<u8>,
+    pub number: Option<u8>,
+    pub functions: Vec<PinFunction>,
+}
+
+/// A peripheral signal a pin can be switched to, e.g. SCI0 TXD0 on P101 with PSEL 4.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PinFunction {
+    pub peripheral: String,
+    pub signal: String,
+    pub psel: u8,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -66,3 +186,51 @@ pub struct Part {
     pub packages: Vec<String>,
 }
 
+
+/// Where a generated datum came from: the source file, the XPath (or line) inside it,
+/// and the extraction rule that produced the value.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    pub file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
+}
+
+impl Source {
+    pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
+        self.rule = Some(rule.into());
+        self
+    }
+}
+
+/// Provenance sidecar for a `Chip`, keyed by the names used in the chip JSON.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ChipProvenance {
+    pub chip: String,
+    pub memory: BTreeMap<String, Source>,
+    pub peripherals: BTreeMap<String, PeripheralProvenance>,
+    pub events: BTreeMap<String, Source>,
+    pub elc_events: BTreeMap<String, Source>,
+    pub elc_destinations: BTreeMap<String, Source>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dma: Option<Source>,
+    /// Keyed by `sources`, `domains`, `pll` and `limits`.
+    pub clocks: BTreeMap<String, Source>,
+    pub packages: BTreeMap<String, Source>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PeripheralProvenance {
+    pub address: Source,
+    pub version: Source,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mstp: Option<Source>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bit_width: Option<Source>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clock: Option<Source>,
+}
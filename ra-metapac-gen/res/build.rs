@@ -1,5 +1,8 @@
+use std::collections::BTreeSet;
 use std::env;
 
+include!("src/chips/cfgs.rs");
+
 enum GetOneError {
     None,
     Multiple,
@@ -32,6 +35,25 @@ fn main() {
         Err(GetOneError::Multiple) => panic!("Multiple RA chip Cargo features enabled"),
     };
 
+    let package = match env::vars()
+        .map(|(a, _)| a)
+        .filter(|x| x.starts_with("CARGO_FEATURE_PACKAGE_"))
+        .get_one()
+    {
+        Ok(x) => Some(x.strip_prefix("CARGO_FEATURE_").unwrap().to_ascii_lowercase()),
+        Err(GetOneError::None) => None,
+        Err(GetOneError::Multiple) => panic!("Multiple package Cargo features enabled"),
+    };
+
+    let all_cfgs: BTreeSet<&str> = CHIP_CFGS
+        .iter()
+        .chain(CHIP_PACKAGES)
+        .flat_map(|(_, cfgs)| cfgs.iter().copied())
+        .collect();
+    for cfg in all_cfgs {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+    }
+
     if let Some(chip_name) = chip_name {
         let chip_name = chip_name
             .strip_prefix("CARGO_FEATURE_")
@@ -42,6 +64,33 @@ fn main() {
         println!("cargo:rustc-env=RA_METAPAC_PAC_PATH=chips/{}/pac.rs", chip_name);
         println!("cargo:rustc-env=RA_METAPAC_METADATA_PATH=chips/{}/metadata.rs", chip_name);
 
+        let mut cfgs: Vec<&str> = CHIP_CFGS
+            .iter()
+            .find(|(name, _)| *name == chip_name)
+            .map(|(_, cfgs)| cfgs.to_vec())
+            .unwrap_or_default();
+
+        // A chip with a single package always has it; otherwise pins not on every package need one selected.
+        let packages = CHIP_PACKAGES
+            .iter()
+            .find(|(name, _)| *name == chip_name)
+            .map(|(_, packages)| *packages)
+            .unwrap_or_default();
+        match (&package, packages) {
+            (Some(package), _) => match packages.iter().find(|p| *p == package) {
+                Some(package) => cfgs.push(package),
+                None => panic!("{chip_name} doesn't come in {}", package.replace('_', "-")),
+            },
+            (None, [package]) => cfgs.push(package),
+            (None, _) => {}
+        }
+
+        for cfg in &cfgs {
+            println!("cargo:rustc-cfg={}", cfg);
+        }
+        // Re-exported to dependents as `DEP_RA_METAPAC_CFGS`, so a HAL's build.rs can set the same cfgs.
+        println!("cargo:cfgs={}", cfgs.join(","));
+
         let out_dir = env::var("OUT_DIR").unwrap();
         let out_path = std::path::Path::new(&out_dir);
 
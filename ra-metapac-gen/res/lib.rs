@@ -1,6 +1,15 @@
-#![no_std]
+#![cfg_attr(not(feature = "mock"), no_std)]
 
+#[cfg(not(any(feature = "mock", feature = "trace")))]
 pub mod common;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(all(feature = "mock", not(feature = "trace")))]
+pub use mock::common;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "trace")]
+pub use trace::common;
 pub mod _peripherals;
 
 #[cfg(feature = "pac")]
@@ -16,7 +25,5 @@ pub mod metadata {
     include!(env!("RA_METAPAC_METADATA_PATH"));
 }
 
-pub trait Peripheral {
-    #[cfg(feature = "metadata")]
-    fn metadata() -> &'static metadata::Peripheral;
-}
+// Shared with the check crate harness, which includes the same file.
+include!("traits.rs");
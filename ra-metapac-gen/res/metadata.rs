@@ -14,6 +14,16 @@ pub struct Peripheral {
     pub version: &'static str,
     pub mstp: Option<Mstp>,
     pub bit_width: Option<u32>,
+    /// The clock domain the peripheral runs on, e.g. `PCLKD` for GPT.
+    pub clock: Option<&'static str>,
+    pub instance: Option<Instance>,
+}
+
+impl Peripheral {
+    /// The dividers and maximum frequency of the peripheral's clock domain.
+    pub fn clock_domain(&self) -> Option<&'static ClockDomain> {
+        CLOCKS.as_ref()?.domain(self.clock?)
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -22,6 +32,13 @@ pub struct Mstp {
     pub bit: u32,
 }
 
+/// Position of a numbered peripheral among its siblings, e.g. `GPT32EH3` is instance 3 of `GPT`.
+#[derive(Copy, Clone)]
+pub struct Instance {
+    pub group: &'static str,
+    pub index: u32,
+}
+
 /// Event that can be mapped to an ICU IELSR slot.
 /// For devices with grouped interrupts (RA2 family), `irq_slots` contains
 /// the allowed IELSR indices. For other devices, it's empty (any slot allowed).
@@ -31,12 +48,102 @@ pub struct Event {
     pub id: u16,
     /// Allowed IELSR slot indices. Empty means any slot is allowed.
     pub irq_slots: &'static [u8],
+    /// Whether the event can activate a DTC transfer / a DMAC channel.
+    pub dtc: bool,
+    pub dmac: bool,
+}
+
+/// The Data Transfer Controller, if there is one, and the number of DMAC channels.
+#[derive(Copy, Clone)]
+pub struct Dma {
+    pub dtc: bool,
+    pub dmac_channels: u32,
+}
+
+/// The clock tree, with frequencies in Hz.
+#[derive(Copy, Clone)]
+pub struct Clocks {
+    pub sources: &'static [ClockSource],
+    pub domains: &'static [ClockDomain],
+    pub pll: Option<Pll>,
+    /// Whether the HOCO frequencies, domain maximums and PLL multiplier range are known.
+    pub manual_limits: bool,
+}
+
+/// A system clock source, selected by writing `select` to `SCKSCR.CKSEL`. `frequencies` is empty
+/// for the main oscillator and the PLL.
+#[derive(Copy, Clone)]
+pub struct ClockSource {
+    pub name: &'static str,
+    pub select: u8,
+    pub frequencies: &'static [u32],
+}
+
+/// A clock divided down from the system clock by `register.field`, e.g. PCLKA by `SCKDIVCR.PCKA`.
+#[derive(Copy, Clone)]
+pub struct ClockDomain {
+    pub name: &'static str,
+    pub register: &'static str,
+    pub field: &'static str,
+    pub dividers: &'static [ClockDivider],
+    pub max_frequency: Option<u32>,
+}
+
+/// A division ratio and the field value selecting it.
+#[derive(Copy, Clone)]
+pub struct ClockDivider {
+    pub value: u8,
+    pub divider: u16,
+}
+
+#[derive(Copy, Clone)]
+pub struct Pll {
+    pub input_dividers: &'static [ClockDivider],
+    pub input_min_frequency: Option<u32>,
+    pub input_max_frequency: Option<u32>,
+    pub output_min_frequency: Option<u32>,
+    pub output_max_frequency: Option<u32>,
+    pub multiplier_min: Option<f32>,
+    pub multiplier_max: Option<f32>,
+}
+
+impl Clocks {
+    pub fn domain(&self, name: &str) -> Option<&'static ClockDomain> {
+        self.domains.iter().find(|d| d.name == name)
+    }
+}
+
+/// Event as numbered for the ELC, which can differ from its ICU number on RA2 parts. Any of
+/// `ELC_EVENTS` can be linked to any of `ELC_DESTINATIONS`.
+#[derive(Copy, Clone)]
+pub struct ElcEvent {
+    pub name: &'static str,
+    pub id: u16,
+}
+
+/// Peripheral an ELC event can start, linked through `ELSR[index]`.
+#[derive(Copy, Clone)]
+pub struct ElcDestination {
+    pub name: &'static str,
+    pub index: u8,
 }
 
 #[derive(Copy, Clone)]
 pub struct Pin {
     pub position: &'static str,
     pub signals: &'static [&'static str],
+    /// For port pins, the port and pin number: `PA05` is port 10, number 5.
+    pub port: Option<u8>,
+    pub number: Option<u8>,
+    pub functions: &'static [PinFunction],
+}
+
+/// A peripheral signal a pin can be switched to, e.g. SCI0 TXD0 on P101 with PSEL 4.
+#[derive(Copy, Clone)]
+pub struct PinFunction {
+    pub peripheral: &'static str,
+    pub signal: &'static str,
+    pub psel: u8,
 }
 
 #[derive(Copy, Clone)]
@@ -44,3 +151,24 @@ pub struct Package {
     pub name: &'static str,
     pub pins: &'static [Pin],
 }
+
+/// A register's value after reset, e.g. `MSTP` `MSTPCRD` is `0xffffffff`.
+#[derive(Copy, Clone)]
+pub struct RegisterReset {
+    pub peripheral: &'static str,
+    pub register: &'static str,
+    pub address: u64,
+    pub bit_size: u32,
+    pub value: u64,
+}
+
+/// Compares a register dump with reset state. `dump` returns the value read at an address, given
+/// the register's bit size, or `None` if it wasn't captured; the registers whose value differs
+/// from their reset value are returned along with it.
+pub fn reset_differences(
+    mut dump: impl FnMut(u64, u32) -> Option<u64>,
+) -> impl Iterator<Item = (&'static RegisterReset, u64)> {
+    REGISTER_RESETS
+        .iter()
+        .filter_map(move |r| dump(r.address, r.bit_size).filter(|v| *v != r.value).map(|v| (r, v)))
+}
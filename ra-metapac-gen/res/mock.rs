@@ -0,0 +1,220 @@
+//! Host-side register mock, enabled by the `mock` feature.
+//!
+//! Register blocks keep their MMIO addresses, but the `common` module below never dereferences
+//! them: every access goes to memory kept per thread (so parallel tests don't interfere) and keyed
+//! by address. Registers start at their reset value from `pac::MOCK_RESETS`, or 0 where it isn't
+//! known. Hooks can observe writes and override reads.
+
+extern crate std;
+
+use std::boxed::Box;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::vec::Vec;
+
+/// A register access: `size` bytes at `address`, little-endian in `value`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Access {
+    pub address: usize,
+    pub size: usize,
+    pub value: u64,
+}
+
+/// A register's value after reset, as listed in `pac::MOCK_RESETS`.
+#[derive(Copy, Clone)]
+pub struct Reset {
+    pub address: usize,
+    pub size: usize,
+    pub value: u64,
+}
+
+type ReadHook = Box<dyn FnMut(usize, usize) -> Option<u64>>;
+type WriteHook = Box<dyn FnMut(&Access)>;
+
+struct State {
+    memory: BTreeMap<usize, u8>,
+    read_hooks: Vec<ReadHook>,
+    write_hooks: Vec<WriteHook>,
+}
+
+impl State {
+    /// Memory in reset state and no hooks.
+    fn new() -> Self {
+        #[cfg(feature = "pac")]
+        let memory = crate::pac::MOCK_RESETS
+            .iter()
+            .flat_map(|reset| (0..reset.size).map(move |i| (reset.address + i, (reset.value >> (8 * i)) as u8)))
+            .collect();
+        #[cfg(not(feature = "pac"))]
+        let memory = BTreeMap::new();
+        Self {
+            memory,
+            read_hooks: Vec::new(),
+            write_hooks: Vec::new(),
+        }
+    }
+}
+
+std::thread_local! {
+    // Seeded on a thread's first access.
+    static STATE: RefCell<State> = RefCell::new(State::new());
+}
+
+/// Puts this thread's memory back in reset state and clears its hooks.
+pub fn reset() {
+    STATE.with(|s| *s.borrow_mut() = State::new());
+}
+
+/// Calls `hook(address, size)` on each register read. Returning `Some` overrides the value in
+/// memory; the first hook to do so wins.
+pub fn on_read(hook: impl FnMut(usize, usize) -> Option<u64> + 'static) {
+    STATE.with(|s| s.borrow_mut().read_hooks.push(Box::new(hook)));
+}
+
+/// Calls `hook` on each register write, after memory is updated.
+pub fn on_write(hook: impl FnMut(&Access) + 'static) {
+    STATE.with(|s| s.borrow_mut().write_hooks.push(Box::new(hook)));
+}
+
+/// Reads `size` bytes of memory at `address`, bypassing the hooks.
+pub fn peek(address: usize, size: usize) -> u64 {
+    STATE.with(|s| {
+        let s = s.borrow();
+        (0..size).fold(0, |value, i| value | (*s.memory.get(&(address + i)).unwrap_or(&0) as u64) << (8 * i))
+    })
+}
+
+/// Writes `size` bytes of memory at `address`, bypassing the hooks.
+pub fn poke(address: usize, size: usize, value: u64) {
+    STATE.with(|s| {
+        let mut s = s.borrow_mut();
+        for i in 0..size {
+            s.memory.insert(address + i, (value >> (8 * i)) as u8);
+        }
+    });
+}
+
+fn read(address: usize, size: usize) -> u64 {
+    // Hooks are taken out while they run, so they can use `peek`/`poke` themselves.
+    let mut hooks = STATE.with(|s| core::mem::take(&mut s.borrow_mut().read_hooks));
+    let value = hooks.iter_mut().find_map(|hook| hook(address, size));
+    STATE.with(|s| {
+        let mut s = s.borrow_mut();
+        hooks.append(&mut s.read_hooks);
+        s.read_hooks = hooks;
+    });
+    value.unwrap_or_else(|| peek(address, size))
+}
+
+fn write(access: Access) {
+    poke(access.address, access.size, access.value);
+    let mut hooks = STATE.with(|s| core::mem::take(&mut s.borrow_mut().write_hooks));
+    for hook in &mut hooks {
+        hook(&access);
+    }
+    STATE.with(|s| {
+        let mut s = s.borrow_mut();
+        hooks.append(&mut s.write_hooks);
+        s.write_hooks = hooks;
+    });
+}
+
+/// Drop-in for the generated `common` module, with the same API.
+pub mod common {
+    use core::marker::PhantomData;
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct RW;
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct R;
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct W;
+
+    mod sealed {
+        use super::*;
+        pub trait Access {}
+        impl Access for R {}
+        impl Access for W {}
+        impl Access for RW {}
+    }
+
+    pub trait Access: sealed::Access + Copy {}
+    impl Access for R {}
+    impl Access for W {}
+    impl Access for RW {}
+
+    pub trait Read: Access {}
+    impl Read for RW {}
+    impl Read for R {}
+
+    pub trait Write: Access {}
+    impl Write for RW {}
+    impl Write for W {}
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct Reg<T: Copy, A: Access> {
+        ptr: *mut u8,
+        phantom: PhantomData<*mut (T, A)>,
+    }
+    unsafe impl<T: Copy, A: Access> Send for Reg<T, A> {}
+    unsafe impl<T: Copy, A: Access> Sync for Reg<T, A> {}
+
+    impl<T: Copy, A: Access> Reg<T, A> {
+        #[allow(clippy::missing_safety_doc)]
+        #[inline(always)]
+        pub const unsafe fn from_ptr(ptr: *mut T) -> Self {
+            Self {
+                ptr: ptr as _,
+                phantom: PhantomData,
+            }
+        }
+
+        #[inline(always)]
+        pub const fn as_ptr(&self) -> *mut T {
+            self.ptr as _
+        }
+    }
+
+    impl<T: Copy, A: Read> Reg<T, A> {
+        #[inline(always)]
+        pub fn read(&self) -> T {
+            let value = super::read(self.ptr as usize, core::mem::size_of::<T>());
+            // Registers are at most 64 bits; take the low bytes of `value`.
+            unsafe { (&value as *const u64 as *const T).read_unaligned() }
+        }
+    }
+
+    impl<T: Copy, A: Write> Reg<T, A> {
+        #[inline(always)]
+        pub fn write_value(&self, val: T) {
+            let size = core::mem::size_of::<T>();
+            let mut value = 0u64;
+            unsafe { (&mut value as *mut u64 as *mut T).write_unaligned(val) };
+            super::write(super::Access {
+                address: self.ptr as usize,
+                size,
+                value,
+            });
+        }
+    }
+
+    impl<T: Default + Copy, A: Write> Reg<T, A> {
+        #[inline(always)]
+        pub fn write<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+            let mut val = Default::default();
+            let res = f(&mut val);
+            self.write_value(val);
+            res
+        }
+    }
+
+    impl<T: Copy, A: Read + Write> Reg<T, A> {
+        #[inline(always)]
+        pub fn modify<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+            let mut val = self.read();
+            let res = f(&mut val);
+            self.write_value(val);
+            res
+        }
+    }
+}
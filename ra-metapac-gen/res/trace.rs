@@ -0,0 +1,190 @@
+//! Register access tracing, enabled by the `trace` feature.
+//!
+//! The `common` module below performs each access as usual (on the mock, with the `mock` feature
+//! too), then reports it to the hook set with [`set_hook`] and, with the `defmt` feature, logs it
+//! with `defmt::trace!`. Accesses are named after the chip's peripherals and registers.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A traced register access: `size` bytes at `address`, little-endian in `value`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RegisterAccess {
+    pub write: bool,
+    pub address: usize,
+    pub size: usize,
+    pub value: u64,
+    /// The peripheral and register at `address`, if it's a known register of the selected chip.
+    pub peripheral: Option<&'static str>,
+    pub register: Option<&'static str>,
+}
+
+/// A register of the selected chip, as listed in `pac::TRACE_REGISTERS`.
+#[derive(Copy, Clone)]
+pub struct Register {
+    pub address: usize,
+    pub size: usize,
+    pub peripheral: &'static str,
+    pub register: &'static str,
+}
+
+static HOOK: AtomicUsize = AtomicUsize::new(0);
+
+/// Calls `hook` after every register access.
+pub fn set_hook(hook: fn(&RegisterAccess)) {
+    HOOK.store(hook as usize, Ordering::Relaxed);
+}
+
+/// Stops calling the hook set with [`set_hook`].
+pub fn clear_hook() {
+    HOOK.store(0, Ordering::Relaxed);
+}
+
+/// The register containing `address`.
+pub fn lookup(address: usize) -> Option<&'static Register> {
+    #[cfg(feature = "pac")]
+    {
+        // Sorted by address.
+        let registers = crate::pac::TRACE_REGISTERS;
+        let i = registers.partition_point(|r| r.address <= address).checked_sub(1)?;
+        let register = &registers[i];
+        (address < register.address + register.size).then_some(register)
+    }
+    #[cfg(not(feature = "pac"))]
+    {
+        let _ = address;
+        None
+    }
+}
+
+fn record<T: Copy>(write: bool, ptr: *mut u8, val: &T) {
+    let size = core::mem::size_of::<T>();
+    let mut value = 0u64;
+    // Registers are at most 64 bits; `val` fills the low bytes of `value`.
+    unsafe { (&mut value as *mut u64 as *mut T).write_unaligned(*val) };
+    let address = ptr as usize;
+    let register = lookup(address);
+    let access = RegisterAccess {
+        write,
+        address,
+        size,
+        value,
+        peripheral: register.map(|r| r.peripheral),
+        register: register.map(|r| r.register),
+    };
+
+    #[cfg(feature = "defmt")]
+    defmt::trace!(
+        "{=str} {=str}.{=str} ({=usize:#x}) = {=u64:#x}",
+        if write { "write" } else { "read" },
+        access.peripheral.unwrap_or("?"),
+        access.register.unwrap_or("?"),
+        address,
+        value,
+    );
+
+    let hook = HOOK.load(Ordering::Relaxed);
+    if hook != 0 {
+        let hook: fn(&RegisterAccess) = unsafe { core::mem::transmute(hook) };
+        hook(&access);
+    }
+}
+
+/// Drop-in for the generated `common` module, with the same API.
+pub mod common {
+    use core::marker::PhantomData;
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct RW;
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct R;
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct W;
+
+    mod sealed {
+        use super::*;
+        pub trait Access {}
+        impl Access for R {}
+        impl Access for W {}
+        impl Access for RW {}
+    }
+
+    pub trait Access: sealed::Access + Copy {}
+    impl Access for R {}
+    impl Access for W {}
+    impl Access for RW {}
+
+    pub trait Read: Access {}
+    impl Read for RW {}
+    impl Read for R {}
+
+    pub trait Write: Access {}
+    impl Write for RW {}
+    impl Write for W {}
+
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct Reg<T: Copy, A: Access> {
+        ptr: *mut u8,
+        phantom: PhantomData<*mut (T, A)>,
+    }
+    unsafe impl<T: Copy, A: Access> Send for Reg<T, A> {}
+    unsafe impl<T: Copy, A: Access> Sync for Reg<T, A> {}
+
+    impl<T: Copy, A: Access> Reg<T, A> {
+        #[allow(clippy::missing_safety_doc)]
+        #[inline(always)]
+        pub const unsafe fn from_ptr(ptr: *mut T) -> Self {
+            Self {
+                ptr: ptr as _,
+                phantom: PhantomData,
+            }
+        }
+
+        #[inline(always)]
+        pub const fn as_ptr(&self) -> *mut T {
+            self.ptr as _
+        }
+    }
+
+    impl<T: Copy, A: Read> Reg<T, A> {
+        #[inline(always)]
+        pub fn read(&self) -> T {
+            #[cfg(not(feature = "mock"))]
+            let val = unsafe { (self.ptr as *mut T).read_volatile() };
+            #[cfg(feature = "mock")]
+            let val = unsafe { crate::mock::common::Reg::<T, crate::mock::common::R>::from_ptr(self.ptr as _) }.read();
+            super::record(false, self.ptr, &val);
+            val
+        }
+    }
+
+    impl<T: Copy, A: Write> Reg<T, A> {
+        #[inline(always)]
+        pub fn write_value(&self, val: T) {
+            #[cfg(not(feature = "mock"))]
+            unsafe { (self.ptr as *mut T).write_volatile(val) };
+            #[cfg(feature = "mock")]
+            unsafe { crate::mock::common::Reg::<T, crate::mock::common::W>::from_ptr(self.ptr as _) }.write_value(val);
+            super::record(true, self.ptr, &val);
+        }
+    }
+
+    impl<T: Default + Copy, A: Write> Reg<T, A> {
+        #[inline(always)]
+        pub fn write<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+            let mut val = Default::default();
+            let res = f(&mut val);
+            self.write_value(val);
+            res
+        }
+    }
+
+    impl<T: Copy, A: Read + Write> Reg<T, A> {
+        #[inline(always)]
+        pub fn modify<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+            let mut val = self.read();
+            let res = f(&mut val);
+            self.write_value(val);
+            res
+        }
+    }
+}
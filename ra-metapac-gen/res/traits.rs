@@ -0,0 +1,13 @@
+pub trait Peripheral {
+    #[cfg(feature = "metadata")]
+    fn metadata() -> &'static metadata::Peripheral;
+}
+
+/// A peripheral with a module stop bit in one of the MSTPCRx registers.
+pub trait MstpPeripheral {
+    /// Clears the module stop bit, supplying the peripheral's clock.
+    fn enable_clock();
+    /// Sets the module stop bit, stopping the peripheral's clock.
+    fn disable_clock();
+    fn is_clock_enabled() -> bool;
+}
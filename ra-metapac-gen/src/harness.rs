@@ -0,0 +1,116 @@
+//! The optional "check all chips" harness.
+//!
+//! Every chip's `pac.rs` defines the same `#[macro_export]` macros, so they can't share a crate.
+//! Instead each chip gets a tiny crate of its own that includes its `pac.rs` and `metadata.rs` on
+//! top of the generated crate's register blocks, and the chip crates are grouped into one workspace
+//! per target triple. A compile error then names the crate, which is the chip.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Context;
+use quote::{format_ident, quote};
+
+use crate::format_file;
+
+/// Writes `<dir>/<target triple>/{Cargo.toml, <chip>/}`. `chips` holds lowercase chip names with
+/// their target triple and package cfgs.
+pub fn generate(dir: &Path, pac_dir: &Path, crate_name: &str, chips: &[(String, &str, Vec<String>)]) -> anyhow::Result<()> {
+    // Absolute, as the harness may live anywhere relative to the PAC.
+    let pac_dir = fs::canonicalize(pac_dir).context("failed to resolve PAC directory")?;
+    let pac_crate = format_ident!("{}", crate_name.replace('-', "_"));
+
+    if dir.exists() {
+        fs::remove_dir_all(dir).context("failed to clear check crate directory")?;
+    }
+
+    let mut by_target: BTreeMap<&str, Vec<(&str, &[String])>> = BTreeMap::new();
+    for (chip, target, packages) in chips {
+        by_target.entry(target).or_default().push((chip, packages));
+    }
+
+    for (target, chips) in by_target {
+        let workspace_dir = dir.join(target);
+        fs::create_dir_all(&workspace_dir)?;
+
+        let mut workspace_toml = String::new();
+        writeln!(workspace_toml, "# Check with `cargo check --workspace --target {target}`.")?;
+        writeln!(workspace_toml, "[workspace]")?;
+        writeln!(workspace_toml, "resolver = \"2\"")?;
+        writeln!(workspace_toml, "members = [")?;
+        for (chip, _) in &chips {
+            writeln!(workspace_toml, "    \"{chip}\",")?;
+        }
+        writeln!(workspace_toml, "]")?;
+        fs::write(workspace_dir.join("Cargo.toml"), workspace_toml)?;
+
+        for (chip, packages) in chips {
+            let chip_dir = workspace_dir.join(chip);
+            fs::create_dir_all(chip_dir.join("src"))?;
+
+            let mut cargo_toml = String::new();
+            writeln!(cargo_toml, "[package]")?;
+            writeln!(cargo_toml, "name = \"{chip}\"")?;
+            writeln!(cargo_toml, "version = \"0.0.0\"")?;
+            writeln!(cargo_toml, "edition = \"2021\"")?;
+            writeln!(cargo_toml, "publish = false")?;
+            writeln!(cargo_toml, "\n[dependencies]")?;
+            writeln!(
+                cargo_toml,
+                "{crate_name} = {{ path = {:?}, default-features = false }}",
+                pac_dir.display().to_string()
+            )?;
+            // Same as res/Cargo.toml, as the included files expect them.
+            writeln!(cargo_toml, "cortex-m = \"0.7.7\"")?;
+            writeln!(cargo_toml, "cortex-m-rt = {{ version = \"0.7.3\", optional = true }}")?;
+            writeln!(cargo_toml, "critical-section = \"1.1\"")?;
+            writeln!(cargo_toml, "embassy-hal-internal = {{ version = \"0.3.0\", optional = true }}")?;
+            writeln!(cargo_toml, "\n[features]")?;
+            writeln!(cargo_toml, "default = [\"metadata\"]")?;
+            writeln!(cargo_toml, "metadata = []")?;
+            writeln!(cargo_toml, "rt = [\"cortex-m-rt/device\"]")?;
+            // Only declared for the cfgs in pac.rs; the harness has no mock or trace support.
+            writeln!(cargo_toml, "mock = []")?;
+            writeln!(cargo_toml, "trace = []")?;
+            writeln!(
+                cargo_toml,
+                "embassy = [\"dep:embassy-hal-internal\", \"embassy-hal-internal/cortex-m\", \"embassy-hal-internal/prio-bits-3\"]"
+            )?;
+            // Checked without a package selected, which leaves out pins not on every package.
+            let check_cfgs: Vec<_> = packages.iter().map(|p| format!("'cfg({p})'")).collect();
+            writeln!(cargo_toml, "\n[lints.rust]")?;
+            writeln!(cargo_toml, "unexpected_cfgs = {{ level = \"warn\", check-cfg = [{}] }}", check_cfgs.join(", "))?;
+            fs::write(chip_dir.join("Cargo.toml"), cargo_toml)?;
+
+            let path = |p: &str| pac_dir.join(p).display().to_string();
+            let metadata_types = path("src/metadata.rs");
+            let traits = path("src/traits.rs");
+            let metadata = path(&format!("src/chips/{chip}/metadata.rs"));
+            let pac = path(&format!("src/chips/{chip}/pac.rs"));
+            let lib_rs = quote! {
+                #![no_std]
+
+                pub use #pac_crate::{_peripherals, common};
+
+                #[cfg(feature = "metadata")]
+                pub mod metadata {
+                    include!(#metadata_types);
+                    include!(#metadata);
+                }
+
+                // The PAC's own traits would refer to its `metadata` module rather than this one.
+                include!(#traits);
+
+                pub mod pac {
+                    include!(#pac);
+                }
+                pub use pac::*;
+            };
+            fs::write(chip_dir.join("src/lib.rs"), format_file(lib_rs)?)?;
+        }
+    }
+
+    Ok(())
+}
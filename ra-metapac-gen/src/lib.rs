@@ -0,0 +1,1615 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, File};
+use std::io::Write as _;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use anyhow::Context;
+use chiptool::ir;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
+use ra_data_types::{Chip, Clocks, Elc, Interrupt, Package, Peripheral};
+use regex::Regex;
+
+mod harness;
+mod registers;
+
+const AUTOGENERATED_TEXT: &str =
+    "// This file is autogenerated by ra-metapac-gen: do not edit by hand.";
+
+// Static files of the generated crate, embedded so the generator doesn't depend on the working directory.
+const RES_CARGO_TOML: &str = include_str!("../res/Cargo.toml");
+const RES_BUILD_RS: &str = include_str!("../res/build.rs");
+const RES_LIB_RS: &str = include_str!("../res/lib.rs");
+const RES_METADATA_RS: &str = include_str!("../res/metadata.rs");
+const RES_MOCK_RS: &str = include_str!("../res/mock.rs");
+const RES_TRACE_RS: &str = include_str!("../res/trace.rs");
+const RES_TRAITS_RS: &str = include_str!("../res/traits.rs");
+
+pub struct Config {
+    /// Output of `ra-data-gen`, with `chips/` and `registers/` subdirectories.
+    pub data_dir: PathBuf,
+    pub out_dir: PathBuf,
+    /// Only chips whose name matches are generated, together with just the register blocks they use.
+    pub chips: Option<Regex>,
+    pub crate_name: String,
+    pub crate_version: String,
+    /// Where to write the "check all chips" harness, if wanted. See `./d check`.
+    pub check_crate_dir: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            data_dir: "build/data".into(),
+            out_dir: "build/ra-metapac".into(),
+            chips: None,
+            crate_name: "ra-metapac".into(),
+            crate_version: "0.1.0".into(),
+            check_crate_dir: None,
+        }
+    }
+}
+
+pub fn generate(config: &Config, diagnostics: &Diagnostics) -> anyhow::Result<()> {
+    let out_dir = config.out_dir.as_path();
+    let chips_dir = config.data_dir.join("chips");
+    fs::create_dir_all(out_dir.join("src")).context("failed to create output directory")?;
+
+    fs::write(out_dir.join("build.rs"), RES_BUILD_RS)?;
+    fs::write(out_dir.join("src/lib.rs"), RES_LIB_RS)?;
+    fs::write(out_dir.join("src/metadata.rs"), RES_METADATA_RS)?;
+    fs::write(out_dir.join("src/mock.rs"), RES_MOCK_RS)?;
+    fs::write(out_dir.join("src/trace.rs"), RES_TRACE_RS)?;
+    fs::write(out_dir.join("src/traits.rs"), RES_TRAITS_RS)?;
+
+    let mut cargo_toml = RES_CARGO_TOML.to_string();
+    set_package_field(&mut cargo_toml, "name", &config.crate_name)?;
+    set_package_field(&mut cargo_toml, "version", &config.crate_version)?;
+    set_package_field(&mut cargo_toml, "links", &config.crate_name)?;
+    cargo_toml.insert_str(0, "[workspace]\n\n");
+
+    let mut paths = fs::read_dir(&chips_dir)
+        .context(format!("failed to read chips directory: {:?}", chips_dir))?
+        .map(|e| e.map(|e| e.path()))
+        .filter(|p| p.as_ref().is_ok_and(|p| p.extension().is_some_and(|ext| ext == "json")) || p.is_err())
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+
+    let mut chips: Vec<Chip> = paths
+        .iter()
+        .filter_map(|path| {
+            let content = diagnostics.ok(fs::read_to_string(path), |e| Diagnostic::error(e).file(path))?;
+            let chip = serde_json::from_str(&content).context("failed to parse chip JSON");
+            diagnostics.ok(chip, |e| Diagnostic::error(e).file(path))
+        })
+        .filter(|chip: &Chip| config.chips.as_ref().is_none_or(|re| re.is_match(&chip.name)))
+        .collect();
+
+    // With a chip filter, only generate the register blocks those chips use.
+    let used_blocks = config.chips.is_some().then(|| {
+        chips
+            .iter()
+            .flat_map(|c| &c.peripherals)
+            .map(|p| format!("{}_{}", p.peri_type.to_lowercase(), p.version.to_lowercase()))
+            .chain(chips.iter().flat_map(|c| &c.memory).filter_map(|m| m.registers.clone()))
+            .collect::<BTreeSet<_>>()
+    });
+    let block_map = registers::generate(&config.data_dir.join("registers"), out_dir, used_blocks.as_ref(), diagnostics)?;
+
+    // A peripheral without a register block is left out everywhere, rather than referring to a
+    // module that doesn't exist.
+    for chip in &mut chips {
+        chip.peripherals.retain(|peri| {
+            let mod_name = format!("{}_{}", peri.peri_type.to_lowercase(), peri.version.to_lowercase());
+            let generated = block_map.contains_key(&mod_name);
+            if !generated {
+                diagnostics.report(
+                    Diagnostic::error(format!("{} uses {mod_name}, which has no generated register block", peri.name))
+                        .chip(&chip.name),
+                );
+            }
+            generated
+        });
+    }
+
+    let chips_src_dir = out_dir.join("src/chips");
+    fs::create_dir_all(&chips_src_dir).context("failed to create chips directory")?;
+
+    let generate_chip = |chip: &Chip| -> anyhow::Result<(String, &'static str)> {
+        let chip_name_lower = chip.name.to_lowercase();
+        let chip_dir = chips_src_dir.join(&chip_name_lower);
+        fs::create_dir_all(&chip_dir)?;
+
+        generate_chip_pac(chip, &chip_dir, &block_map, diagnostics)?;
+
+        Ok((chip_name_lower, target_for_core(&chip.core)))
+    };
+
+    #[cfg(feature = "rayon")]
+    let generated: Vec<_> = {
+        use rayon::prelude::*;
+        chips.par_iter().map(generate_chip).collect::<anyhow::Result<_>>()?
+    };
+    #[cfg(not(feature = "rayon"))]
+    let generated: Vec<_> = chips.iter().map(generate_chip).collect::<anyhow::Result<_>>()?;
+
+    let mut chip_features = String::new();
+    let mut flavors: BTreeMap<String, &str> = BTreeMap::new();
+
+    // Family, series and core features that each chip feature enables. build.rs sets the matching
+    // cfgs, along with ones for the chip's peripherals.
+    let mut group_features = BTreeSet::new();
+    let mut chip_cfgs = Vec::new();
+    // Package features only gate pins; build.rs checks the selected one belongs to the chip.
+    let mut package_features = BTreeSet::new();
+    let mut chip_packages = Vec::new();
+    let mut harness_chips = Vec::new();
+
+    // Results come back in `chips` order, so features and flavors are sorted either way.
+    for ((chip_name_lower, target), chip) in generated.into_iter().zip(&chips) {
+        let groups = chip_groups(chip);
+        let enables: Vec<_> = groups.iter().map(|g| format!("{g:?}")).collect();
+        writeln!(chip_features, "{} = [{}]", chip_name_lower, enables.join(", "))?;
+        let prefix = chip_name_lower[..7].to_string();
+        flavors.insert(prefix, target);
+
+        let mut cfgs: Vec<_> = groups.iter().map(|g| g.replace('-', "_")).collect();
+        cfgs.extend(peripheral_cfgs(chip));
+        let packages: Vec<_> = chip.packages.iter().map(|pkg| package_cfg(&pkg.package)).collect();
+        package_features.extend(packages.iter().map(|cfg| cfg.replace('_', "-")));
+        harness_chips.push((chip_name_lower.clone(), target, packages.clone()));
+        chip_packages.push((chip_name_lower.clone(), packages));
+        chip_cfgs.push((chip_name_lower, cfgs));
+        group_features.extend(groups);
+    }
+
+    fs::write(chips_src_dir.join("cfgs.rs"), format_file(cfgs_tokens(&chip_cfgs, &chip_packages))?)?;
+
+    if let Some(dir) = &config.check_crate_dir {
+        harness::generate(dir, out_dir, &config.crate_name, &harness_chips)?;
+    }
+
+    writeln!(cargo_toml, "\n[features]")?;
+    writeln!(cargo_toml, "default = [\"pac\"]")?;
+    writeln!(cargo_toml, "pac = []")?;
+    writeln!(cargo_toml, "metadata = []")?;
+    writeln!(cargo_toml, "rt = [\"cortex-m-rt/device\"]")?;
+    writeln!(cargo_toml, "defmt = [\"dep:defmt\"]")?;
+    writeln!(cargo_toml, "mock = [\"critical-section/std\"]")?;
+    writeln!(cargo_toml, "trace = []")?;
+    writeln!(cargo_toml, "embassy = [\"dep:embassy-hal-internal\", \"embassy-hal-internal/cortex-m\", \"embassy-hal-internal/prio-bits-3\"]")?;
+    for group in &group_features {
+        writeln!(cargo_toml, "{} = []", group)?;
+    }
+    for package in &package_features {
+        writeln!(cargo_toml, "{} = []", package)?;
+    }
+    cargo_toml.push_str(&chip_features);
+
+    writeln!(cargo_toml, "\n[package.metadata.embassy_docs]")?;
+    writeln!(cargo_toml, "features = [\"pac\", \"metadata\"]")?;
+    writeln!(cargo_toml, "flavors = [")?;
+    for (prefix, target) in flavors {
+        writeln!(cargo_toml, "    {{ regex_feature = \"{}.*\", target = \"{}\" }},", prefix, target)?;
+    }
+    writeln!(cargo_toml, "]")?;
+
+    fs::write(out_dir.join("Cargo.toml"), cargo_toml)?;
+
+    Ok(())
+}
+
+fn generate_chip_pac(
+    chip: &Chip,
+    chip_dir: &Path,
+    block_map: &BTreeMap<String, registers::Block>,
+    diagnostics: &Diagnostics,
+) -> anyhow::Result<()> {
+    let pac = pac_tokens(chip, block_map, diagnostics);
+    fs::write(chip_dir.join("pac.rs"), format_file(pac).context("failed to format pac.rs")?)?;
+
+    // Generate device.x
+    let interrupt_count = chip.interrupt_count as usize;
+    let device_x_path = chip_dir.join("device.x");
+    let mut device_x = File::create(&device_x_path)?;
+    writeln!(device_x, "PROVIDE(NonMaskableInt = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(HardFault = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(MemoryManagement = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(BusFault = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(UsageFault = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(SecureFault = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(SVCall = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(DebugMonitor = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(PendSV = DefaultHandler);")?;
+    writeln!(device_x, "PROVIDE(SysTick = DefaultHandler);")?;
+    for i in 0..interrupt_count {
+        writeln!(device_x, "EXTERN(IEL{});", i)?;
+        writeln!(device_x, "PROVIDE(IEL{} = DefaultHandler);", i)?;
+    }
+
+    let metadata = metadata_tokens(chip, block_map);
+    fs::write(chip_dir.join("metadata.rs"), format_file(metadata).context("failed to format metadata.rs")?)?;
+
+    Ok(())
+}
+
+fn pac_tokens(chip: &Chip, block_map: &BTreeMap<String, registers::Block>, diagnostics: &Diagnostics) -> TokenStream {
+    let interrupt_count = chip.interrupt_count as usize;
+    let iels: Vec<_> = (0..interrupt_count).map(|i| format_ident!("IEL{}", i)).collect();
+    let iel_numbers: Vec<_> = (0..interrupt_count).map(Literal::usize_unsuffixed).collect();
+
+    let mut peripheral_items = Vec::new();
+    // Instance group -> (index, peripheral name) of each instance.
+    let mut groups: BTreeMap<&str, Vec<(usize, &str)>> = BTreeMap::new();
+    // Peripheral name -> register block module and type.
+    let mut regs_types: BTreeMap<&str, (Ident, Ident)> = BTreeMap::new();
+    for (i, peri) in chip.peripherals.iter().enumerate() {
+        let mod_name = format!("{}_{}", peri.peri_type.to_lowercase(), peri.version.to_lowercase());
+        // `generate` dropped the peripherals without a block.
+        let block_name = format_ident!("{}", block_map[&mod_name].name);
+        let name = format_ident!("{}", peri.name);
+        let mod_name = format_ident!("{}", mod_name);
+        regs_types.insert(&peri.name, (mod_name.clone(), block_name.clone()));
+        let address = hex(peri.address);
+        let index = Literal::usize_unsuffixed(i);
+        let mstp = mstp_tokens(chip, peri, block_map, diagnostics);
+        let instance_index = peri.instance.as_ref().map(|instance| {
+            let index = Literal::usize_unsuffixed(instance.index as usize);
+            quote!(pub const INDEX: usize = #index;)
+        });
+        if let Some(instance) = &peri.instance {
+            groups.entry(&instance.group).or_default().push((instance.index as usize, &peri.name));
+        }
+        peripheral_items.push(quote! {
+            #[derive(Copy, Clone)]
+            pub struct #name(());
+            #[cfg(feature = "embassy")]
+            impl embassy_hal_internal::PeripheralType for #name {}
+            impl #name {
+                pub const REGS: crate::_peripherals::#mod_name::#block_name =
+                    unsafe { crate::_peripherals::#mod_name::#block_name::from_ptr(#address as *mut ()) };
+                #instance_index
+                pub unsafe fn steal() -> Self {
+                    Self(())
+                }
+            }
+            impl core::ops::Deref for #name {
+                type Target = crate::_peripherals::#mod_name::#block_name;
+                fn deref(&self) -> &Self::Target {
+                    &Self::REGS
+                }
+            }
+            #[cfg(feature = "metadata")]
+            impl crate::Peripheral for #name {
+                fn metadata() -> &'static crate::metadata::Peripheral {
+                    &crate::metadata::PERIPHERALS[#index]
+                }
+            }
+            #mstp
+        });
+    }
+
+    // `<GROUP>_REGS[index]` for groups whose instances all use the same register block.
+    let instance_arrays = groups.iter().filter_map(|(group, instances)| {
+        let (mod_name, block_name) = &regs_types[instances[0].1];
+        if instances.iter().any(|(_, name)| regs_types[name] != regs_types[instances[0].1]) {
+            return None;
+        }
+        let array = format_ident!("{}_REGS", group);
+        let len = instances.iter().map(|(index, _)| index + 1).max().unwrap_or(0);
+        let entries = (0..len).map(|i| match instances.iter().find(|(index, _)| *index == i) {
+            Some((_, name)) => {
+                let name = format_ident!("{}", name);
+                quote!(Some(#name::REGS))
+            }
+            None => quote!(None),
+        });
+        let len = Literal::usize_unsuffixed(len);
+        Some(quote! {
+            pub const #array: [Option<crate::_peripherals::#mod_name::#block_name>; #len] = [#(#entries),*];
+        })
+    });
+
+    // (port, number) of every port pin in any package.
+    let port_pins: BTreeSet<(u8, u8)> = chip
+        .packages
+        .iter()
+        .flat_map(|pkg| &pkg.pins)
+        .filter_map(|pin| Some((pin.port?, pin.number?)))
+        .collect();
+    let pins: Vec<_> = port_pins.iter().map(|&(port, number)| pin_name(port, number)).collect();
+    let pin_consts = pin_consts(chip, &port_pins, block_map, &regs_types, diagnostics);
+
+    // Pins bonded out on only some of the chip's packages only exist with one of those package cfgs.
+    let in_package = |pkg: &Package, (port, number): (u8, u8)| {
+        pkg.pins.iter().any(|pin| pin.port == Some(port) && pin.number == Some(number))
+    };
+    let in_all_packages = |pin: (u8, u8)| chip.packages.iter().all(|pkg| in_package(pkg, pin));
+    let pin_cfgs: Vec<_> = port_pins
+        .iter()
+        .map(|&pin| {
+            let packages = chip.packages.iter().filter(|pkg| in_package(pkg, pin));
+            let cfgs = packages.map(|pkg| format_ident!("{}", package_cfg(&pkg.package)));
+            (!in_all_packages(pin)).then(|| quote!(#[cfg(any(#(#cfgs),*))]))
+        })
+        .collect();
+    // The pin macros are defined once per package when their pins differ, plus once with the pins
+    // common to all packages for when no package is selected.
+    let pin_macro_variants: Vec<(TokenStream, Option<&Package>)> = if pin_cfgs.iter().all(Option::is_none) {
+        vec![(quote!(), None)]
+    } else {
+        let cfgs: Vec<_> = chip.packages.iter().map(|pkg| format_ident!("{}", package_cfg(&pkg.package))).collect();
+        chip.packages
+            .iter()
+            .zip(&cfgs)
+            .map(|(pkg, cfg)| (quote!(#[cfg(#cfg)]), Some(pkg)))
+            .chain([(quote!(#[cfg(not(any(#(#cfgs),*)))]), None)])
+            .collect()
+    };
+    let pin_in_variant = |package: Option<&Package>, pin: (u8, u8)| match package {
+        Some(pkg) => in_package(pkg, pin),
+        None => in_all_packages(pin),
+    };
+
+    // Every singleton (peripherals, IELs and pins) becomes a field of `Peripherals`.
+    let singletons: Vec<_> = chip
+        .peripherals
+        .iter()
+        .map(|p| (p.name.clone(), None))
+        .chain(iels.iter().map(|i| (i.to_string(), None)))
+        .chain(pins.iter().cloned().zip(pin_cfgs.iter().cloned()))
+        .collect();
+    let singleton_types: Vec<_> = singletons.iter().map(|(s, _)| format_ident!("{}", s)).collect();
+    let singleton_fields: Vec<_> = singletons.iter().map(|(s, _)| format_ident!("{}", s.to_lowercase())).collect();
+    let singleton_cfgs: Vec<_> = singletons.iter().map(|(_, cfg)| cfg).collect();
+
+    let pin_types: Vec<_> = pins.iter().map(|p| format_ident!("{}", p)).collect();
+
+    let foreach_interrupt = quote! {
+        #(#iels = #iel_numbers,)*
+    };
+
+    let events: Vec<_> = chip
+        .interrupts
+        .iter()
+        .map(|irq| {
+            let name = format_ident!("{}", heck::AsPascalCase(&irq.name).to_string());
+            let value = Literal::u32_unsuffixed(irq.value);
+            let slots = irq.irq_number.iter().flatten().map(|n| Literal::u32_unsuffixed(*n));
+            quote!((#name, #value, (#(#slots),*)),)
+        })
+        .collect();
+
+    let foreach_peripheral = chip.peripherals.iter().enumerate().map(|(i, peri)| {
+        let name = format_ident!("{}", peri.name);
+        let kind = format_ident!("{}", peri.peri_type);
+        let version = format_ident!("{}", peri.version);
+        let index = Literal::usize_unsuffixed(i);
+        // One shape for every peripheral, so callbacks need a single rule.
+        let (group, instance_index) = match &peri.instance {
+            Some(instance) => {
+                let group = format_ident!("{}", instance.group);
+                let instance_index = Literal::usize_unsuffixed(instance.index as usize);
+                (quote!(Some(#group)), quote!(Some(#instance_index)))
+            }
+            None => (quote!(None), quote!(None)),
+        };
+        quote!((#name, #kind, #version, #index, #group, #instance_index),)
+    });
+
+    // Only functions of peripherals the PAC has a singleton for, so HALs can implement traits for them.
+    let pin_functions: BTreeSet<_> = chip
+        .packages
+        .iter()
+        .flat_map(|pkg| &pkg.pins)
+        .filter_map(|pin| Some(((pin.port?, pin.number?), &pin.functions)))
+        .flat_map(|(pin, functions)| functions.iter().map(move |f| (pin, f.peripheral.as_str(), f.signal.as_str(), f.psel)))
+        .filter(|(_, peripheral, _, _)| chip.peripherals.iter().any(|p| p.name == *peripheral))
+        .collect();
+
+    let pin_macros = pin_macro_variants.iter().map(|(cfg, package)| {
+        let foreach_pin = port_pins.iter().filter(|&&pin| pin_in_variant(*package, pin)).map(|&(port, number)| {
+            let name = format_ident!("{}", pin_name(port, number));
+            let port = Literal::u8_unsuffixed(port);
+            let number = Literal::u8_unsuffixed(number);
+            quote!((#name, #port, #number),)
+        });
+        let foreach_pin_function = pin_functions.iter().filter(|(pin, ..)| pin_in_variant(*package, *pin)).map(
+            |&((port, number), peripheral, signal, psel)| {
+                let pin = format_ident!("{}", pin_name(port, number));
+                let peripheral = format_ident!("{}", peripheral);
+                let signal = format_ident!("{}", signal);
+                let psel = Literal::u8_unsuffixed(psel);
+                quote!((#pin, #peripheral, #signal, #psel),)
+            },
+        );
+        quote! {
+            #cfg
+            #[macro_export]
+            macro_rules! foreach_pin {
+                ($m:path) => {
+                    $m! { #(#foreach_pin)* }
+                };
+            }
+
+            #cfg
+            #[macro_export]
+            macro_rules! foreach_pin_function {
+                ($m:path) => {
+                    $m! { #(#foreach_pin_function)* }
+                };
+            }
+        }
+    });
+
+    // (controller, event) for every event that can activate the DTC or a DMAC channel.
+    let dma_triggers = chip.interrupts.iter().flat_map(|irq| {
+        let name = format_ident!("{}", heck::AsPascalCase(&irq.name).to_string());
+        let value = Literal::u32_unsuffixed(irq.value);
+        let dtc = irq.dtc.then(|| quote!((dtc, #name, #value),));
+        let dmac = irq.dmac.then(|| quote!((dmac, #name, #value),));
+        dtc.into_iter().chain(dmac)
+    });
+
+    let event_enum = event_tokens(chip);
+    let iel_binding = iel_binding_tokens(chip, block_map, diagnostics);
+    let option_setting = option_setting_tokens(chip, block_map, diagnostics);
+    let elc = chip.elc.as_ref().map(elc_tokens);
+
+    let interrupt_count_lit = Literal::usize_unsuffixed(interrupt_count);
+    let trace_registers = trace_registers(chip, block_map);
+    let mock_resets = register_resets(chip, block_map).into_iter().map(|reset| {
+        let address = hex(reset.address);
+        let size = Literal::u32_unsuffixed((reset.bit_size / 8).max(1));
+        let value = hex(reset.value);
+        quote!(crate::mock::Reset { address: #address, size: #size, value: #value })
+    });
+
+    quote! {
+        pub mod peripherals {
+            #(#peripheral_items)*
+            #(#instance_arrays)*
+
+            pub struct Peripherals {
+                #(
+                    #singleton_cfgs
+                    #[cfg(feature = "embassy")]
+                    pub #singleton_fields: embassy_hal_internal::Peri<'static, #singleton_types>,
+                    #singleton_cfgs
+                    #[cfg(not(feature = "embassy"))]
+                    pub #singleton_fields: #singleton_types,
+                )*
+            }
+            impl Peripherals {
+                pub unsafe fn steal() -> Self {
+                    Self {
+                        #(
+                            #singleton_cfgs
+                            #[cfg(feature = "embassy")]
+                            #singleton_fields: embassy_hal_internal::Peri::new_unchecked(#singleton_types(())),
+                            #singleton_cfgs
+                            #[cfg(not(feature = "embassy"))]
+                            #singleton_fields: #singleton_types(()),
+                        )*
+                    }
+                }
+            }
+            #(
+                #[derive(Copy, Clone)]
+                pub struct #iels(());
+                #[cfg(feature = "embassy")]
+                impl embassy_hal_internal::PeripheralType for #iels {}
+            )*
+            #(
+                #pin_cfgs
+                #[derive(Copy, Clone)]
+                pub struct #pin_types(());
+                #pin_cfgs
+                #[cfg(feature = "embassy")]
+                impl embassy_hal_internal::PeripheralType for #pin_types {}
+                #pin_cfgs
+                impl #pin_types {
+                    #pin_consts
+                }
+            )*
+        }
+
+        #[macro_export]
+        macro_rules! foreach_interrupt {
+            ($m:path, $($args:tt)*) => {
+                $m! { $($args)* { #foreach_interrupt } }
+            };
+            ($($m:tt)*) => {
+                $($m)*! { #foreach_interrupt }
+            };
+        }
+
+        #[macro_export]
+        macro_rules! foreach_event {
+            ($m:path, $($args:tt)*) => {
+                $m! { $($args)* { #(#events)* } }
+            };
+            ($($m:tt)*) => {
+                $($m)*! { #(#events)* }
+            };
+        }
+
+        #[macro_export]
+        macro_rules! foreach_dma_trigger {
+            ($m:path) => {
+                $m! { #(#dma_triggers)* }
+            };
+        }
+
+        #[macro_export]
+        macro_rules! foreach_peripheral {
+            ($m:path) => {
+                $m! { #(#foreach_peripheral)* }
+            };
+        }
+
+        #(#pin_macros)*
+
+        #event_enum
+
+        #elc
+
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(u16)]
+        pub enum Interrupt {
+            #(#iels = #iel_numbers,)*
+        }
+
+        unsafe impl cortex_m::interrupt::InterruptNumber for Interrupt {
+            #[inline(always)]
+            fn number(self) -> u16 {
+                self as u16
+            }
+        }
+
+        #iel_binding
+
+        #option_setting
+
+        #[cfg(feature = "embassy")]
+        embassy_hal_internal::interrupt_mod!(#(#iels),*);
+
+        // The vector table only makes sense on the target; a mock build runs on the host.
+        #[cfg(not(feature = "mock"))]
+        extern "C" {
+            #(pub fn #iels();)*
+        }
+
+        #[derive(Copy, Clone)]
+        #[repr(C)]
+        pub union Vector {
+            pub _handler: unsafe extern "C" fn(),
+            pub _reserved: u32,
+        }
+
+        #[cfg(not(feature = "mock"))]
+        #[link_section = ".vector_table.interrupts"]
+        #[no_mangle]
+        pub static __INTERRUPTS: [Vector; #interrupt_count_lit] = [
+            #(Vector { _handler: #iels },)*
+        ];
+
+        #[cfg(feature = "trace")]
+        pub const TRACE_REGISTERS: &[crate::trace::Register] = &[#(#trace_registers),*];
+
+        #[cfg(feature = "mock")]
+        pub const MOCK_RESETS: &[crate::mock::Reset] = &[#(#mock_resets),*];
+    }
+}
+
+/// A register of the chip with a known reset value.
+struct RegisterReset<'a> {
+    peripheral: &'a str,
+    register: &'a str,
+    address: u64,
+    bit_size: u32,
+    value: u64,
+}
+
+/// The registers of the chip's peripherals with a known reset value, leaving out register arrays.
+fn register_resets<'a>(chip: &'a Chip, block_map: &'a BTreeMap<String, registers::Block>) -> Vec<RegisterReset<'a>> {
+    let mut resets = Vec::new();
+    for peri in &chip.peripherals {
+        let mod_name = format!("{}_{}", peri.peri_type.to_lowercase(), peri.version.to_lowercase());
+        let Some(block) = block_map.get(&mod_name) else { continue };
+        for (register, value) in &block.resets {
+            let item = &block.registers[register];
+            let ir::BlockItemInner::Register(reg) = &item.inner else { continue };
+            if item.array.is_some() {
+                continue;
+            }
+            resets.push(RegisterReset {
+                peripheral: &peri.name,
+                register,
+                address: peri.address + item.byte_offset as u64,
+                bit_size: reg.bit_size,
+                value: *value,
+            });
+        }
+    }
+    resets
+}
+
+/// Every register of the chip's peripherals, with array elements named `NAME[i]`, sorted by
+/// address for `trace::lookup`.
+fn trace_registers(chip: &Chip, block_map: &BTreeMap<String, registers::Block>) -> Vec<TokenStream> {
+    let mut registers = Vec::new();
+    for peri in &chip.peripherals {
+        let mod_name = format!("{}_{}", peri.peri_type.to_lowercase(), peri.version.to_lowercase());
+        let Some(block) = block_map.get(&mod_name) else { continue };
+        for (name, item) in &block.registers {
+            let ir::BlockItemInner::Register(reg) = &item.inner else { continue };
+            let offsets: Vec<(String, u32)> = match &item.array {
+                None => vec![(name.clone(), 0)],
+                Some(ir::Array::Regular(array)) => {
+                    (0..array.len).map(|i| (format!("{name}[{i}]"), i * array.stride)).collect()
+                }
+                Some(ir::Array::Cursed(array)) => {
+                    array.offsets.iter().enumerate().map(|(i, offset)| (format!("{name}[{i}]"), *offset)).collect()
+                }
+            };
+            for (register, offset) in offsets {
+                let address = peri.address + (item.byte_offset + offset) as u64;
+                registers.push((address, (reg.bit_size / 8).max(1), &peri.name, register));
+            }
+        }
+    }
+    registers.sort();
+    registers
+        .into_iter()
+        .map(|(address, size, peripheral, register)| {
+            let address = hex(address);
+            let size = Literal::u32_unsuffixed(size);
+            quote! {
+                crate::trace::Register {
+                    address: #address,
+                    size: #size,
+                    peripheral: #peripheral,
+                    register: #register,
+                }
+            }
+        })
+        .collect()
+}
+
+/// `Interrupt::bind` and `bind_events!`, which link events to IEL slots through the `IELSR`
+/// array of the chip's ICU block.
+fn iel_binding_tokens(
+    chip: &Chip,
+    block_map: &BTreeMap<String, registers::Block>,
+    diagnostics: &Diagnostics,
+) -> TokenStream {
+    let can_bind = quote! {
+        impl Interrupt {
+            /// Whether `event` can be linked in this IEL slot.
+            #[inline(always)]
+            pub const fn can_bind(self, event: Event) -> bool {
+                event.allows_slot(self as usize)
+            }
+        }
+    };
+
+    let Some(icu) = chip.peripherals.iter().find(|p| p.peri_type == "icu") else {
+        return can_bind;
+    };
+    let mod_name = format!("{}_{}", icu.peri_type.to_lowercase(), icu.version.to_lowercase());
+    let ielsr = block_map.get(&mod_name).and_then(|b| b.registers.get("IELSR"));
+    let Some((item, ir::BlockItemInner::Register(reg))) = ielsr.map(|item| (item, &item.inner)) else {
+        // The RA0 ICU blocks have no IELSR.
+        return can_bind;
+    };
+    match &item.array {
+        Some(ir::Array::Regular(array)) if array.len >= chip.interrupt_count => {}
+        _ => {
+            diagnostics.report(
+                Diagnostic::warning(format!("{mod_name} has no IELSR array for all {} IELs, so there is no Interrupt::bind", chip.interrupt_count))
+                    .chip(&chip.name),
+            );
+            return can_bind;
+        }
+    }
+
+    let icu_name = format_ident!("{}", icu.name);
+    let ty = format_ident!("u{}", reg.bit_size);
+    // IELSR is a plain integer in most ICU blocks, and a fieldset in the ones that describe IELS.
+    let ielsr = RawRegister::with_accessor(quote!(peripherals::#icu_name::REGS.ielsr(self as usize)), icu, "IELSR", block_map);
+    let bind = ielsr.write(quote!(event.number() as #ty));
+    let unbind = ielsr.write(quote!(0));
+
+    quote! {
+        #can_bind
+
+        impl Interrupt {
+            /// Links `event` to this IEL slot by writing its number to `IELSR`, which also clears
+            /// the slot's IR flag and DTC enable.
+            ///
+            /// Panics if the event can't be linked in this slot; [`bind_events!`] checks that at
+            /// compile time instead.
+            #[inline(always)]
+            pub fn bind(self, event: Event) {
+                assert!(self.can_bind(event));
+                #bind
+            }
+
+            /// Unlinks the IEL slot from its event.
+            #[inline(always)]
+            pub fn unbind(self) {
+                #unbind
+            }
+        }
+
+        /// Links events to IEL slots, checking at compile time that each event can be linked in its
+        /// slot and that no slot is used twice:
+        ///
+        /// ```ignore
+        /// bind_events!(IEL0 => IcuIrq0, IEL4 => Sci0Rxi);
+        /// ```
+        #[macro_export]
+        macro_rules! bind_events {
+            ($($iel:ident => $event:ident),* $(,)?) => {{
+                const _: () = {
+                    let slots = [$($crate::Interrupt::$iel as usize),*];
+                    let mut i = 0;
+                    while i < slots.len() {
+                        let mut j = i + 1;
+                        while j < slots.len() {
+                            assert!(slots[i] != slots[j], "an IEL slot is bound twice");
+                            j += 1;
+                        }
+                        i += 1;
+                    }
+                };
+                $(
+                    const _: () = assert!(
+                        $crate::Interrupt::$iel.can_bind($crate::Event::$event),
+                        concat!(stringify!($event), " can't be linked in ", stringify!($iel)),
+                    );
+                    $crate::Interrupt::$iel.bind($crate::Event::$event);
+                )*
+            }};
+        }
+    }
+}
+
+/// `option_setting`, with a value type per register of the option-setting and ID code regions, and
+/// `option_setting!` to place values in the link sections the linker script puts at their address.
+fn option_setting_tokens(
+    chip: &Chip,
+    block_map: &BTreeMap<String, registers::Block>,
+    diagnostics: &Diagnostics,
+) -> TokenStream {
+    let mut types = Vec::new();
+    // (register, static, section, type) for every register `option_setting!` can place.
+    let mut placements: Vec<(String, Ident, String, Ident)> = Vec::new();
+    for mem in &chip.memory {
+        let Some(block) = mem.registers.as_ref().and_then(|r| block_map.get(r)) else { continue };
+        let mut items: Vec<_> = block.registers.values().collect();
+        items.sort_by_key(|item| item.byte_offset);
+        for item in items {
+            let ir::BlockItemInner::Register(reg) = &item.inner else { continue };
+            if placements.iter().any(|(name, ..)| name == &item.name) {
+                diagnostics.report(
+                    Diagnostic::warning(format!("{} is in more than one option-setting region, keeping the first", item.name))
+                        .chip(&chip.name),
+                );
+                continue;
+            }
+            let uint = format_ident!("u{}", reg.bit_size);
+            let (value, erased) = match &item.array {
+                None => (quote!(#uint), quote!(#uint::MAX)),
+                Some(ir::Array::Regular(array)) => {
+                    let len = Literal::usize_unsuffixed(array.len as usize);
+                    (quote!([#uint; #len]), quote!([#uint::MAX; #len]))
+                }
+                Some(ir::Array::Cursed(_)) => continue,
+            };
+
+            // Field accessors only for single registers: an array's value is one word per element.
+            let fields = block.fields.get(&item.name).filter(|_| item.array.is_none());
+            let accessors = fields.into_iter().flatten().filter_map(|field| {
+                let (ir::BitOffset::Regular(offset), None) = (&field.bit_offset, &field.array) else { return None };
+                let getter = format_ident!("{}", heck::AsSnakeCase(&field.name).to_string());
+                let setter = format_ident!("with_{}", getter);
+                let offset = Literal::u32_unsuffixed(*offset);
+                let mask = hex((1u64 << field.bit_size) - 1);
+                let doc = format!(" {}", field.description.as_deref().and_then(|d| d.lines().next()).unwrap_or(&field.name));
+                let (ty, get, set) = match field.bit_size {
+                    1 => (quote!(bool), quote!((self.0 >> #offset) & #mask != 0), quote!((value as #uint))),
+                    bits => {
+                        let ty = format_ident!("u{}", if bits <= 8 { 8u32 } else if bits <= 16 { 16 } else { 32 });
+                        (quote!(#ty), quote!(((self.0 >> #offset) & #mask) as #ty), quote!(((value as #uint) & #mask)))
+                    }
+                };
+                Some(quote! {
+                    #[doc = #doc]
+                    #[inline(always)]
+                    pub const fn #getter(self) -> #ty {
+                        #get
+                    }
+                    #[doc = #doc]
+                    #[inline(always)]
+                    pub const fn #setter(self, value: #ty) -> Self {
+                        Self(self.0 & !(#mask << #offset) | #set << #offset)
+                    }
+                })
+            });
+
+            let ty = format_ident!("{}", heck::AsPascalCase(&item.name).to_string());
+            let address = hex(mem.address + item.byte_offset as u64);
+            let section = format!(".{}.{}", mem.name.to_lowercase(), item.name.to_lowercase());
+            let doc = format!(" `{}` in `{}`.", item.name, mem.name);
+            types.push(quote! {
+                #[doc = #doc]
+                #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+                #[repr(transparent)]
+                pub struct #ty(pub #value);
+
+                impl #ty {
+                    pub const ADDRESS: usize = #address;
+                    /// The link section `option_setting!` places the value in.
+                    pub const SECTION: &'static str = #section;
+                    /// Erased flash, which leaves every setting at its default.
+                    pub const ERASED: Self = Self(#erased);
+                    #(#accessors)*
+                }
+            });
+            let static_name = format_ident!("__{}_{}", mem.name.to_uppercase(), item.name.to_uppercase());
+            placements.push((item.name.clone(), static_name, section, ty));
+        }
+    }
+    if placements.is_empty() {
+        return quote!();
+    }
+
+    let arms = |sections: bool| {
+        let arms = placements.iter().map(|(name, static_name, section, ty)| {
+            let name = format_ident!("{}", name);
+            let section = sections.then(|| quote!(#[link_section = #section]));
+            quote! {
+                (#name = $value:expr) => {
+                    #[used]
+                    #section
+                    static #static_name: $crate::option_setting::#ty = $value;
+                };
+            }
+        });
+        quote!(#(#arms)*)
+    };
+    let target_arms = arms(true);
+    // A mock build runs on the host, whose object format may not take these section names.
+    let mock_arms = arms(false);
+
+    quote! {
+        pub mod option_setting {
+            #(#types)*
+        }
+
+        /// Places values of the `option_setting` types in the link sections named by their
+        /// `SECTION`, which the linker script has to put at their `ADDRESS`:
+        ///
+        /// ```ignore
+        /// option_setting!(OFS0 = Ofs0::ERASED.with_iwdtstrt(true));
+        /// ```
+        ///
+        /// Registers left out stay erased. A register given twice is a duplicate definition.
+        #[macro_export]
+        macro_rules! option_setting {
+            ($($register:ident = $value:expr),* $(,)?) => {
+                $($crate::__option_setting!($register = $value);)*
+            };
+        }
+
+        #[cfg(not(feature = "mock"))]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __option_setting {
+            #target_arms
+        }
+
+        #[cfg(feature = "mock")]
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! __option_setting {
+            #mock_arms
+        }
+    }
+}
+
+/// `Event`, with the IEL slots each event can be linked in.
+///
+/// On parts with grouped events, events in different groups share numbers, so the enum is only
+/// numbered by its discriminants if no two events do.
+fn event_tokens(chip: &Chip) -> TokenStream {
+    let all_slots: Vec<u32> = (0..chip.interrupt_count).collect();
+    let slots_of = |irq: &Interrupt| irq.irq_number.clone().unwrap_or_else(|| all_slots.clone());
+
+    // Names for the same event (same number, same slots) become aliases of the first one.
+    let (variants, aliases) = enum_variants(
+        "Event",
+        chip.interrupts.iter().map(|irq| (irq, irq.name.as_str(), irq.description.as_deref())),
+        |irq| (irq.value, slots_of(irq)),
+    );
+
+    let numbered = variants.iter().map(|(irq, ..)| irq.value).collect::<BTreeSet<_>>().len() == variants.len();
+    let variant_items = variants.iter().map(|(irq, ident, doc)| {
+        let value = Literal::u32_unsuffixed(irq.value);
+        let discriminant = numbered.then(|| quote!(= #value));
+        quote! {
+            #doc
+            #ident #discriminant,
+        }
+    });
+    let repr = numbered.then(|| quote!(#[repr(u16)]));
+    let number = if numbered {
+        quote!(self as u16)
+    } else {
+        let arms = variants.iter().map(|(irq, ident, _)| {
+            let value = Literal::u16_unsuffixed(irq.value as u16);
+            quote!(Self::#ident => #value,)
+        });
+        quote!(match self { #(#arms)* })
+    };
+
+    let all_slot_lits = all_slots.iter().map(|n| Literal::u8_unsuffixed(*n as u8));
+    let slot_arms = variants.iter().filter_map(|(irq, ident, _)| {
+        let slots = irq.irq_number.as_ref()?.iter().map(|n| Literal::u8_unsuffixed(*n as u8));
+        Some(quote!(Self::#ident => &[#(#slots),*],))
+    });
+
+    quote! {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #repr
+        pub enum Event {
+            #(#variant_items)*
+        }
+        #aliases
+
+        impl Event {
+            const ALL_SLOTS: &'static [u8] = &[#(#all_slot_lits),*];
+
+            /// The event's number, as written to `IELSR.IELS`.
+            #[inline(always)]
+            pub const fn number(self) -> u16 {
+                #number
+            }
+
+            /// The IEL slots the event can be linked in. Grouped events can only use the slots of
+            /// their groups; every other event can use any slot.
+            pub const fn allowed_slots(self) -> &'static [u8] {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#slot_arms)*
+                    _ => Self::ALL_SLOTS,
+                }
+            }
+
+            /// Whether the event can be linked in IEL slot `slot`, for use in const assertions.
+            pub const fn allows_slot(self, slot: usize) -> bool {
+                let slots = self.allowed_slots();
+                let mut i = 0;
+                while i < slots.len() {
+                    if slots[i] as usize == slot {
+                        return true;
+                    }
+                    i += 1;
+                }
+                false
+            }
+        }
+    }
+}
+
+/// An enum variant: the entry it was made from, its name and its doc attribute.
+type EnumVariant<T> = (T, Ident, Option<TokenStream>);
+
+/// Variants of a generated enum `ty` from `(item, name, description)` entries, and an `impl ty`
+/// block of aliases for the entries whose `key` an earlier entry already has.
+fn enum_variants<'a, T, K: PartialEq>(
+    ty: &str,
+    entries: impl IntoIterator<Item = (T, &'a str, Option<&'a str>)>,
+    key: impl Fn(&T) -> K,
+) -> (Vec<EnumVariant<T>>, Option<TokenStream>) {
+    let mut variants: Vec<EnumVariant<T>> = Vec::new();
+    let mut aliases = Vec::new();
+    for (item, name, description) in entries {
+        let ident = format_ident!("{}", heck::AsPascalCase(name).to_string());
+        let doc = description.map(|d| {
+            let d = format!(" {d}");
+            quote!(#[doc = #d])
+        });
+        // Point the alias at the first entry with this key.
+        if let Some((_, original, _)) = variants.iter().find(|(v, ..)| key(v) == key(&item)) {
+            aliases.push(quote! {
+                #doc
+                pub const #ident: Self = Self::#original;
+            });
+            continue;
+        }
+        variants.push((item, ident, doc));
+    }
+    let ty = format_ident!("{ty}");
+    let aliases = (!aliases.is_empty()).then(|| {
+        quote! {
+            #[allow(non_upper_case_globals)]
+            impl #ty {
+                #(#aliases)*
+            }
+        }
+    });
+    (variants, aliases)
+}
+
+/// Variants of an enum `ty` of `(name, value, description)` numbered by value, and its aliases.
+fn value_enum_variants(ty: &str, entries: &[(&str, u32, Option<&str>)]) -> (Vec<TokenStream>, Option<TokenStream>) {
+    let (variants, aliases) = enum_variants(ty, entries.iter().map(|&(name, value, description)| (value, name, description)), |v| *v);
+    let variants = variants
+        .into_iter()
+        .map(|(value, ident, doc)| {
+            let value = Literal::u32_unsuffixed(value);
+            quote! {
+                #doc
+                #ident = #value,
+            }
+        })
+        .collect();
+    (variants, aliases)
+}
+
+/// `elc::Event`, numbered as the ELC numbers events, and `elc::Destination`, numbered by the
+/// `ELSR` register each destination is linked through.
+fn elc_tokens(elc: &Elc) -> TokenStream {
+    let events: Vec<_> = elc.events.iter().map(|e| (e.name.as_str(), e.value, e.description.as_deref())).collect();
+    let (event_variants, event_aliases) = value_enum_variants("Event", &events);
+    let destinations: Vec<_> = elc.destinations.iter().map(|d| (d.name.as_str(), d.index, None)).collect();
+    let (destination_variants, destination_aliases) = value_enum_variants("Destination", &destinations);
+    quote! {
+        /// Event Link Controller: any `Event` can be linked to any `Destination` by writing it to
+        /// `ELSR[destination]`.
+        pub mod elc {
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            #[repr(u16)]
+            pub enum Event {
+                #(#event_variants)*
+            }
+            #event_aliases
+
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            #[repr(u8)]
+            pub enum Destination {
+                #(#destination_variants)*
+            }
+            #destination_aliases
+        }
+    }
+}
+
+/// The cfg set for a package, e.g. `package_lqfp64`. The feature is the same with `-`.
+fn package_cfg(package: &str) -> String {
+    let name: String = package.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' }).collect();
+    format!("package_{name}")
+}
+
+fn pin_name(port: u8, number: u8) -> String {
+    format!("P{port:X}{number:02}")
+}
+
+/// Per-pin constants: port and pin number, the address of the pin's PmnPFS register, and the
+/// register block of its PORT.
+fn pin_consts(
+    chip: &Chip,
+    port_pins: &BTreeSet<(u8, u8)>,
+    block_map: &BTreeMap<String, registers::Block>,
+    regs_types: &BTreeMap<&str, (Ident, Ident)>,
+    diagnostics: &Diagnostics,
+) -> Vec<TokenStream> {
+    // PmnPFS is an array of 16 registers per port.
+    let pfs = chip.peripherals.iter().find(|p| p.peri_type == "pfs").and_then(|pfs| {
+        let mod_name = format!("{}_{}", pfs.peri_type, pfs.version);
+        let item = block_map.get(&mod_name)?.registers.get("PmnPFS")?;
+        match &item.array {
+            Some(ir::Array::Regular(array)) => Some((pfs.address + item.byte_offset as u64, array.stride as u64)),
+            _ => None,
+        }
+    });
+    if pfs.is_none() && !port_pins.is_empty() {
+        diagnostics.report(Diagnostic::warning("no PFS register block with a PmnPFS array").chip(&chip.name));
+    }
+
+    let mut missing_ports = BTreeSet::new();
+    let consts = port_pins
+        .iter()
+        .map(|&(port, number)| {
+            let pfs_address = pfs.map(|(base, stride)| {
+                let address = hex(base + (port as u64 * 16 + number as u64) * stride);
+                quote! {
+                    /// Address of the pin's PmnPFS register.
+                    pub const PFS_ADDRESS: usize = #address;
+                }
+            });
+            let port_name = format!("PORT{port:X}");
+            let port_regs = match regs_types.get(port_name.as_str()) {
+                Some((mod_name, block_name)) => {
+                    let port_peri = format_ident!("{}", port_name);
+                    Some(quote! {
+                        /// Register block of the pin's port.
+                        pub const PORT_REGS: crate::_peripherals::#mod_name::#block_name = #port_peri::REGS;
+                    })
+                }
+                None => {
+                    missing_ports.insert(port_name);
+                    None
+                }
+            };
+            let port = Literal::u8_unsuffixed(port);
+            let number = Literal::u8_unsuffixed(number);
+            quote! {
+                pub const PORT: u8 = #port;
+                pub const NUMBER: u8 = #number;
+                #pfs_address
+                #port_regs
+            }
+        })
+        .collect();
+
+    for port in missing_ports {
+        diagnostics.report(Diagnostic::warning(format!("pins on {port}, but there is no such peripheral")).chip(&chip.name));
+    }
+    consts
+}
+
+/// `MstpPeripheral` for a peripheral with a module stop bit. The bit's register is looked up in the
+/// chip's MSTP block, then SYSC, which holds MSTPCRA on some chips.
+fn mstp_tokens(
+    chip: &Chip,
+    peri: &Peripheral,
+    block_map: &BTreeMap<String, registers::Block>,
+    diagnostics: &Diagnostics,
+) -> Option<TokenStream> {
+    let mstp = peri.mstp.as_ref()?;
+    let find = |peri_type: &str, register: &str| {
+        chip.peripherals.iter().find(|p| {
+            let mod_name = format!("{}_{}", p.peri_type.to_lowercase(), p.version.to_lowercase());
+            p.peri_type == peri_type && block_map.get(&mod_name).is_some_and(|b| b.registers.contains_key(register))
+        })
+    };
+
+    let Some(owner) = find("mstp", &mstp.register).or_else(|| find("sysc", &mstp.register)) else {
+        diagnostics.report(
+            Diagnostic::warning(format!(
+                "{}'s module stop bit is in {}, which neither the MSTP nor the SYSC register block has",
+                peri.name, mstp.register
+            ))
+            .chip(&chip.name),
+        );
+        return None;
+    };
+    // The impl goes in `peripherals`, next to the peripherals it uses.
+    let owner_name = format_ident!("{}", owner.name);
+    let mstpcr = RawRegister::new(quote!(#owner_name), owner, &mstp.register, block_map);
+    let bit = Literal::u32_unsuffixed(mstp.bit);
+    let name = format_ident!("{}", peri.name);
+
+    // Protected registers are unlocked through PRCR around the write, which restores PRCR after.
+    let protected = PRC1_PROTECTED.contains(&mstp.register.as_str());
+    let (unlock, lock) = match (protected, find("sysc", "PRCR")) {
+        (true, Some(sysc)) => {
+            let sysc_name = format_ident!("{}", sysc.name);
+            let prcr = RawRegister::new(quote!(#sysc_name), sysc, "PRCR", block_map);
+            let read = prcr.read();
+            let unlock = prcr.write(quote!(0xa500 | prcr | 0x02));
+            let lock = prcr.write(quote!(0xa500 | prcr));
+            (quote!(let prcr = #read & 0x00ff; #unlock;), quote!(#lock;))
+        }
+        (true, None) => {
+            diagnostics.report(
+                Diagnostic::warning(format!("{} is in {}, but there is no SYSC block with PRCR", peri.name, mstp.register))
+                    .chip(&chip.name),
+            );
+            (quote!(), quote!())
+        }
+        (false, _) => (quote!(), quote!()),
+    };
+
+    let read = mstpcr.read();
+    let enable = mstpcr.write(quote!(#read & !(1 << #bit)));
+    let disable = mstpcr.write(quote!(#read | 1 << #bit));
+    Some(quote! {
+        impl crate::MstpPeripheral for #name {
+            fn enable_clock() {
+                critical_section::with(|_| {
+                    #unlock
+                    #enable;
+                    #lock
+                });
+            }
+            fn disable_clock() {
+                critical_section::with(|_| {
+                    #unlock
+                    #disable;
+                    #lock
+                });
+            }
+            fn is_clock_enabled() -> bool {
+                #read & (1 << #bit) == 0
+            }
+        }
+    })
+}
+
+/// Module stop registers write-protected by `PRCR.PRC1`. Only MSTPCRA, in the SYSTEM block, is;
+/// MSTPCRB to MSTPCRE in the MSTP block can be written freely (see "Register Write Protection" in
+/// the hardware manuals).
+const PRC1_PROTECTED: &[&str] = &["MSTPCRA"];
+
+/// Whole-register access to a peripheral's register. chiptool renders a register with a fieldset
+/// as a newtype over the integer and one without as the integer itself.
+struct RawRegister {
+    accessor: TokenStream,
+    fieldset: bool,
+}
+
+impl RawRegister {
+    /// `path` names the peripheral from where the code goes, e.g. `peripherals::SYSC`.
+    fn new(path: TokenStream, peri: &Peripheral, register: &str, block_map: &BTreeMap<String, registers::Block>) -> Self {
+        let accessor = format_ident!("{}", heck::AsSnakeCase(register).to_string());
+        Self::with_accessor(quote!(#path::REGS.#accessor()), peri, register, block_map)
+    }
+
+    /// `accessor` reaches the register, e.g. an element of a register array.
+    fn with_accessor(
+        accessor: TokenStream,
+        peri: &Peripheral,
+        register: &str,
+        block_map: &BTreeMap<String, registers::Block>,
+    ) -> Self {
+        let mod_name = format!("{}_{}", peri.peri_type.to_lowercase(), peri.version.to_lowercase());
+        let item = block_map.get(&mod_name).and_then(|b| b.registers.get(register));
+        let fieldset = matches!(item.map(|i| &i.inner), Some(ir::BlockItemInner::Register(ir::Register { fieldset: Some(_), .. })));
+        Self { accessor, fieldset }
+    }
+
+    fn read(&self) -> TokenStream {
+        let accessor = &self.accessor;
+        match self.fieldset {
+            true => quote!(#accessor.read().0),
+            false => quote!(#accessor.read()),
+        }
+    }
+
+    fn write(&self, value: TokenStream) -> TokenStream {
+        let accessor = &self.accessor;
+        match self.fieldset {
+            true => quote!(#accessor.write(|w| w.0 = #value)),
+            false => quote!(#accessor.write_value(#value)),
+        }
+    }
+}
+
+fn metadata_tokens(chip: &Chip, block_map: &BTreeMap<String, registers::Block>) -> TokenStream {
+    let name = &chip.name;
+    let family = &chip.family;
+    let core = &chip.core;
+    let interrupt_count = Literal::usize_unsuffixed(chip.interrupt_count as usize);
+
+    let memory = chip.memory.iter().map(|mem| {
+        let name = &mem.name;
+        let kind = &mem.kind;
+        let address = Literal::u64_unsuffixed(mem.address);
+        let size = Literal::u64_unsuffixed(mem.size);
+        quote! {
+            MemoryRegion {
+                name: #name,
+                kind: #kind,
+                address: #address,
+                size: #size,
+            }
+        }
+    });
+
+    let peripherals = chip.peripherals.iter().map(|peri| {
+        let name = &peri.name;
+        let address = Literal::u64_unsuffixed(peri.address);
+        let kind = &peri.peri_type;
+        let version = &peri.version;
+        let mstp = match &peri.mstp {
+            Some(mstp) => {
+                let register = &mstp.register;
+                let bit = Literal::u32_unsuffixed(mstp.bit);
+                quote!(Some(Mstp { register: #register, bit: #bit }))
+            }
+            None => quote!(None),
+        };
+        let bit_width = match peri.bit_width {
+            Some(bw) => {
+                let bw = Literal::u32_unsuffixed(bw);
+                quote!(Some(#bw))
+            }
+            None => quote!(None),
+        };
+        let clock = match &peri.clock {
+            Some(clock) => quote!(Some(#clock)),
+            None => quote!(None),
+        };
+        let instance = match &peri.instance {
+            Some(instance) => {
+                let group = &instance.group;
+                let index = Literal::u32_unsuffixed(instance.index);
+                quote!(Some(Instance { group: #group, index: #index }))
+            }
+            None => quote!(None),
+        };
+        quote! {
+            Peripheral {
+                name: #name,
+                address: #address,
+                kind: #kind,
+                version: #version,
+                mstp: #mstp,
+                bit_width: #bit_width,
+                clock: #clock,
+                instance: #instance,
+            }
+        }
+    });
+
+    let resets = register_resets(chip, block_map).into_iter().map(|reset| {
+        let RegisterReset { peripheral, register, .. } = reset;
+        let address = hex(reset.address);
+        let bit_size = Literal::u32_unsuffixed(reset.bit_size);
+        let value = hex(reset.value);
+        quote! {
+            RegisterReset {
+                peripheral: #peripheral,
+                register: #register,
+                address: #address,
+                bit_size: #bit_size,
+                value: #value,
+            }
+        }
+    });
+
+    let events = chip.interrupts.iter().map(|irq| {
+        let name = heck::AsSnakeCase(&irq.name).to_string().to_uppercase();
+        let id = Literal::u32_unsuffixed(irq.value);
+        let slots = irq.irq_number.iter().flatten().map(|n| Literal::u32_unsuffixed(*n));
+        let dtc = irq.dtc;
+        let dmac = irq.dmac;
+        quote! {
+            Event {
+                name: #name,
+                id: #id,
+                irq_slots: &[#(#slots),*],
+                dtc: #dtc,
+                dmac: #dmac,
+            }
+        }
+    });
+
+    let dtc = chip.dma.dtc;
+    let dmac_channels = Literal::u32_unsuffixed(chip.dma.dmac_channels);
+
+    let clocks = match &chip.clocks {
+        Some(clocks) => {
+            let clocks = clocks_tokens(clocks);
+            quote!(Some(#clocks))
+        }
+        None => quote!(None),
+    };
+
+    let elc_events = chip.elc.iter().flat_map(|elc| &elc.events).map(|e| {
+        let name = heck::AsSnakeCase(&e.name).to_string().to_uppercase();
+        let id = Literal::u32_unsuffixed(e.value);
+        quote!(ElcEvent { name: #name, id: #id })
+    });
+    let elc_destinations = chip.elc.iter().flat_map(|elc| &elc.destinations).map(|d| {
+        let name = &d.name;
+        let index = Literal::u32_unsuffixed(d.index);
+        quote!(ElcDestination { name: #name, index: #index })
+    });
+
+    let packages = chip.packages.iter().map(|pkg| {
+        let name = &pkg.package;
+        let pins = pkg.pins.iter().map(|pin| {
+            let position = &pin.position;
+            let signals = &pin.signals;
+            let port = match pin.port {
+                Some(port) => quote!(Some(#port)),
+                None => quote!(None),
+            };
+            let number = match pin.number {
+                Some(number) => quote!(Some(#number)),
+                None => quote!(None),
+            };
+            let functions = pin.functions.iter().map(|f| {
+                let peripheral = &f.peripheral;
+                let signal = &f.signal;
+                let psel = Literal::u8_unsuffixed(f.psel);
+                quote!(PinFunction { peripheral: #peripheral, signal: #signal, psel: #psel })
+            });
+            quote! {
+                Pin {
+                    position: #position,
+                    signals: &[#(#signals),*],
+                    port: #port,
+                    number: #number,
+                    functions: &[#(#functions),*],
+                }
+            }
+        });
+        quote! {
+            Package {
+                name: #name,
+                pins: &[#(#pins),*],
+            }
+        }
+    });
+
+    quote! {
+        pub const NAME: &str = #name;
+        pub const FAMILY: &str = #family;
+        pub const CORE: &str = #core;
+        pub const INTERRUPT_COUNT: usize = #interrupt_count;
+        pub const MEMORY: &[MemoryRegion] = &[#(#memory),*];
+        pub const PERIPHERALS: &[Peripheral] = &[#(#peripherals),*];
+        pub const EVENTS: &[Event] = &[#(#events),*];
+        pub const DMA: Dma = Dma { dtc: #dtc, dmac_channels: #dmac_channels };
+        pub const CLOCKS: Option<Clocks> = #clocks;
+        pub const ELC_EVENTS: &[ElcEvent] = &[#(#elc_events),*];
+        pub const ELC_DESTINATIONS: &[ElcDestination] = &[#(#elc_destinations),*];
+        pub const PACKAGES: &[Package] = &[#(#packages),*];
+        pub const REGISTER_RESETS: &[RegisterReset] = &[#(#resets),*];
+    }
+}
+
+fn clocks_tokens(clocks: &Clocks) -> TokenStream {
+    let option = |value: Option<TokenStream>| match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    };
+    let hz = |value: Option<u32>| {
+        option(value.map(|v| {
+            let v = Literal::u32_unsuffixed(v);
+            quote!(#v)
+        }))
+    };
+    let multiplier = |value: Option<f64>| {
+        option(value.map(|v| {
+            let v = Literal::f32_unsuffixed(v as f32);
+            quote!(#v)
+        }))
+    };
+    let dividers = |dividers: &[ra_data_types::ClockDivider]| {
+        let dividers = dividers.iter().map(|d| {
+            let value = Literal::u8_unsuffixed(d.value as u8);
+            let divider = Literal::u16_unsuffixed(d.divider as u16);
+            quote!(ClockDivider { value: #value, divider: #divider })
+        });
+        quote!(&[#(#dividers),*])
+    };
+
+    let manual_limits = clocks.manual_limits;
+    let sources = clocks.sources.iter().map(|s| {
+        let name = &s.name;
+        let select = Literal::u8_unsuffixed(s.select as u8);
+        let frequencies = s.frequencies.iter().map(|f| Literal::u32_unsuffixed(*f));
+        quote!(ClockSource { name: #name, select: #select, frequencies: &[#(#frequencies),*] })
+    });
+    let domains = clocks.domains.iter().map(|d| {
+        let name = &d.name;
+        let register = &d.register;
+        let field = &d.field;
+        let dividers = dividers(&d.dividers);
+        let max_frequency = hz(d.max_frequency);
+        quote! {
+            ClockDomain {
+                name: #name,
+                register: #register,
+                field: #field,
+                dividers: #dividers,
+                max_frequency: #max_frequency,
+            }
+        }
+    });
+    let pll = option(clocks.pll.as_ref().map(|pll| {
+        let input_dividers = dividers(&pll.input_dividers);
+        let input_min_frequency = hz(pll.input_min_frequency);
+        let input_max_frequency = hz(pll.input_max_frequency);
+        let output_min_frequency = hz(pll.output_min_frequency);
+        let output_max_frequency = hz(pll.output_max_frequency);
+        let multiplier_min = multiplier(pll.multiplier_min);
+        let multiplier_max = multiplier(pll.multiplier_max);
+        quote! {
+            Pll {
+                input_dividers: #input_dividers,
+                input_min_frequency: #input_min_frequency,
+                input_max_frequency: #input_max_frequency,
+                output_min_frequency: #output_min_frequency,
+                output_max_frequency: #output_max_frequency,
+                multiplier_min: #multiplier_min,
+                multiplier_max: #multiplier_max,
+            }
+        }
+    }));
+
+    quote! {
+        Clocks {
+            sources: &[#(#sources),*],
+            domains: &[#(#domains),*],
+            pll: #pll,
+            manual_limits: #manual_limits,
+        }
+    }
+}
+
+/// The family (`ra6m5`), series (`ra6`) and core (`cortex-m33`) features of a chip.
+fn chip_groups(chip: &Chip) -> Vec<String> {
+    let family = chip.family.to_lowercase();
+    let family = if family.starts_with("r7f") || family.starts_with("r7k") {
+        format!("ra{}", &family[4..])
+    } else {
+        family
+    };
+    let series = family.get(..3).unwrap_or(&family).to_string();
+    vec![family, series, chip.core.to_lowercase()]
+}
+
+/// `peri_<instance>` for every peripheral (`peri_sci0`) and `<kind>_<version>` for every register
+/// block version (`gpt_v1`, `icu_ra4m2`), sorted.
+fn peripheral_cfgs(chip: &Chip) -> Vec<String> {
+    let instances: BTreeSet<_> = chip.peripherals.iter().map(|p| format!("peri_{}", p.name.to_lowercase())).collect();
+    let versions: BTreeSet<_> = chip
+        .peripherals
+        .iter()
+        .map(|p| format!("{}_{}", p.peri_type.to_lowercase(), p.version.to_lowercase()))
+        .collect();
+    instances.into_iter().chain(versions).collect()
+}
+
+/// The table build.rs includes to find the cfgs of the selected chip.
+fn cfgs_tokens(chip_cfgs: &[(String, Vec<String>)], chip_packages: &[(String, Vec<String>)]) -> TokenStream {
+    let chips = chip_cfgs.iter().map(|(chip, cfgs)| quote!((#chip, &[#(#cfgs),*])));
+    let packages = chip_packages.iter().map(|(chip, cfgs)| quote!((#chip, &[#(#cfgs),*])));
+    quote! {
+        pub const CHIP_CFGS: &[(&str, &[&str])] = &[#(#chips),*];
+        pub const CHIP_PACKAGES: &[(&str, &[&str])] = &[#(#packages),*];
+    }
+}
+
+fn target_for_core(core: &str) -> &'static str {
+    match core {
+        "Cortex-M23" => "thumbv6m-none-eabi",
+        "Cortex-M33" => "thumbv8m.main-none-eabihf",
+        "Cortex-M4" => "thumbv7em-none-eabi",
+        "Cortex-M85" => "thumbv8m.main-none-eabihf",
+        _ => "thumbv7m-none-eabi", // Default
+    }
+}
+
+/// Sets `key` in the `[package]` table of the Cargo.toml template.
+fn set_package_field(cargo_toml: &mut String, key: &str, value: &str) -> anyhow::Result<()> {
+    let prefix = format!("{key} = ");
+    let line = cargo_toml
+        .lines()
+        .find(|l| l.starts_with(&prefix))
+        .with_context(|| format!("no `{key}` in res/Cargo.toml"))?
+        .to_string();
+    *cargo_toml = cargo_toml.replacen(&line, &format!("{prefix}{value:?}"), 1);
+    Ok(())
+}
+
+fn hex(value: u64) -> Literal {
+    Literal::from_str(&format!("0x{:08x}", value)).unwrap()
+}
+
+/// Formats generated code in-process, so generation doesn't depend on a `rustfmt` binary.
+fn format_file(tokens: TokenStream) -> anyhow::Result<String> {
+    let file: syn::File = syn::parse2(tokens)?;
+    Ok(format!("{}\n{}", AUTOGENERATED_TEXT, prettyplease::unparse(&file)))
+}
@@ -1,8 +1,8 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::{
     fs::{self, File},
     io::Write,
-    path::Path,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
@@ -12,11 +12,31 @@ use chiptool::{
     ir, transform,
 };
 use proc_macro2::TokenStream;
+use quote::quote;
+use ra_data_types::diagnostics::{Diagnostic, Diagnostics};
 use regex::Regex;
 
-use crate::{rustfmt, AUTOGENERATED_TEXT};
+use crate::{format_file, AUTOGENERATED_TEXT};
 
-pub fn generate(out_dir: &Path) -> anyhow::Result<BTreeMap<String, String>> {
+/// A generated register block module.
+pub struct Block {
+    /// The block's type name, e.g. `Mstp`.
+    pub name: String,
+    /// The block's top-level items by their name in the register data, e.g. `MSTPCRA`.
+    pub registers: BTreeMap<String, ir::BlockItem>,
+    /// Reset values of the registers that have one, by the same names.
+    pub resets: BTreeMap<String, u64>,
+    /// Fields of the registers that have a fieldset, by the same names.
+    pub fields: BTreeMap<String, Vec<ir::Field>>,
+}
+
+/// Generates a module per register block in `registers_dir`, or only those named in `only`.
+pub fn generate(
+    registers_dir: &Path,
+    out_dir: &Path,
+    only: Option<&BTreeSet<String>>,
+    diagnostics: &Diagnostics,
+) -> anyhow::Result<BTreeMap<String, Block>> {
     let mut block_map = BTreeMap::new();
     let common = out_dir.join("src/common.rs");
 
@@ -27,104 +47,215 @@ pub fn generate(out_dir: &Path) -> anyhow::Result<BTreeMap<String, String>> {
     // Common module
     fs::write(common, generate::COMMON_MODULE).unwrap();
 
-    let options = generate::Options::new()
-        .with_common_module(CommonModule::External(TokenStream::from_str("crate::common").unwrap()));
+    let mut paths = fs::read_dir(registers_dir)
+        .context(format!("failed to read registers directory: {:?}", registers_dir))?
+        .map(|e| e.map(|e| e.path()))
+        .filter(|p| p.as_ref().map_or(true, |p| p.is_file()))
+        .filter(|p| {
+            let stem = p.as_ref().ok().and_then(|p| p.file_stem()).map(|s| s.to_string_lossy());
+            match (only, stem) {
+                (Some(only), Some(stem)) => only.contains(stem.as_ref()),
+                _ => true,
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
 
-    for entry in fs::read_dir("build/data/registers")? {
-        let entry = entry?;
-        let f = entry.path();
+    let for_each_file = |f: &PathBuf| {
+        // Built per file: the options hold a `TokenStream`, which can't be shared between threads.
+        let options = generate::Options::new()
+            .with_common_module(CommonModule::External(TokenStream::from_str("crate::common").unwrap()));
+        let generated = generate_block(f, &options, &peripherals_dir);
+        diagnostics.ok(generated, |e| Diagnostic::error(e).file(f))
+    };
 
-        if !f.is_file() {
-            continue;
-        }
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        block_map.extend(paths.par_iter().filter_map(for_each_file).collect::<Vec<_>>());
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        block_map.extend(paths.iter().filter_map(for_each_file));
+    }
 
-        let ctx = format!("{:?}", f.file_name());
+    // Only list the blocks generated by this run, in sorted order, rather than whatever is in the directory.
+    let mut mod_rs = File::create(peripherals_dir.join("mod.rs")).unwrap();
+    writeln!(mod_rs, "{}", AUTOGENERATED_TEXT).unwrap();
+    for name in block_map.keys() {
+        writeln!(mod_rs, "pub mod {};", name).unwrap();
+    }
 
-        let mut ir: ir::IR = serde_json::from_str(&fs::read_to_string(&f).unwrap())
-            .context(ctx.clone())
-            .expect("Error reading registers");
+    Ok(block_map)
+}
 
-        let block_name = ir.blocks.keys().next().unwrap().clone();
-        let block_name_pascal = heck::AsPascalCase(&block_name).to_string();
+fn generate_block(f: &Path, options: &generate::Options, peripherals_dir: &Path) -> anyhow::Result<(String, Block)> {
+    let mut json: serde_json::Value = serde_json::from_str(&fs::read_to_string(f)?).context("Error reading registers")?;
+    let resets = take_reset_values(&mut json);
+    let mut ir: ir::IR = serde_json::from_value(json).context("Error reading registers")?;
 
-        transform::expand_extends::ExpandExtends {}
-            .run(&mut ir)
-            .unwrap();
+    let block_name = ir.blocks.keys().next().context("No blocks in register file")?.clone();
+    let block_name_pascal = heck::AsPascalCase(&block_name).to_string();
 
-        transform::map_names(&mut ir, |k, s| match k {
-            transform::NameKind::Block => *s = s.to_string(),
-            transform::NameKind::Fieldset => *s = format!("regs::{}", s),
-            transform::NameKind::Enum => *s = format!("vals::{}", s),
-            _ => {}
-        });
+    transform::expand_extends::ExpandExtends {}.run(&mut ir)?;
+
+    let registers: BTreeMap<_, _> = ir.blocks[&block_name].items.iter().map(|i| (i.name.clone(), i.clone())).collect();
+    let fields = registers
+        .iter()
+        .filter_map(|(name, item)| match &item.inner {
+            ir::BlockItemInner::Register(ir::Register { fieldset: Some(fieldset), .. }) => {
+                Some((name.clone(), ir.fieldsets.get(fieldset)?.fields.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+
+    // A fieldset gets a reset value if every register using it has the same one.
+    let mut fieldset_resets: BTreeMap<String, Option<u64>> = BTreeMap::new();
+    for (name, item) in &registers {
+        let ir::BlockItemInner::Register(reg) = &item.inner else { continue };
+        let Some(fieldset) = &reg.fieldset else { continue };
+        let mask = u64::MAX >> (64 - reg.bit_size.clamp(1, 64));
+        let reset = resets.get(name).map(|r| r & mask);
+        fieldset_resets
+            .entry(fieldset.clone())
+            .and_modify(|r| {
+                if *r != reset {
+                    *r = None
+                }
+            })
+            .or_insert(reset);
+    }
+
+    transform::map_names(&mut ir, |k, s| match k {
+        transform::NameKind::Block => *s = s.to_string(),
+        transform::NameKind::Fieldset => *s = format!("regs::{}", s),
+        transform::NameKind::Enum => *s = format!("vals::{}", s),
+        _ => {}
+    });
 
-        for (_, fs) in ir.fieldsets.iter_mut() {
-            let mut names = std::collections::HashSet::new();
-            for f in fs.fields.iter_mut() {
-                if names.contains(&f.name) {
-                    let mut i = 0;
-                    while names.contains(&format!("{}_{}", f.name, i)) {
-                        i += 1;
-                    }
-                    f.name = format!("{}_{}", f.name, i);
+    for (_, fs) in ir.fieldsets.iter_mut() {
+        let mut names = std::collections::HashSet::new();
+        for f in fs.fields.iter_mut() {
+            if names.contains(&f.name) {
+                let mut i = 0;
+                while names.contains(&format!("{}_{}", f.name, i)) {
+                    i += 1;
                 }
-                names.insert(f.name.clone());
+                f.name = format!("{}_{}", f.name, i);
             }
+            names.insert(f.name.clone());
         }
+    }
+
+    chiptool::transform::sort::Sort {}.run(&mut ir)?;
+    chiptool::transform::sanitize::Sanitize {}.run(&mut ir)?;
 
-        chiptool::transform::sort::Sort {}.run(&mut ir).unwrap();
-        chiptool::transform::sanitize::Sanitize {}.run(&mut ir).unwrap();
-
-        let items = generate::render(&ir, &options)
-            .context(ctx)
-            .expect("Failed to generate code for peripheral");
-
-        let name = f
-            .file_name()
-            .unwrap()
-            .to_string_lossy()
-            .replace(".json", "");
-        
-        block_map.insert(name.clone(), block_name_pascal);
-
-        let path = peripherals_dir.join(format!("{}.rs", name));
-        let mut file = File::create(&path).unwrap();
-
-        writeln!(file, "{AUTOGENERATED_TEXT}").unwrap();
-        writeln!(
-            file,
-            r"#![allow(clippy::missing_safety_doc)]
-            #![allow(clippy::identity_op)]
-            #![allow(clippy::unnecessary_cast)]
-            #![allow(clippy::erasing_op)]
-            #![allow(non_camel_case_types)]
-            #![allow(non_snake_case)]
-            #![allow(non_upper_case_globals)]"
-        )
-        .unwrap();
-
-        let items = items.to_string().replace("] ", "]\n");
-        let re = Regex::new("# *! *\\[.*\\]").unwrap();
-        let items = re.replace_all(&items, "");
-        writeln!(file, "{items}").unwrap();
-
-        file.flush().unwrap();
-        drop(file);
-
-        rustfmt(path);
+    let items = generate::render(&ir, options).context("Failed to generate code for peripheral")?;
+
+    let name = f
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .replace(".json", "");
+
+    let mut file: syn::File = syn::parse2(items).context("Failed to parse generated code for peripheral")?;
+    add_reset_values(&mut file, &fieldset_resets)?;
+    let items = quote!(#file).to_string();
+
+    let items = items.replace("] ", "]\n");
+    let re = Regex::new("# *! *\\[.*\\]").unwrap();
+    let items = re.replace_all(&items, "");
+    let items = TokenStream::from_str(&items).map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    let code = format_file(quote! {
+        #![allow(clippy::missing_safety_doc)]
+        #![allow(clippy::identity_op)]
+        #![allow(clippy::unnecessary_cast)]
+        #![allow(clippy::erasing_op)]
+        #![allow(non_camel_case_types)]
+        #![allow(non_snake_case)]
+        #![allow(non_upper_case_globals)]
+        #items
+    })?;
+    fs::write(peripherals_dir.join(format!("{}.rs", name)), code)?;
+
+    Ok((name, Block { name: block_name_pascal, registers, resets, fields }))
+}
+
+/// Makes the fieldsets in `file`'s `regs` module default to their reset value: adds a `RESET`
+/// const to each fieldset that has one and replaces chiptool's `Default` impl, which returns 0.
+fn add_reset_values(file: &mut syn::File, fieldset_resets: &BTreeMap<String, Option<u64>>) -> anyhow::Result<()> {
+    let resets: Vec<_> = fieldset_resets.iter().filter_map(|(name, reset)| Some((name, (*reset)?))).collect();
+    if resets.is_empty() {
+        return Ok(());
     }
+    let regs = file
+        .items
+        .iter_mut()
+        .find_map(|item| match item {
+            syn::Item::Mod(m) if m.ident == "regs" => m.content.as_mut().map(|(_, items)| items),
+            _ => None,
+        })
+        .context("no regs module for the fieldsets with reset values")?;
 
-    let mut mod_rs = File::create(peripherals_dir.join("mod.rs")).unwrap();
-    writeln!(mod_rs, "{}", AUTOGENERATED_TEXT).unwrap();
-    for entry in fs::read_dir(&peripherals_dir)? {
-        let entry = entry?;
-        let f = entry.path();
-        if f.file_name().unwrap() == "mod.rs" {
-            continue;
+    let mut impls = Vec::new();
+    let mut with_reset = BTreeSet::new();
+    for (name, reset) in resets {
+        let ty = regs
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Struct(s) if normalize(&s.ident.to_string()) == normalize(name) => Some(s.ident.clone()),
+                _ => None,
+            })
+            .with_context(|| format!("fieldset {name} has a reset value but no struct in regs"))?;
+        let reset = syn::LitInt::new(&format!("{reset:#x}"), proc_macro2::Span::call_site());
+        impls.push(quote! {
+            impl #ty {
+                #[doc = "Value after reset."]
+                pub const RESET: Self = Self(#reset);
+            }
+            impl Default for #ty {
+                #[inline(always)]
+                fn default() -> #ty {
+                    #ty::RESET
+                }
+            }
+        });
+        with_reset.insert(ty);
+    }
+
+    regs.retain(|item| match item {
+        syn::Item::Impl(i) if i.trait_.as_ref().is_some_and(|(_, t, _)| t.is_ident("Default")) => {
+            !matches!(&*i.self_ty, syn::Type::Path(t) if t.path.get_ident().is_some_and(|ty| with_reset.contains(ty)))
+        }
+        _ => true,
+    });
+    let impls: syn::File = syn::parse2(quote!(#(#impls)*))?;
+    regs.extend(impls.items);
+    Ok(())
+}
+
+/// Removes the `reset_value`s ra-data-gen adds to the top-level block's items, which aren't part
+/// of chiptool's IR, and returns them by item name.
+fn take_reset_values(json: &mut serde_json::Value) -> BTreeMap<String, u64> {
+    let mut resets = BTreeMap::new();
+    let Some(map) = json.as_object_mut() else { return resets };
+    let Some(block) = map.keys().filter(|k| k.starts_with("block/")).min().cloned() else {
+        return resets;
+    };
+    let items = map.get_mut(&block).and_then(|b| b.get_mut("items")).and_then(|i| i.as_array_mut());
+    for item in items.into_iter().flatten().filter_map(|i| i.as_object_mut()) {
+        let reset = item.remove("reset_value").and_then(|v| v.as_u64());
+        if let (Some(reset), Some(name)) = (reset, item.get("name").and_then(|n| n.as_str())) {
+            resets.insert(name.to_string(), reset);
         }
-        let name = f.file_stem().unwrap().to_string_lossy();
-        writeln!(mod_rs, "pub mod {};", name).unwrap();
     }
+    resets
+}
 
-    Ok(block_map)
+/// Compares fieldset names from the register data (`MSTPCRD`) with chiptool's (`Mstpcrd`).
+fn normalize(name: &str) -> String {
+    let name = name.rsplit("::").next().unwrap_or(name);
+    name.chars().filter(|c| c.is_ascii_alphanumeric()).map(|c| c.to_ascii_lowercase()).collect()
 }
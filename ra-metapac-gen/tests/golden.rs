@@ -0,0 +1,194 @@
+//! Golden-file tests: run ra-data-gen over its fixture sources, generate the PAC from its output
+//! and compare each chip's `pac.rs` and `metadata.rs` with `tests/golden/`.
+//!
+//! Run with `UPDATE_GOLDEN=1` to rewrite the golden files after an intended change.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use ra_data_types::diagnostics::Diagnostics;
+use ra_metapac_gen::Config;
+
+const CHIPS: [&str; 2] = ["r7fa2e1a92dfm", "r7fa6m5bh3cfc"];
+
+fn manifest_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn tmp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Runs both generators into a fresh directory named `name` and returns the generated crate.
+fn generate_pac(name: &str) -> PathBuf {
+    generate_pac_with_registers(name, &manifest_dir().join("../data/registers"))
+}
+
+fn generate_pac_with_registers(name: &str, registers_dir: &Path) -> PathBuf {
+    let dir = tmp_dir(name);
+    let diagnostics = Diagnostics::new();
+
+    let data_gen = manifest_dir().join("../ra-data-gen");
+    let inputs = ra_data_gen::generate::Inputs::parse(&data_gen.join("tests/fixtures/sources"), &diagnostics, |_| {})
+        .unwrap();
+    let options = ra_data_gen::generate::Options {
+        registers_dir: registers_dir.to_path_buf(),
+        out_dir: dir.join("data"),
+        provenance: false,
+    };
+    ra_data_gen::generate::generate(&inputs, &options, &diagnostics).unwrap();
+
+    let config = Config {
+        data_dir: dir.join("data"),
+        out_dir: dir.join("ra-metapac"),
+        ..Config::default()
+    };
+    ra_metapac_gen::generate(&config, &diagnostics).unwrap();
+    config.out_dir
+}
+
+#[test]
+fn chip_modules_match_golden_files() {
+    let pac = generate_pac("golden");
+    let golden_dir = manifest_dir().join("tests/golden");
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut mismatches = Vec::new();
+    for chip in CHIPS {
+        for file in ["pac.rs", "metadata.rs"] {
+            let actual = fs::read_to_string(pac.join("src/chips").join(chip).join(file)).unwrap();
+            let golden_path = golden_dir.join(chip).join(file);
+
+            if update {
+                fs::create_dir_all(golden_path.parent().unwrap()).unwrap();
+                fs::write(&golden_path, &actual).unwrap();
+                continue;
+            }
+
+            let expected = fs::read_to_string(&golden_path)
+                .unwrap_or_else(|_| panic!("missing golden file {golden_path:?}, run with UPDATE_GOLDEN=1"));
+            if actual != expected {
+                mismatches.push(format!("{chip}/{file}"));
+            }
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "generated chip modules differ from golden files (compare against {:?}, rerun with UPDATE_GOLDEN=1 if intended): {mismatches:?}",
+        pac.join("src/chips"),
+    );
+}
+
+#[test]
+fn generated_crate_builds_with_mock() {
+    let pac = generate_pac("mock");
+    for chip in CHIPS {
+        let status = Command::new(env!("CARGO"))
+            .arg("check")
+            .arg("--manifest-path")
+            .arg(pac.join("Cargo.toml"))
+            .arg("--features")
+            .arg(format!("{chip},metadata,mock"))
+            .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("mock-target"))
+            .status()
+            .unwrap();
+        assert!(status.success(), "cargo check of the generated crate failed for {chip}");
+    }
+}
+
+#[test]
+fn mock_registers_start_at_reset_values() {
+    let pac = generate_pac("mock-resets");
+    // A crate using the generated one, whose test reads a register nothing has written.
+    let user = pac.with_file_name("mock-resets-user");
+    fs::create_dir_all(user.join("src")).unwrap();
+    let manifest = format!(
+        "[package]\nname = \"mock-resets-user\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n[workspace]\n\n\
+         [dependencies]\nra-metapac = {{ path = {:?}, features = [\"r7fa6m5bh3cfc\", \"mock\"] }}\n",
+        pac.display().to_string(),
+    );
+    fs::write(user.join("Cargo.toml"), manifest).unwrap();
+    fs::write(
+        user.join("src/lib.rs"),
+        "#[test]\n\
+         fn mstpcrd_reads_its_reset_value() {\n    \
+             use ra_metapac::peripherals::MSTP;\n    \
+             assert_eq!(MSTP::REGS.mstpcrd().read().0, 0xffff_ffff);\n    \
+             MSTP::REGS.mstpcrd().write(|w| w.0 = 0);\n    \
+             assert_eq!(MSTP::REGS.mstpcrd().read().0, 0);\n    \
+             ra_metapac::mock::reset();\n    \
+             assert_eq!(MSTP::REGS.mstpcrd().read().0, 0xffff_ffff);\n\
+         }\n",
+    )
+    .unwrap();
+
+    let status = Command::new(env!("CARGO"))
+        .arg("test")
+        .arg("--manifest-path")
+        .arg(user.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("mock-target"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "reading an unwritten register through the mock failed");
+}
+
+#[test]
+fn option_setting_arrays_have_no_field_accessors() {
+    // OSIS has no fields in the register data; give it some in a copy to cover register arrays.
+    let registers = tmp_dir("array-fields-registers");
+    for entry in walkdir::WalkDir::new(manifest_dir().join("../data/registers")) {
+        let entry = entry.unwrap();
+        let path = registers.join(entry.path().strip_prefix(manifest_dir().join("../data/registers")).unwrap());
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(path).unwrap();
+        } else {
+            fs::copy(entry.path(), path).unwrap();
+        }
+    }
+    let idcode = registers.join("idcode/idcode_v1.yaml");
+    let yaml = fs::read_to_string(&idcode).unwrap().replace("    bit_size: 32\n", "    bit_size: 32\n    fieldset: OSIS\n")
+        + "fieldset/OSIS:\n  bit_size: 32\n  fields:\n  - name: ID\n    bit_offset: 0\n    bit_size: 8\n";
+    fs::write(&idcode, yaml).unwrap();
+
+    let pac = generate_pac_with_registers("array-fields", &registers);
+    let pac_rs = fs::read_to_string(pac.join("src/chips/r7fa6m5bh3cfc/pac.rs")).unwrap();
+    let osis = pac_rs.split("impl Osis {").nth(1).expect("no Osis type").split("\n    }\n").next().unwrap();
+    assert!(pac_rs.contains("pub struct Osis(pub [u32; 4]);"));
+    assert!(!osis.contains("fn id("), "{osis}");
+    assert!(pac_rs.contains("pub const fn with_hocoen(self, value: bool) -> Self"));
+}
+
+fn find_struct<'a>(items: &'a [syn::Item], name: &str) -> Option<&'a syn::ItemStruct> {
+    items.iter().find_map(|item| match item {
+        syn::Item::Struct(s) if s.ident == name => Some(s),
+        syn::Item::Mod(m) => find_struct(&m.content.as_ref()?.1, name),
+        _ => None,
+    })
+}
+
+#[test]
+fn pin_on_one_package_is_gated_behind_it() {
+    let pac = generate_pac("packages");
+    let pac_rs = fs::read_to_string(pac.join("src/chips/r7fa6m5bh3cfc/pac.rs")).unwrap();
+    let file = syn::parse_file(&pac_rs).unwrap();
+    let cfgs = |name: &str| -> Vec<String> {
+        let pin = find_struct(&file.items, name).unwrap_or_else(|| panic!("no {name}"));
+        pin.attrs.iter().filter(|a| a.path().is_ident("cfg")).map(|a| quote::quote!(#a).to_string()).collect()
+    };
+
+    // The RA6M5 fixture has LQFP176 and BGA176 pin configurations; only LQFP176 has P105.
+    assert_eq!(cfgs("P105"), [quote::quote!(#[cfg(any(package_lqfp176))]).to_string()]);
+    assert!(cfgs("P101").is_empty());
+}
+
+#[test]
+fn sci0_txd_on_p101_is_a_pin_function() {
+    let pac = generate_pac("pin-functions");
+    let pac_rs = fs::read_to_string(pac.join("src/chips/r7fa6m5bh3cfc/pac.rs")).unwrap();
+    let foreach = pac_rs.split("macro_rules! foreach_pin_function").nth(1).expect("no foreach_pin_function!");
+    assert!(foreach.split("macro_rules!").next().unwrap().contains("(P101, SCI0, TXD0, 4)"), "{foreach}");
+}
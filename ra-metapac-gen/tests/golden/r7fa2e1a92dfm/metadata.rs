@@ -0,0 +1,379 @@
+// This file is autogenerated by ra-metapac-gen: do not edit by hand.
+pub const NAME: &str = "R7FA2E1A92DFM";
+pub const FAMILY: &str = "R7FA2E1";
+pub const CORE: &str = "Cortex-M23";
+pub const INTERRUPT_COUNT: usize = 32;
+pub const MEMORY: &[MemoryRegion] = &[
+    MemoryRegion {
+        name: "FLASH",
+        kind: "Flash",
+        address: 0,
+        size: 131072,
+    },
+    MemoryRegion {
+        name: "RAM",
+        kind: "RAM",
+        address: 536887296,
+        size: 16384,
+    },
+    MemoryRegion {
+        name: "OPTION_SETTING",
+        kind: "OptionSetting",
+        address: 1024,
+        size: 256,
+    },
+    MemoryRegion {
+        name: "ID_CODE",
+        kind: "IdCode",
+        address: 16842776,
+        size: 16,
+    },
+];
+pub const PERIPHERALS: &[Peripheral] = &[
+    Peripheral {
+        name: "SYSC",
+        address: 1073864704,
+        kind: "sysc",
+        version: "ra2e1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("ICLK"),
+        instance: None,
+    },
+    Peripheral {
+        name: "MSTP",
+        address: 1074032640,
+        kind: "mstp",
+        version: "v3",
+        mstp: None,
+        bit_width: None,
+        clock: Some("ICLK"),
+        instance: None,
+    },
+    Peripheral {
+        name: "ICU",
+        address: 1073766400,
+        kind: "icu",
+        version: "ra2e1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("ICLK"),
+        instance: None,
+    },
+    Peripheral {
+        name: "ELC",
+        address: 1074008064,
+        kind: "elc",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: None,
+    },
+    Peripheral {
+        name: "PFS",
+        address: 1074006016,
+        kind: "pfs",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: None,
+    },
+    Peripheral {
+        name: "PORT0",
+        address: 1074003968,
+        kind: "port",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: Some(Instance {
+            group: "PORT",
+            index: 0,
+        }),
+    },
+    Peripheral {
+        name: "GPT320",
+        address: 1074233344,
+        kind: "timer",
+        version: "v5",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKD"),
+        instance: Some(Instance { group: "GPT", index: 0 }),
+    },
+    Peripheral {
+        name: "GPT164",
+        address: 1074234368,
+        kind: "timer",
+        version: "v5",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKD"),
+        instance: Some(Instance { group: "GPT", index: 4 }),
+    },
+];
+pub const EVENTS: &[Event] = &[
+    Event {
+        name: "GPT0_COUNTER_OVERFLOW",
+        id: 14,
+        irq_slots: &[2, 10, 18, 26],
+        dtc: true,
+        dmac: false,
+    },
+    Event {
+        name: "ICU_IRQ0",
+        id: 1,
+        irq_slots: &[0, 8, 16, 24],
+        dtc: true,
+        dmac: false,
+    },
+    Event {
+        name: "ICU_IRQ1",
+        id: 1,
+        irq_slots: &[1, 9, 17, 25],
+        dtc: true,
+        dmac: false,
+    },
+    Event {
+        name: "SCI0_RXI",
+        id: 13,
+        irq_slots: &[0, 4, 8, 12, 16, 20, 24, 28],
+        dtc: true,
+        dmac: false,
+    },
+    Event {
+        name: "SCI0_TXI",
+        id: 13,
+        irq_slots: &[1, 5, 9, 13, 17, 21, 25, 29],
+        dtc: true,
+        dmac: false,
+    },
+];
+pub const DMA: Dma = Dma { dtc: true, dmac_channels: 0 };
+pub const CLOCKS: Option<Clocks> = Some(Clocks {
+    sources: &[
+        ClockSource {
+            name: "HOCO",
+            select: 0,
+            frequencies: &[24000000, 32000000, 48000000, 64000000],
+        },
+        ClockSource {
+            name: "MOCO",
+            select: 1,
+            frequencies: &[8000000],
+        },
+        ClockSource {
+            name: "LOCO",
+            select: 2,
+            frequencies: &[32768],
+        },
+        ClockSource {
+            name: "MOSC",
+            select: 3,
+            frequencies: &[],
+        },
+        ClockSource {
+            name: "SOSC",
+            select: 4,
+            frequencies: &[32768],
+        },
+    ],
+    domains: &[
+        ClockDomain {
+            name: "ICLK",
+            register: "SCKDIVCR",
+            field: "ICK",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(48000000),
+        },
+        ClockDomain {
+            name: "PCLKB",
+            register: "SCKDIVCR",
+            field: "PCKB",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(32000000),
+        },
+        ClockDomain {
+            name: "PCLKD",
+            register: "SCKDIVCR",
+            field: "PCKD",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(64000000),
+        },
+    ],
+    pll: None,
+    manual_limits: true,
+});
+pub const ELC_EVENTS: &[ElcEvent] = &[
+    ElcEvent {
+        name: "ICU_IRQ0",
+        id: 1,
+    },
+    ElcEvent {
+        name: "ICU_IRQ1",
+        id: 2,
+    },
+    ElcEvent {
+        name: "GPT0_COUNTER_OVERFLOW",
+        id: 60,
+    },
+    ElcEvent {
+        name: "SCI0_RXI",
+        id: 113,
+    },
+];
+pub const ELC_DESTINATIONS: &[ElcDestination] = &[
+    ElcDestination {
+        name: "GPT_A",
+        index: 0,
+    },
+    ElcDestination {
+        name: "GPT_B",
+        index: 1,
+    },
+    ElcDestination {
+        name: "ADC0",
+        index: 8,
+    },
+    ElcDestination {
+        name: "ADC0_B",
+        index: 9,
+    },
+    ElcDestination {
+        name: "IOPORT1",
+        index: 14,
+    },
+];
+pub const PACKAGES: &[Package] = &[
+    Package {
+        name: "LQFP64",
+        pins: &[
+            Pin {
+                position: "1",
+                signals: &["p108"],
+                port: Some(1u8),
+                number: Some(8u8),
+                functions: &[],
+            },
+            Pin {
+                position: "2",
+                signals: &["p109"],
+                port: Some(1u8),
+                number: Some(9u8),
+                functions: &[],
+            },
+            Pin {
+                position: "3",
+                signals: &["VSS"],
+                port: None,
+                number: None,
+                functions: &[],
+            },
+            Pin {
+                position: "4",
+                signals: &["p500"],
+                port: Some(5u8),
+                number: Some(0u8),
+                functions: &[],
+            },
+        ],
+    },
+];
+pub const REGISTER_RESETS: &[RegisterReset] = &[
+    RegisterReset {
+        peripheral: "MSTP",
+        register: "MSTPCRD",
+        address: 0x40047008,
+        bit_size: 32,
+        value: 0xffffffff,
+    },
+];
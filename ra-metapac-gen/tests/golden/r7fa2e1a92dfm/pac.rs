@@ -0,0 +1,5753 @@
+// This file is autogenerated by ra-metapac-gen: do not edit by hand.
+pub mod peripherals {
+    #[derive(Copy, Clone)]
+    pub struct SYSC(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for SYSC {}
+    impl SYSC {
+        pub const REGS: crate::_peripherals::sysc_ra2e1::Sysc = unsafe {
+            crate::_peripherals::sysc_ra2e1::Sysc::from_ptr(0x4001e000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for SYSC {
+        type Target = crate::_peripherals::sysc_ra2e1::Sysc;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for SYSC {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[0]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct MSTP(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for MSTP {}
+    impl MSTP {
+        pub const REGS: crate::_peripherals::mstp_v3::Mstp = unsafe {
+            crate::_peripherals::mstp_v3::Mstp::from_ptr(0x40047000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for MSTP {
+        type Target = crate::_peripherals::mstp_v3::Mstp;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for MSTP {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[1]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct ICU(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for ICU {}
+    impl ICU {
+        pub const REGS: crate::_peripherals::icu_ra2e1::Icu = unsafe {
+            crate::_peripherals::icu_ra2e1::Icu::from_ptr(0x40006000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for ICU {
+        type Target = crate::_peripherals::icu_ra2e1::Icu;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for ICU {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[2]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct ELC(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for ELC {}
+    impl ELC {
+        pub const REGS: crate::_peripherals::elc_v1::Elc = unsafe {
+            crate::_peripherals::elc_v1::Elc::from_ptr(0x40041000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for ELC {
+        type Target = crate::_peripherals::elc_v1::Elc;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for ELC {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[3]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct PFS(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for PFS {}
+    impl PFS {
+        pub const REGS: crate::_peripherals::pfs_v1::Pfs = unsafe {
+            crate::_peripherals::pfs_v1::Pfs::from_ptr(0x40040800 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for PFS {
+        type Target = crate::_peripherals::pfs_v1::Pfs;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for PFS {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[4]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct PORT0(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for PORT0 {}
+    impl PORT0 {
+        pub const REGS: crate::_peripherals::port_v1::Port = unsafe {
+            crate::_peripherals::port_v1::Port::from_ptr(0x40040000 as *mut ())
+        };
+        pub const INDEX: usize = 0;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for PORT0 {
+        type Target = crate::_peripherals::port_v1::Port;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for PORT0 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[5]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct GPT320(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for GPT320 {}
+    impl GPT320 {
+        pub const REGS: crate::_peripherals::timer_v5::Gpt = unsafe {
+            crate::_peripherals::timer_v5::Gpt::from_ptr(0x40078000 as *mut ())
+        };
+        pub const INDEX: usize = 0;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for GPT320 {
+        type Target = crate::_peripherals::timer_v5::Gpt;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for GPT320 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[6]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct GPT164(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for GPT164 {}
+    impl GPT164 {
+        pub const REGS: crate::_peripherals::timer_v5::Gpt = unsafe {
+            crate::_peripherals::timer_v5::Gpt::from_ptr(0x40078400 as *mut ())
+        };
+        pub const INDEX: usize = 4;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for GPT164 {
+        type Target = crate::_peripherals::timer_v5::Gpt;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for GPT164 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[7]
+        }
+    }
+    pub const GPT_REGS: [Option<crate::_peripherals::timer_v5::Gpt>; 5] = [
+        Some(GPT320::REGS),
+        None,
+        None,
+        None,
+        Some(GPT164::REGS),
+    ];
+    pub const PORT_REGS: [Option<crate::_peripherals::port_v1::Port>; 1] = [
+        Some(PORT0::REGS),
+    ];
+    pub struct Peripherals {
+        #[cfg(feature = "embassy")]
+        pub sysc: embassy_hal_internal::Peri<'static, SYSC>,
+        #[cfg(not(feature = "embassy"))]
+        pub sysc: SYSC,
+        #[cfg(feature = "embassy")]
+        pub mstp: embassy_hal_internal::Peri<'static, MSTP>,
+        #[cfg(not(feature = "embassy"))]
+        pub mstp: MSTP,
+        #[cfg(feature = "embassy")]
+        pub icu: embassy_hal_internal::Peri<'static, ICU>,
+        #[cfg(not(feature = "embassy"))]
+        pub icu: ICU,
+        #[cfg(feature = "embassy")]
+        pub elc: embassy_hal_internal::Peri<'static, ELC>,
+        #[cfg(not(feature = "embassy"))]
+        pub elc: ELC,
+        #[cfg(feature = "embassy")]
+        pub pfs: embassy_hal_internal::Peri<'static, PFS>,
+        #[cfg(not(feature = "embassy"))]
+        pub pfs: PFS,
+        #[cfg(feature = "embassy")]
+        pub port0: embassy_hal_internal::Peri<'static, PORT0>,
+        #[cfg(not(feature = "embassy"))]
+        pub port0: PORT0,
+        #[cfg(feature = "embassy")]
+        pub gpt320: embassy_hal_internal::Peri<'static, GPT320>,
+        #[cfg(not(feature = "embassy"))]
+        pub gpt320: GPT320,
+        #[cfg(feature = "embassy")]
+        pub gpt164: embassy_hal_internal::Peri<'static, GPT164>,
+        #[cfg(not(feature = "embassy"))]
+        pub gpt164: GPT164,
+        #[cfg(feature = "embassy")]
+        pub iel0: embassy_hal_internal::Peri<'static, IEL0>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel0: IEL0,
+        #[cfg(feature = "embassy")]
+        pub iel1: embassy_hal_internal::Peri<'static, IEL1>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel1: IEL1,
+        #[cfg(feature = "embassy")]
+        pub iel2: embassy_hal_internal::Peri<'static, IEL2>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel2: IEL2,
+        #[cfg(feature = "embassy")]
+        pub iel3: embassy_hal_internal::Peri<'static, IEL3>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel3: IEL3,
+        #[cfg(feature = "embassy")]
+        pub iel4: embassy_hal_internal::Peri<'static, IEL4>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel4: IEL4,
+        #[cfg(feature = "embassy")]
+        pub iel5: embassy_hal_internal::Peri<'static, IEL5>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel5: IEL5,
+        #[cfg(feature = "embassy")]
+        pub iel6: embassy_hal_internal::Peri<'static, IEL6>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel6: IEL6,
+        #[cfg(feature = "embassy")]
+        pub iel7: embassy_hal_internal::Peri<'static, IEL7>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel7: IEL7,
+        #[cfg(feature = "embassy")]
+        pub iel8: embassy_hal_internal::Peri<'static, IEL8>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel8: IEL8,
+        #[cfg(feature = "embassy")]
+        pub iel9: embassy_hal_internal::Peri<'static, IEL9>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel9: IEL9,
+        #[cfg(feature = "embassy")]
+        pub iel10: embassy_hal_internal::Peri<'static, IEL10>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel10: IEL10,
+        #[cfg(feature = "embassy")]
+        pub iel11: embassy_hal_internal::Peri<'static, IEL11>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel11: IEL11,
+        #[cfg(feature = "embassy")]
+        pub iel12: embassy_hal_internal::Peri<'static, IEL12>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel12: IEL12,
+        #[cfg(feature = "embassy")]
+        pub iel13: embassy_hal_internal::Peri<'static, IEL13>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel13: IEL13,
+        #[cfg(feature = "embassy")]
+        pub iel14: embassy_hal_internal::Peri<'static, IEL14>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel14: IEL14,
+        #[cfg(feature = "embassy")]
+        pub iel15: embassy_hal_internal::Peri<'static, IEL15>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel15: IEL15,
+        #[cfg(feature = "embassy")]
+        pub iel16: embassy_hal_internal::Peri<'static, IEL16>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel16: IEL16,
+        #[cfg(feature = "embassy")]
+        pub iel17: embassy_hal_internal::Peri<'static, IEL17>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel17: IEL17,
+        #[cfg(feature = "embassy")]
+        pub iel18: embassy_hal_internal::Peri<'static, IEL18>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel18: IEL18,
+        #[cfg(feature = "embassy")]
+        pub iel19: embassy_hal_internal::Peri<'static, IEL19>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel19: IEL19,
+        #[cfg(feature = "embassy")]
+        pub iel20: embassy_hal_internal::Peri<'static, IEL20>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel20: IEL20,
+        #[cfg(feature = "embassy")]
+        pub iel21: embassy_hal_internal::Peri<'static, IEL21>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel21: IEL21,
+        #[cfg(feature = "embassy")]
+        pub iel22: embassy_hal_internal::Peri<'static, IEL22>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel22: IEL22,
+        #[cfg(feature = "embassy")]
+        pub iel23: embassy_hal_internal::Peri<'static, IEL23>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel23: IEL23,
+        #[cfg(feature = "embassy")]
+        pub iel24: embassy_hal_internal::Peri<'static, IEL24>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel24: IEL24,
+        #[cfg(feature = "embassy")]
+        pub iel25: embassy_hal_internal::Peri<'static, IEL25>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel25: IEL25,
+        #[cfg(feature = "embassy")]
+        pub iel26: embassy_hal_internal::Peri<'static, IEL26>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel26: IEL26,
+        #[cfg(feature = "embassy")]
+        pub iel27: embassy_hal_internal::Peri<'static, IEL27>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel27: IEL27,
+        #[cfg(feature = "embassy")]
+        pub iel28: embassy_hal_internal::Peri<'static, IEL28>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel28: IEL28,
+        #[cfg(feature = "embassy")]
+        pub iel29: embassy_hal_internal::Peri<'static, IEL29>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel29: IEL29,
+        #[cfg(feature = "embassy")]
+        pub iel30: embassy_hal_internal::Peri<'static, IEL30>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel30: IEL30,
+        #[cfg(feature = "embassy")]
+        pub iel31: embassy_hal_internal::Peri<'static, IEL31>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel31: IEL31,
+        #[cfg(feature = "embassy")]
+        pub p108: embassy_hal_internal::Peri<'static, P108>,
+        #[cfg(not(feature = "embassy"))]
+        pub p108: P108,
+        #[cfg(feature = "embassy")]
+        pub p109: embassy_hal_internal::Peri<'static, P109>,
+        #[cfg(not(feature = "embassy"))]
+        pub p109: P109,
+        #[cfg(feature = "embassy")]
+        pub p500: embassy_hal_internal::Peri<'static, P500>,
+        #[cfg(not(feature = "embassy"))]
+        pub p500: P500,
+    }
+    impl Peripherals {
+        pub unsafe fn steal() -> Self {
+            Self {
+                #[cfg(feature = "embassy")]
+                sysc: embassy_hal_internal::Peri::new_unchecked(SYSC(())),
+                #[cfg(not(feature = "embassy"))]
+                sysc: SYSC(()),
+                #[cfg(feature = "embassy")]
+                mstp: embassy_hal_internal::Peri::new_unchecked(MSTP(())),
+                #[cfg(not(feature = "embassy"))]
+                mstp: MSTP(()),
+                #[cfg(feature = "embassy")]
+                icu: embassy_hal_internal::Peri::new_unchecked(ICU(())),
+                #[cfg(not(feature = "embassy"))]
+                icu: ICU(()),
+                #[cfg(feature = "embassy")]
+                elc: embassy_hal_internal::Peri::new_unchecked(ELC(())),
+                #[cfg(not(feature = "embassy"))]
+                elc: ELC(()),
+                #[cfg(feature = "embassy")]
+                pfs: embassy_hal_internal::Peri::new_unchecked(PFS(())),
+                #[cfg(not(feature = "embassy"))]
+                pfs: PFS(()),
+                #[cfg(feature = "embassy")]
+                port0: embassy_hal_internal::Peri::new_unchecked(PORT0(())),
+                #[cfg(not(feature = "embassy"))]
+                port0: PORT0(()),
+                #[cfg(feature = "embassy")]
+                gpt320: embassy_hal_internal::Peri::new_unchecked(GPT320(())),
+                #[cfg(not(feature = "embassy"))]
+                gpt320: GPT320(()),
+                #[cfg(feature = "embassy")]
+                gpt164: embassy_hal_internal::Peri::new_unchecked(GPT164(())),
+                #[cfg(not(feature = "embassy"))]
+                gpt164: GPT164(()),
+                #[cfg(feature = "embassy")]
+                iel0: embassy_hal_internal::Peri::new_unchecked(IEL0(())),
+                #[cfg(not(feature = "embassy"))]
+                iel0: IEL0(()),
+                #[cfg(feature = "embassy")]
+                iel1: embassy_hal_internal::Peri::new_unchecked(IEL1(())),
+                #[cfg(not(feature = "embassy"))]
+                iel1: IEL1(()),
+                #[cfg(feature = "embassy")]
+                iel2: embassy_hal_internal::Peri::new_unchecked(IEL2(())),
+                #[cfg(not(feature = "embassy"))]
+                iel2: IEL2(()),
+                #[cfg(feature = "embassy")]
+                iel3: embassy_hal_internal::Peri::new_unchecked(IEL3(())),
+                #[cfg(not(feature = "embassy"))]
+                iel3: IEL3(()),
+                #[cfg(feature = "embassy")]
+                iel4: embassy_hal_internal::Peri::new_unchecked(IEL4(())),
+                #[cfg(not(feature = "embassy"))]
+                iel4: IEL4(()),
+                #[cfg(feature = "embassy")]
+                iel5: embassy_hal_internal::Peri::new_unchecked(IEL5(())),
+                #[cfg(not(feature = "embassy"))]
+                iel5: IEL5(()),
+                #[cfg(feature = "embassy")]
+                iel6: embassy_hal_internal::Peri::new_unchecked(IEL6(())),
+                #[cfg(not(feature = "embassy"))]
+                iel6: IEL6(()),
+                #[cfg(feature = "embassy")]
+                iel7: embassy_hal_internal::Peri::new_unchecked(IEL7(())),
+                #[cfg(not(feature = "embassy"))]
+                iel7: IEL7(()),
+                #[cfg(feature = "embassy")]
+                iel8: embassy_hal_internal::Peri::new_unchecked(IEL8(())),
+                #[cfg(not(feature = "embassy"))]
+                iel8: IEL8(()),
+                #[cfg(feature = "embassy")]
+                iel9: embassy_hal_internal::Peri::new_unchecked(IEL9(())),
+                #[cfg(not(feature = "embassy"))]
+                iel9: IEL9(()),
+                #[cfg(feature = "embassy")]
+                iel10: embassy_hal_internal::Peri::new_unchecked(IEL10(())),
+                #[cfg(not(feature = "embassy"))]
+                iel10: IEL10(()),
+                #[cfg(feature = "embassy")]
+                iel11: embassy_hal_internal::Peri::new_unchecked(IEL11(())),
+                #[cfg(not(feature = "embassy"))]
+                iel11: IEL11(()),
+                #[cfg(feature = "embassy")]
+                iel12: embassy_hal_internal::Peri::new_unchecked(IEL12(())),
+                #[cfg(not(feature = "embassy"))]
+                iel12: IEL12(()),
+                #[cfg(feature = "embassy")]
+                iel13: embassy_hal_internal::Peri::new_unchecked(IEL13(())),
+                #[cfg(not(feature = "embassy"))]
+                iel13: IEL13(()),
+                #[cfg(feature = "embassy")]
+                iel14: embassy_hal_internal::Peri::new_unchecked(IEL14(())),
+                #[cfg(not(feature = "embassy"))]
+                iel14: IEL14(()),
+                #[cfg(feature = "embassy")]
+                iel15: embassy_hal_internal::Peri::new_unchecked(IEL15(())),
+                #[cfg(not(feature = "embassy"))]
+                iel15: IEL15(()),
+                #[cfg(feature = "embassy")]
+                iel16: embassy_hal_internal::Peri::new_unchecked(IEL16(())),
+                #[cfg(not(feature = "embassy"))]
+                iel16: IEL16(()),
+                #[cfg(feature = "embassy")]
+                iel17: embassy_hal_internal::Peri::new_unchecked(IEL17(())),
+                #[cfg(not(feature = "embassy"))]
+                iel17: IEL17(()),
+                #[cfg(feature = "embassy")]
+                iel18: embassy_hal_internal::Peri::new_unchecked(IEL18(())),
+                #[cfg(not(feature = "embassy"))]
+                iel18: IEL18(()),
+                #[cfg(feature = "embassy")]
+                iel19: embassy_hal_internal::Peri::new_unchecked(IEL19(())),
+                #[cfg(not(feature = "embassy"))]
+                iel19: IEL19(()),
+                #[cfg(feature = "embassy")]
+                iel20: embassy_hal_internal::Peri::new_unchecked(IEL20(())),
+                #[cfg(not(feature = "embassy"))]
+                iel20: IEL20(()),
+                #[cfg(feature = "embassy")]
+                iel21: embassy_hal_internal::Peri::new_unchecked(IEL21(())),
+                #[cfg(not(feature = "embassy"))]
+                iel21: IEL21(()),
+                #[cfg(feature = "embassy")]
+                iel22: embassy_hal_internal::Peri::new_unchecked(IEL22(())),
+                #[cfg(not(feature = "embassy"))]
+                iel22: IEL22(()),
+                #[cfg(feature = "embassy")]
+                iel23: embassy_hal_internal::Peri::new_unchecked(IEL23(())),
+                #[cfg(not(feature = "embassy"))]
+                iel23: IEL23(()),
+                #[cfg(feature = "embassy")]
+                iel24: embassy_hal_internal::Peri::new_unchecked(IEL24(())),
+                #[cfg(not(feature = "embassy"))]
+                iel24: IEL24(()),
+                #[cfg(feature = "embassy")]
+                iel25: embassy_hal_internal::Peri::new_unchecked(IEL25(())),
+                #[cfg(not(feature = "embassy"))]
+                iel25: IEL25(()),
+                #[cfg(feature = "embassy")]
+                iel26: embassy_hal_internal::Peri::new_unchecked(IEL26(())),
+                #[cfg(not(feature = "embassy"))]
+                iel26: IEL26(()),
+                #[cfg(feature = "embassy")]
+                iel27: embassy_hal_internal::Peri::new_unchecked(IEL27(())),
+                #[cfg(not(feature = "embassy"))]
+                iel27: IEL27(()),
+                #[cfg(feature = "embassy")]
+                iel28: embassy_hal_internal::Peri::new_unchecked(IEL28(())),
+                #[cfg(not(feature = "embassy"))]
+                iel28: IEL28(()),
+                #[cfg(feature = "embassy")]
+                iel29: embassy_hal_internal::Peri::new_unchecked(IEL29(())),
+                #[cfg(not(feature = "embassy"))]
+                iel29: IEL29(()),
+                #[cfg(feature = "embassy")]
+                iel30: embassy_hal_internal::Peri::new_unchecked(IEL30(())),
+                #[cfg(not(feature = "embassy"))]
+                iel30: IEL30(()),
+                #[cfg(feature = "embassy")]
+                iel31: embassy_hal_internal::Peri::new_unchecked(IEL31(())),
+                #[cfg(not(feature = "embassy"))]
+                iel31: IEL31(()),
+                #[cfg(feature = "embassy")]
+                p108: embassy_hal_internal::Peri::new_unchecked(P108(())),
+                #[cfg(not(feature = "embassy"))]
+                p108: P108(()),
+                #[cfg(feature = "embassy")]
+                p109: embassy_hal_internal::Peri::new_unchecked(P109(())),
+                #[cfg(not(feature = "embassy"))]
+                p109: P109(()),
+                #[cfg(feature = "embassy")]
+                p500: embassy_hal_internal::Peri::new_unchecked(P500(())),
+                #[cfg(not(feature = "embassy"))]
+                p500: P500(()),
+            }
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct IEL0(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL0 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL1(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL1 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL2(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL2 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL3(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL3 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL4(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL4 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL5(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL5 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL6(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL6 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL7(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL7 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL8(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL8 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL9(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL9 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL10(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL10 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL11(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL11 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL12(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL12 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL13(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL13 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL14(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL14 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL15(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL15 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL16(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL16 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL17(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL17 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL18(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL18 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL19(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL19 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL20(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL20 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL21(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL21 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL22(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL22 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL23(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL23 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL24(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL24 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL25(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL25 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL26(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL26 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL27(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL27 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL28(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL28 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL29(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL29 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL30(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL30 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL31(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL31 {}
+    #[derive(Copy, Clone)]
+    pub struct P108(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P108 {}
+    impl P108 {
+        pub const PORT: u8 = 1;
+        pub const NUMBER: u8 = 8;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40040860;
+    }
+    #[derive(Copy, Clone)]
+    pub struct P109(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P109 {}
+    impl P109 {
+        pub const PORT: u8 = 1;
+        pub const NUMBER: u8 = 9;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40040864;
+    }
+    #[derive(Copy, Clone)]
+    pub struct P500(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P500 {}
+    impl P500 {
+        pub const PORT: u8 = 5;
+        pub const NUMBER: u8 = 0;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40040940;
+    }
+}
+#[macro_export]
+macro_rules! foreach_interrupt {
+    ($m:path, $($args:tt)*) => {
+        $m ! { $($args)* { IEL0 = 0, IEL1 = 1, IEL2 = 2, IEL3 = 3, IEL4 = 4, IEL5 = 5,
+        IEL6 = 6, IEL7 = 7, IEL8 = 8, IEL9 = 9, IEL10 = 10, IEL11 = 11, IEL12 = 12, IEL13
+        = 13, IEL14 = 14, IEL15 = 15, IEL16 = 16, IEL17 = 17, IEL18 = 18, IEL19 = 19,
+        IEL20 = 20, IEL21 = 21, IEL22 = 22, IEL23 = 23, IEL24 = 24, IEL25 = 25, IEL26 =
+        26, IEL27 = 27, IEL28 = 28, IEL29 = 29, IEL30 = 30, IEL31 = 31, } }
+    };
+    ($($m:tt)*) => {
+        $($m)* ! { IEL0 = 0, IEL1 = 1, IEL2 = 2, IEL3 = 3, IEL4 = 4, IEL5 = 5, IEL6 = 6,
+        IEL7 = 7, IEL8 = 8, IEL9 = 9, IEL10 = 10, IEL11 = 11, IEL12 = 12, IEL13 = 13,
+        IEL14 = 14, IEL15 = 15, IEL16 = 16, IEL17 = 17, IEL18 = 18, IEL19 = 19, IEL20 =
+        20, IEL21 = 21, IEL22 = 22, IEL23 = 23, IEL24 = 24, IEL25 = 25, IEL26 = 26, IEL27
+        = 27, IEL28 = 28, IEL29 = 29, IEL30 = 30, IEL31 = 31, }
+    };
+}
+#[macro_export]
+macro_rules! foreach_event {
+    ($m:path, $($args:tt)*) => {
+        $m ! { $($args)* { (Gpt0CounterOverflow, 14, (2, 10, 18, 26)), (IcuIrq0, 1, (0,
+        8, 16, 24)), (IcuIrq1, 1, (1, 9, 17, 25)), (Sci0Rxi, 13, (0, 4, 8, 12, 16, 20,
+        24, 28)), (Sci0Txi, 13, (1, 5, 9, 13, 17, 21, 25, 29)), } }
+    };
+    ($($m:tt)*) => {
+        $($m)* ! { (Gpt0CounterOverflow, 14, (2, 10, 18, 26)), (IcuIrq0, 1, (0, 8, 16,
+        24)), (IcuIrq1, 1, (1, 9, 17, 25)), (Sci0Rxi, 13, (0, 4, 8, 12, 16, 20, 24, 28)),
+        (Sci0Txi, 13, (1, 5, 9, 13, 17, 21, 25, 29)), }
+    };
+}
+#[macro_export]
+macro_rules! foreach_dma_trigger {
+    ($m:path) => {
+        $m ! { (dtc, Gpt0CounterOverflow, 14), (dtc, IcuIrq0, 1), (dtc, IcuIrq1, 1),
+        (dtc, Sci0Rxi, 13), (dtc, Sci0Txi, 13), }
+    };
+}
+#[macro_export]
+macro_rules! foreach_peripheral {
+    ($m:path) => {
+        $m ! { (SYSC, sysc, ra2e1, 0, None, None), (MSTP, mstp, v3, 1, None, None), (ICU,
+        icu, ra2e1, 2, None, None), (ELC, elc, v1, 3, None, None), (PFS, pfs, v1, 4,
+        None, None), (PORT0, port, v1, 5, Some(PORT), Some(0)), (GPT320, timer, v5, 6,
+        Some(GPT), Some(0)), (GPT164, timer, v5, 7, Some(GPT), Some(4)), }
+    };
+}
+#[macro_export]
+macro_rules! foreach_pin {
+    ($m:path) => {
+        $m ! { (P108, 1, 8), (P109, 1, 9), (P500, 5, 0), }
+    };
+}
+#[macro_export]
+macro_rules! foreach_pin_function {
+    ($m:path) => {
+        $m ! {}
+    };
+}
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// Overflow
+    Gpt0CounterOverflow,
+    /// External pin interrupt 0
+    IcuIrq0,
+    /// External pin interrupt 1
+    IcuIrq1,
+    /// Receive data full / Receive data full
+    Sci0Rxi,
+    /// Transmit data empty / Transmit data empty
+    Sci0Txi,
+}
+impl Event {
+    const ALL_SLOTS: &'static [u8] = &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+        23, 24, 25, 26, 27, 28, 29, 30, 31,
+    ];
+    /// The event's number, as written to `IELSR.IELS`.
+    #[inline(always)]
+    pub const fn number(self) -> u16 {
+        match self {
+            Self::Gpt0CounterOverflow => 14,
+            Self::IcuIrq0 => 1,
+            Self::IcuIrq1 => 1,
+            Self::Sci0Rxi => 13,
+            Self::Sci0Txi => 13,
+        }
+    }
+    /// The IEL slots the event can be linked in. Grouped events can only use the slots of
+    /// their groups; every other event can use any slot.
+    pub const fn allowed_slots(self) -> &'static [u8] {
+        #[allow(unreachable_patterns)]
+        match self {
+            Self::Gpt0CounterOverflow => &[2, 10, 18, 26],
+            Self::IcuIrq0 => &[0, 8, 16, 24],
+            Self::IcuIrq1 => &[1, 9, 17, 25],
+            Self::Sci0Rxi => &[0, 4, 8, 12, 16, 20, 24, 28],
+            Self::Sci0Txi => &[1, 5, 9, 13, 17, 21, 25, 29],
+            _ => Self::ALL_SLOTS,
+        }
+    }
+    /// Whether the event can be linked in IEL slot `slot`, for use in const assertions.
+    pub const fn allows_slot(self, slot: usize) -> bool {
+        let slots = self.allowed_slots();
+        let mut i = 0;
+        while i < slots.len() {
+            if slots[i] as usize == slot {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+/// Event Link Controller: any `Event` can be linked to any `Destination` by writing it to
+/// `ELSR[destination]`.
+pub mod elc {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(u16)]
+    pub enum Event {
+        /// External pin interrupt 0
+        IcuIrq0 = 1,
+        /// External pin interrupt 1
+        IcuIrq1 = 2,
+        /// Overflow
+        Gpt0CounterOverflow = 60,
+        /// Receive data full
+        Sci0Rxi = 113,
+    }
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(u8)]
+    pub enum Destination {
+        GptA = 0,
+        GptB = 1,
+        Adc0 = 8,
+        Adc0B = 9,
+        Ioport1 = 14,
+    }
+}
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Interrupt {
+    IEL0 = 0,
+    IEL1 = 1,
+    IEL2 = 2,
+    IEL3 = 3,
+    IEL4 = 4,
+    IEL5 = 5,
+    IEL6 = 6,
+    IEL7 = 7,
+    IEL8 = 8,
+    IEL9 = 9,
+    IEL10 = 10,
+    IEL11 = 11,
+    IEL12 = 12,
+    IEL13 = 13,
+    IEL14 = 14,
+    IEL15 = 15,
+    IEL16 = 16,
+    IEL17 = 17,
+    IEL18 = 18,
+    IEL19 = 19,
+    IEL20 = 20,
+    IEL21 = 21,
+    IEL22 = 22,
+    IEL23 = 23,
+    IEL24 = 24,
+    IEL25 = 25,
+    IEL26 = 26,
+    IEL27 = 27,
+    IEL28 = 28,
+    IEL29 = 29,
+    IEL30 = 30,
+    IEL31 = 31,
+}
+unsafe impl cortex_m::interrupt::InterruptNumber for Interrupt {
+    #[inline(always)]
+    fn number(self) -> u16 {
+        self as u16
+    }
+}
+impl Interrupt {
+    /// Whether `event` can be linked in this IEL slot.
+    #[inline(always)]
+    pub const fn can_bind(self, event: Event) -> bool {
+        event.allows_slot(self as usize)
+    }
+}
+impl Interrupt {
+    /// Links `event` to this IEL slot by writing its number to `IELSR`, which also clears
+    /// the slot's IR flag and DTC enable.
+    ///
+    /// Panics if the event can't be linked in this slot; [`bind_events!`] checks that at
+    /// compile time instead.
+    #[inline(always)]
+    pub fn bind(self, event: Event) {
+        assert!(self.can_bind(event));
+        peripherals::ICU::REGS.ielsr(self as usize).write_value(event.number() as u32)
+    }
+    /// Unlinks the IEL slot from its event.
+    #[inline(always)]
+    pub fn unbind(self) {
+        peripherals::ICU::REGS.ielsr(self as usize).write_value(0)
+    }
+}
+/// Links events to IEL slots, checking at compile time that each event can be linked in its
+/// slot and that no slot is used twice:
+///
+/// ```ignore
+/// bind_events!(IEL0 => IcuIrq0, IEL4 => Sci0Rxi);
+/// ```
+#[macro_export]
+macro_rules! bind_events {
+    ($($iel:ident => $event:ident),* $(,)?) => {
+        { const _ : () = { let slots = [$($crate::Interrupt:: $iel as usize),*]; let mut
+        i = 0; while i < slots.len() { let mut j = i + 1; while j < slots.len() {
+        assert!(slots[i] != slots[j], "an IEL slot is bound twice"); j += 1; } i += 1; }
+        }; $(const _ : () = assert!($crate::Interrupt:: $iel .can_bind($crate::Event::
+        $event), concat!(stringify!($event), " can't be linked in ", stringify!($iel)),);
+        $crate::Interrupt:: $iel .bind($crate::Event:: $event);)* }
+    };
+}
+pub mod option_setting {
+    /// `OFS0` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Ofs0(pub u32);
+    impl Ofs0 {
+        pub const ADDRESS: usize = 0x00000400;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.ofs0";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+        /// IWDT Start Mode Select.
+        #[inline(always)]
+        pub const fn iwdtstrt(self) -> bool {
+            (self.0 >> 1) & 0x00000001 != 0
+        }
+        /// IWDT Start Mode Select.
+        #[inline(always)]
+        pub const fn with_iwdtstrt(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 1) | (value as u32) << 1)
+        }
+        /// IWDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn iwdttops(self) -> u8 {
+            ((self.0 >> 2) & 0x00000003) as u8
+        }
+        /// IWDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn with_iwdttops(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 2) | ((value as u32) & 0x00000003) << 2)
+        }
+        /// IWDT-Dedicated Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn iwdtcks(self) -> u8 {
+            ((self.0 >> 4) & 0x0000000f) as u8
+        }
+        /// IWDT-Dedicated Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn with_iwdtcks(self, value: u8) -> Self {
+            Self(self.0 & !(0x0000000f << 4) | ((value as u32) & 0x0000000f) << 4)
+        }
+        /// IWDT Window End Position Select.
+        #[inline(always)]
+        pub const fn iwdtrpes(self) -> u8 {
+            ((self.0 >> 8) & 0x00000003) as u8
+        }
+        /// IWDT Window End Position Select.
+        #[inline(always)]
+        pub const fn with_iwdtrpes(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 8) | ((value as u32) & 0x00000003) << 8)
+        }
+        /// IWDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn iwdtrpss(self) -> u8 {
+            ((self.0 >> 10) & 0x00000003) as u8
+        }
+        /// IWDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn with_iwdtrpss(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 10) | ((value as u32) & 0x00000003) << 10)
+        }
+        /// IWDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn iwdtrstirqs(self) -> bool {
+            (self.0 >> 12) & 0x00000001 != 0
+        }
+        /// IWDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn with_iwdtrstirqs(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 12) | (value as u32) << 12)
+        }
+        /// IWDT Stop Control.
+        #[inline(always)]
+        pub const fn iwdtstpctl(self) -> bool {
+            (self.0 >> 14) & 0x00000001 != 0
+        }
+        /// IWDT Stop Control.
+        #[inline(always)]
+        pub const fn with_iwdtstpctl(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 14) | (value as u32) << 14)
+        }
+        /// WDT Start Mode Select.
+        #[inline(always)]
+        pub const fn wdtstrt(self) -> bool {
+            (self.0 >> 17) & 0x00000001 != 0
+        }
+        /// WDT Start Mode Select.
+        #[inline(always)]
+        pub const fn with_wdtstrt(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 17) | (value as u32) << 17)
+        }
+        /// WDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn wdttops(self) -> u8 {
+            ((self.0 >> 18) & 0x00000003) as u8
+        }
+        /// WDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn with_wdttops(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 18) | ((value as u32) & 0x00000003) << 18)
+        }
+        /// WDT Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn wdtcks(self) -> u8 {
+            ((self.0 >> 20) & 0x0000000f) as u8
+        }
+        /// WDT Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn with_wdtcks(self, value: u8) -> Self {
+            Self(self.0 & !(0x0000000f << 20) | ((value as u32) & 0x0000000f) << 20)
+        }
+        /// WDT Window End Position Select.
+        #[inline(always)]
+        pub const fn wdtrpes(self) -> u8 {
+            ((self.0 >> 24) & 0x00000003) as u8
+        }
+        /// WDT Window End Position Select.
+        #[inline(always)]
+        pub const fn with_wdtrpes(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 24) | ((value as u32) & 0x00000003) << 24)
+        }
+        /// WDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn wdtrpss(self) -> u8 {
+            ((self.0 >> 26) & 0x00000003) as u8
+        }
+        /// WDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn with_wdtrpss(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 26) | ((value as u32) & 0x00000003) << 26)
+        }
+        /// WDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn wdtrstirqs(self) -> bool {
+            (self.0 >> 28) & 0x00000001 != 0
+        }
+        /// WDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn with_wdtrstirqs(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 28) | (value as u32) << 28)
+        }
+        /// WDT Stop Control.
+        #[inline(always)]
+        pub const fn wdtstpctl(self) -> bool {
+            (self.0 >> 30) & 0x00000001 != 0
+        }
+        /// WDT Stop Control.
+        #[inline(always)]
+        pub const fn with_wdtstpctl(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 30) | (value as u32) << 30)
+        }
+    }
+    /// `OFS1` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Ofs1(pub u32);
+    impl Ofs1 {
+        pub const ADDRESS: usize = 0x00000404;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.ofs1";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+        /// Voltage Detection 0 Circuit Start.
+        #[inline(always)]
+        pub const fn lvdas(self) -> bool {
+            (self.0 >> 2) & 0x00000001 != 0
+        }
+        /// Voltage Detection 0 Circuit Start.
+        #[inline(always)]
+        pub const fn with_lvdas(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 2) | (value as u32) << 2)
+        }
+        /// Voltage Detection 0 Level Select.
+        #[inline(always)]
+        pub const fn vdsel1(self) -> u8 {
+            ((self.0 >> 3) & 0x00000007) as u8
+        }
+        /// Voltage Detection 0 Level Select.
+        #[inline(always)]
+        pub const fn with_vdsel1(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000007 << 3) | ((value as u32) & 0x00000007) << 3)
+        }
+        /// HOCO Oscillation Enable.
+        #[inline(always)]
+        pub const fn hocoen(self) -> bool {
+            (self.0 >> 8) & 0x00000001 != 0
+        }
+        /// HOCO Oscillation Enable.
+        #[inline(always)]
+        pub const fn with_hocoen(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 8) | (value as u32) << 8)
+        }
+        /// HOCO Frequency Setting 1.
+        #[inline(always)]
+        pub const fn hocofrq1(self) -> u8 {
+            ((self.0 >> 12) & 0x00000007) as u8
+        }
+        /// HOCO Frequency Setting 1.
+        #[inline(always)]
+        pub const fn with_hocofrq1(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000007 << 12) | ((value as u32) & 0x00000007) << 12)
+        }
+    }
+    /// `SECMPUPCS0` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpupcs0(pub u32);
+    impl Secmpupcs0 {
+        pub const ADDRESS: usize = 0x00000408;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpupcs0";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUPCE0` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpupce0(pub u32);
+    impl Secmpupce0 {
+        pub const ADDRESS: usize = 0x0000040c;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpupce0";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUPCS1` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpupcs1(pub u32);
+    impl Secmpupcs1 {
+        pub const ADDRESS: usize = 0x00000410;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpupcs1";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUPCE1` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpupce1(pub u32);
+    impl Secmpupce1 {
+        pub const ADDRESS: usize = 0x00000414;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpupce1";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUS0` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpus0(pub u32);
+    impl Secmpus0 {
+        pub const ADDRESS: usize = 0x00000418;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpus0";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUE0` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpue0(pub u32);
+    impl Secmpue0 {
+        pub const ADDRESS: usize = 0x0000041c;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpue0";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUS1` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpus1(pub u32);
+    impl Secmpus1 {
+        pub const ADDRESS: usize = 0x00000420;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpus1";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUE1` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpue1(pub u32);
+    impl Secmpue1 {
+        pub const ADDRESS: usize = 0x00000424;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpue1";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUS2` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpus2(pub u32);
+    impl Secmpus2 {
+        pub const ADDRESS: usize = 0x00000428;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpus2";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUE2` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpue2(pub u32);
+    impl Secmpue2 {
+        pub const ADDRESS: usize = 0x0000042c;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpue2";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUS3` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpus3(pub u32);
+    impl Secmpus3 {
+        pub const ADDRESS: usize = 0x00000430;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpus3";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUE3` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpue3(pub u32);
+    impl Secmpue3 {
+        pub const ADDRESS: usize = 0x00000434;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpue3";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+    }
+    /// `SECMPUAC` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Secmpuac(pub u32);
+    impl Secmpuac {
+        pub const ADDRESS: usize = 0x00000438;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.secmpuac";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+        /// Region 0 Disable.
+        #[inline(always)]
+        pub const fn dis0(self) -> bool {
+            (self.0 >> 0) & 0x00000001 != 0
+        }
+        /// Region 0 Disable.
+        #[inline(always)]
+        pub const fn with_dis0(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 0) | (value as u32) << 0)
+        }
+        /// Region 1 Disable.
+        #[inline(always)]
+        pub const fn dis1(self) -> bool {
+            (self.0 >> 1) & 0x00000001 != 0
+        }
+        /// Region 1 Disable.
+        #[inline(always)]
+        pub const fn with_dis1(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 1) | (value as u32) << 1)
+        }
+        /// Region 2 Disable.
+        #[inline(always)]
+        pub const fn dis2(self) -> bool {
+            (self.0 >> 2) & 0x00000001 != 0
+        }
+        /// Region 2 Disable.
+        #[inline(always)]
+        pub const fn with_dis2(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 2) | (value as u32) << 2)
+        }
+        /// Region 3 Disable.
+        #[inline(always)]
+        pub const fn dis3(self) -> bool {
+            (self.0 >> 3) & 0x00000001 != 0
+        }
+        /// Region 3 Disable.
+        #[inline(always)]
+        pub const fn with_dis3(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 3) | (value as u32) << 3)
+        }
+        /// Program Counter 0 Disable.
+        #[inline(always)]
+        pub const fn dispc0(self) -> bool {
+            (self.0 >> 8) & 0x00000001 != 0
+        }
+        /// Program Counter 0 Disable.
+        #[inline(always)]
+        pub const fn with_dispc0(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 8) | (value as u32) << 8)
+        }
+        /// Program Counter 1 Disable.
+        #[inline(always)]
+        pub const fn dispc1(self) -> bool {
+            (self.0 >> 9) & 0x00000001 != 0
+        }
+        /// Program Counter 1 Disable.
+        #[inline(always)]
+        pub const fn with_dispc1(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 9) | (value as u32) << 9)
+        }
+    }
+    /// `OSIS` in `ID_CODE`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Osis(pub [u32; 4]);
+    impl Osis {
+        pub const ADDRESS: usize = 0x01010018;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".id_code.osis";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self([u32::MAX; 4]);
+    }
+}
+/// Places values of the `option_setting` types in the link sections named by their
+/// `SECTION`, which the linker script has to put at their `ADDRESS`:
+///
+/// ```ignore
+/// option_setting!(OFS0 = Ofs0::ERASED.with_iwdtstrt(true));
+/// ```
+///
+/// Registers left out stay erased. A register given twice is a duplicate definition.
+#[macro_export]
+macro_rules! option_setting {
+    ($($register:ident = $value:expr),* $(,)?) => {
+        $($crate::__option_setting!($register = $value);)*
+    };
+}
+#[cfg(not(feature = "mock"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_setting {
+    (OFS0 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.ofs0"] static __OPTION_SETTING_OFS0 :
+        $crate::option_setting::Ofs0 = $value;
+    };
+    (OFS1 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.ofs1"] static __OPTION_SETTING_OFS1 :
+        $crate::option_setting::Ofs1 = $value;
+    };
+    (SECMPUPCS0 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpupcs0"] static
+        __OPTION_SETTING_SECMPUPCS0 : $crate::option_setting::Secmpupcs0 = $value;
+    };
+    (SECMPUPCE0 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpupce0"] static
+        __OPTION_SETTING_SECMPUPCE0 : $crate::option_setting::Secmpupce0 = $value;
+    };
+    (SECMPUPCS1 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpupcs1"] static
+        __OPTION_SETTING_SECMPUPCS1 : $crate::option_setting::Secmpupcs1 = $value;
+    };
+    (SECMPUPCE1 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpupce1"] static
+        __OPTION_SETTING_SECMPUPCE1 : $crate::option_setting::Secmpupce1 = $value;
+    };
+    (SECMPUS0 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpus0"] static
+        __OPTION_SETTING_SECMPUS0 : $crate::option_setting::Secmpus0 = $value;
+    };
+    (SECMPUE0 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpue0"] static
+        __OPTION_SETTING_SECMPUE0 : $crate::option_setting::Secmpue0 = $value;
+    };
+    (SECMPUS1 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpus1"] static
+        __OPTION_SETTING_SECMPUS1 : $crate::option_setting::Secmpus1 = $value;
+    };
+    (SECMPUE1 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpue1"] static
+        __OPTION_SETTING_SECMPUE1 : $crate::option_setting::Secmpue1 = $value;
+    };
+    (SECMPUS2 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpus2"] static
+        __OPTION_SETTING_SECMPUS2 : $crate::option_setting::Secmpus2 = $value;
+    };
+    (SECMPUE2 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpue2"] static
+        __OPTION_SETTING_SECMPUE2 : $crate::option_setting::Secmpue2 = $value;
+    };
+    (SECMPUS3 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpus3"] static
+        __OPTION_SETTING_SECMPUS3 : $crate::option_setting::Secmpus3 = $value;
+    };
+    (SECMPUE3 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpue3"] static
+        __OPTION_SETTING_SECMPUE3 : $crate::option_setting::Secmpue3 = $value;
+    };
+    (SECMPUAC = $value:expr) => {
+        #[used] #[link_section = ".option_setting.secmpuac"] static
+        __OPTION_SETTING_SECMPUAC : $crate::option_setting::Secmpuac = $value;
+    };
+    (OSIS = $value:expr) => {
+        #[used] #[link_section = ".id_code.osis"] static __ID_CODE_OSIS :
+        $crate::option_setting::Osis = $value;
+    };
+}
+#[cfg(feature = "mock")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_setting {
+    (OFS0 = $value:expr) => {
+        #[used] static __OPTION_SETTING_OFS0 : $crate::option_setting::Ofs0 = $value;
+    };
+    (OFS1 = $value:expr) => {
+        #[used] static __OPTION_SETTING_OFS1 : $crate::option_setting::Ofs1 = $value;
+    };
+    (SECMPUPCS0 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUPCS0 : $crate::option_setting::Secmpupcs0 =
+        $value;
+    };
+    (SECMPUPCE0 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUPCE0 : $crate::option_setting::Secmpupce0 =
+        $value;
+    };
+    (SECMPUPCS1 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUPCS1 : $crate::option_setting::Secmpupcs1 =
+        $value;
+    };
+    (SECMPUPCE1 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUPCE1 : $crate::option_setting::Secmpupce1 =
+        $value;
+    };
+    (SECMPUS0 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUS0 : $crate::option_setting::Secmpus0 =
+        $value;
+    };
+    (SECMPUE0 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUE0 : $crate::option_setting::Secmpue0 =
+        $value;
+    };
+    (SECMPUS1 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUS1 : $crate::option_setting::Secmpus1 =
+        $value;
+    };
+    (SECMPUE1 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUE1 : $crate::option_setting::Secmpue1 =
+        $value;
+    };
+    (SECMPUS2 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUS2 : $crate::option_setting::Secmpus2 =
+        $value;
+    };
+    (SECMPUE2 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUE2 : $crate::option_setting::Secmpue2 =
+        $value;
+    };
+    (SECMPUS3 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUS3 : $crate::option_setting::Secmpus3 =
+        $value;
+    };
+    (SECMPUE3 = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUE3 : $crate::option_setting::Secmpue3 =
+        $value;
+    };
+    (SECMPUAC = $value:expr) => {
+        #[used] static __OPTION_SETTING_SECMPUAC : $crate::option_setting::Secmpuac =
+        $value;
+    };
+    (OSIS = $value:expr) => {
+        #[used] static __ID_CODE_OSIS : $crate::option_setting::Osis = $value;
+    };
+}
+#[cfg(feature = "embassy")]
+embassy_hal_internal::interrupt_mod!(
+    IEL0, IEL1, IEL2, IEL3, IEL4, IEL5, IEL6, IEL7, IEL8, IEL9, IEL10, IEL11, IEL12,
+    IEL13, IEL14, IEL15, IEL16, IEL17, IEL18, IEL19, IEL20, IEL21, IEL22, IEL23, IEL24,
+    IEL25, IEL26, IEL27, IEL28, IEL29, IEL30, IEL31
+);
+#[cfg(not(feature = "mock"))]
+extern "C" {
+    pub fn IEL0();
+    pub fn IEL1();
+    pub fn IEL2();
+    pub fn IEL3();
+    pub fn IEL4();
+    pub fn IEL5();
+    pub fn IEL6();
+    pub fn IEL7();
+    pub fn IEL8();
+    pub fn IEL9();
+    pub fn IEL10();
+    pub fn IEL11();
+    pub fn IEL12();
+    pub fn IEL13();
+    pub fn IEL14();
+    pub fn IEL15();
+    pub fn IEL16();
+    pub fn IEL17();
+    pub fn IEL18();
+    pub fn IEL19();
+    pub fn IEL20();
+    pub fn IEL21();
+    pub fn IEL22();
+    pub fn IEL23();
+    pub fn IEL24();
+    pub fn IEL25();
+    pub fn IEL26();
+    pub fn IEL27();
+    pub fn IEL28();
+    pub fn IEL29();
+    pub fn IEL30();
+    pub fn IEL31();
+}
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union Vector {
+    pub _handler: unsafe extern "C" fn(),
+    pub _reserved: u32,
+}
+#[cfg(not(feature = "mock"))]
+#[link_section = ".vector_table.interrupts"]
+#[no_mangle]
+pub static __INTERRUPTS: [Vector; 32] = [
+    Vector { _handler: IEL0 },
+    Vector { _handler: IEL1 },
+    Vector { _handler: IEL2 },
+    Vector { _handler: IEL3 },
+    Vector { _handler: IEL4 },
+    Vector { _handler: IEL5 },
+    Vector { _handler: IEL6 },
+    Vector { _handler: IEL7 },
+    Vector { _handler: IEL8 },
+    Vector { _handler: IEL9 },
+    Vector { _handler: IEL10 },
+    Vector { _handler: IEL11 },
+    Vector { _handler: IEL12 },
+    Vector { _handler: IEL13 },
+    Vector { _handler: IEL14 },
+    Vector { _handler: IEL15 },
+    Vector { _handler: IEL16 },
+    Vector { _handler: IEL17 },
+    Vector { _handler: IEL18 },
+    Vector { _handler: IEL19 },
+    Vector { _handler: IEL20 },
+    Vector { _handler: IEL21 },
+    Vector { _handler: IEL22 },
+    Vector { _handler: IEL23 },
+    Vector { _handler: IEL24 },
+    Vector { _handler: IEL25 },
+    Vector { _handler: IEL26 },
+    Vector { _handler: IEL27 },
+    Vector { _handler: IEL28 },
+    Vector { _handler: IEL29 },
+    Vector { _handler: IEL30 },
+    Vector { _handler: IEL31 },
+];
+#[cfg(feature = "trace")]
+pub const TRACE_REGISTERS: &[crate::trace::Register] = &[
+    crate::trace::Register {
+        address: 0x40006000,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40006001,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40006002,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[2]",
+    },
+    crate::trace::Register {
+        address: 0x40006003,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40006004,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40006005,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40006006,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[6]",
+    },
+    crate::trace::Register {
+        address: 0x40006007,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40006100,
+        size: 1,
+        peripheral: "ICU",
+        register: "NMICR",
+    },
+    crate::trace::Register {
+        address: 0x40006120,
+        size: 2,
+        peripheral: "ICU",
+        register: "NMIER",
+    },
+    crate::trace::Register {
+        address: 0x40006130,
+        size: 2,
+        peripheral: "ICU",
+        register: "NMICLR",
+    },
+    crate::trace::Register {
+        address: 0x40006140,
+        size: 2,
+        peripheral: "ICU",
+        register: "NMISR",
+    },
+    crate::trace::Register {
+        address: 0x400061a0,
+        size: 4,
+        peripheral: "ICU",
+        register: "WUPEN",
+    },
+    crate::trace::Register {
+        address: 0x400061c0,
+        size: 1,
+        peripheral: "ICU",
+        register: "IELEN",
+    },
+    crate::trace::Register {
+        address: 0x40006200,
+        size: 2,
+        peripheral: "ICU",
+        register: "SELSR0",
+    },
+    crate::trace::Register {
+        address: 0x40006300,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40006304,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40006308,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4000630c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40006310,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40006314,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40006318,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[6]",
+    },
+    crate::trace::Register {
+        address: 0x4000631c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40006320,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[8]",
+    },
+    crate::trace::Register {
+        address: 0x40006324,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[9]",
+    },
+    crate::trace::Register {
+        address: 0x40006328,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[10]",
+    },
+    crate::trace::Register {
+        address: 0x4000632c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[11]",
+    },
+    crate::trace::Register {
+        address: 0x40006330,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[12]",
+    },
+    crate::trace::Register {
+        address: 0x40006334,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[13]",
+    },
+    crate::trace::Register {
+        address: 0x40006338,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[14]",
+    },
+    crate::trace::Register {
+        address: 0x4000633c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[15]",
+    },
+    crate::trace::Register {
+        address: 0x40006340,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[16]",
+    },
+    crate::trace::Register {
+        address: 0x40006344,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[17]",
+    },
+    crate::trace::Register {
+        address: 0x40006348,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[18]",
+    },
+    crate::trace::Register {
+        address: 0x4000634c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[19]",
+    },
+    crate::trace::Register {
+        address: 0x40006350,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[20]",
+    },
+    crate::trace::Register {
+        address: 0x40006354,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[21]",
+    },
+    crate::trace::Register {
+        address: 0x40006358,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[22]",
+    },
+    crate::trace::Register {
+        address: 0x4000635c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[23]",
+    },
+    crate::trace::Register {
+        address: 0x40006360,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[24]",
+    },
+    crate::trace::Register {
+        address: 0x40006364,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[25]",
+    },
+    crate::trace::Register {
+        address: 0x40006368,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[26]",
+    },
+    crate::trace::Register {
+        address: 0x4000636c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[27]",
+    },
+    crate::trace::Register {
+        address: 0x40006370,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[28]",
+    },
+    crate::trace::Register {
+        address: 0x40006374,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[29]",
+    },
+    crate::trace::Register {
+        address: 0x40006378,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[30]",
+    },
+    crate::trace::Register {
+        address: 0x4000637c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[31]",
+    },
+    crate::trace::Register {
+        address: 0x4001e00c,
+        size: 2,
+        peripheral: "SYSC",
+        register: "SBYCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e01c,
+        size: 4,
+        peripheral: "SYSC",
+        register: "MSTPCRA",
+    },
+    crate::trace::Register {
+        address: 0x4001e020,
+        size: 4,
+        peripheral: "SYSC",
+        register: "SCKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e026,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SCKSCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e031,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MEMWAIT",
+    },
+    crate::trace::Register {
+        address: 0x4001e032,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOSCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e036,
+        size: 1,
+        peripheral: "SYSC",
+        register: "HOCOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e038,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOCOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e03c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OSCSF",
+    },
+    crate::trace::Register {
+        address: 0x4001e03e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "CKOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e040,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OSTDCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e041,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OSTDSR",
+    },
+    crate::trace::Register {
+        address: 0x4001e04c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LPOPT",
+    },
+    crate::trace::Register {
+        address: 0x4001e061,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOCOUTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e062,
+        size: 1,
+        peripheral: "SYSC",
+        register: "HOCOUTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e092,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SNZCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e094,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SNZEDCR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e098,
+        size: 4,
+        peripheral: "SYSC",
+        register: "SNZREQCR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e09f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "PSMCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0a0,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OPCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0a2,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOSCWTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0a5,
+        size: 1,
+        peripheral: "SYSC",
+        register: "HOCOWTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0aa,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOPCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0c0,
+        size: 2,
+        peripheral: "SYSC",
+        register: "RSTSR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e0,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1CR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e1,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1SR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e2,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2CR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e3,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2SR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3fe,
+        size: 2,
+        peripheral: "SYSC",
+        register: "PRCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e40e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SYOCDCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e410,
+        size: 1,
+        peripheral: "SYSC",
+        register: "RSTSR0",
+    },
+    crate::trace::Register {
+        address: 0x4001e411,
+        size: 1,
+        peripheral: "SYSC",
+        register: "RSTSR2",
+    },
+    crate::trace::Register {
+        address: 0x4001e413,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOMCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e417,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVCMPCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e418,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVDLVLR",
+    },
+    crate::trace::Register {
+        address: 0x4001e41a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1CR0",
+    },
+    crate::trace::Register {
+        address: 0x4001e41b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2CR0",
+    },
+    crate::trace::Register {
+        address: 0x4001e480,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOSCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e481,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOMCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e482,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOMRG",
+    },
+    crate::trace::Register {
+        address: 0x4001e490,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LOCOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e492,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LOCOUTCR",
+    },
+    crate::trace::Register {
+        address: 0x40040000,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PODR",
+    },
+    crate::trace::Register {
+        address: 0x40040000,
+        size: 4,
+        peripheral: "PORT0",
+        register: "PCNTR1",
+    },
+    crate::trace::Register {
+        address: 0x40040002,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PDR",
+    },
+    crate::trace::Register {
+        address: 0x40040004,
+        size: 4,
+        peripheral: "PORT0",
+        register: "PCNTR2",
+    },
+    crate::trace::Register {
+        address: 0x40040006,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PIDR",
+    },
+    crate::trace::Register {
+        address: 0x40040008,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PORR",
+    },
+    crate::trace::Register {
+        address: 0x40040008,
+        size: 4,
+        peripheral: "PORT0",
+        register: "PCNTR3",
+    },
+    crate::trace::Register {
+        address: 0x4004000a,
+        size: 2,
+        peripheral: "PORT0",
+        register: "POSR",
+    },
+    crate::trace::Register {
+        address: 0x40040800,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[0]",
+    },
+    crate::trace::Register {
+        address: 0x40040804,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[1]",
+    },
+    crate::trace::Register {
+        address: 0x40040808,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[2]",
+    },
+    crate::trace::Register {
+        address: 0x4004080c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[3]",
+    },
+    crate::trace::Register {
+        address: 0x40040810,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[4]",
+    },
+    crate::trace::Register {
+        address: 0x40040814,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[5]",
+    },
+    crate::trace::Register {
+        address: 0x40040818,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[6]",
+    },
+    crate::trace::Register {
+        address: 0x4004081c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[7]",
+    },
+    crate::trace::Register {
+        address: 0x40040820,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[8]",
+    },
+    crate::trace::Register {
+        address: 0x40040824,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[9]",
+    },
+    crate::trace::Register {
+        address: 0x40040828,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[10]",
+    },
+    crate::trace::Register {
+        address: 0x4004082c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[11]",
+    },
+    crate::trace::Register {
+        address: 0x40040830,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[12]",
+    },
+    crate::trace::Register {
+        address: 0x40040834,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[13]",
+    },
+    crate::trace::Register {
+        address: 0x40040838,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[14]",
+    },
+    crate::trace::Register {
+        address: 0x4004083c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[15]",
+    },
+    crate::trace::Register {
+        address: 0x40040840,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[16]",
+    },
+    crate::trace::Register {
+        address: 0x40040844,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[17]",
+    },
+    crate::trace::Register {
+        address: 0x40040848,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[18]",
+    },
+    crate::trace::Register {
+        address: 0x4004084c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[19]",
+    },
+    crate::trace::Register {
+        address: 0x40040850,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[20]",
+    },
+    crate::trace::Register {
+        address: 0x40040854,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[21]",
+    },
+    crate::trace::Register {
+        address: 0x40040858,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[22]",
+    },
+    crate::trace::Register {
+        address: 0x4004085c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[23]",
+    },
+    crate::trace::Register {
+        address: 0x40040860,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[24]",
+    },
+    crate::trace::Register {
+        address: 0x40040864,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[25]",
+    },
+    crate::trace::Register {
+        address: 0x40040868,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[26]",
+    },
+    crate::trace::Register {
+        address: 0x4004086c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[27]",
+    },
+    crate::trace::Register {
+        address: 0x40040870,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[28]",
+    },
+    crate::trace::Register {
+        address: 0x40040874,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[29]",
+    },
+    crate::trace::Register {
+        address: 0x40040878,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[30]",
+    },
+    crate::trace::Register {
+        address: 0x4004087c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[31]",
+    },
+    crate::trace::Register {
+        address: 0x40040880,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[32]",
+    },
+    crate::trace::Register {
+        address: 0x40040884,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[33]",
+    },
+    crate::trace::Register {
+        address: 0x40040888,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[34]",
+    },
+    crate::trace::Register {
+        address: 0x4004088c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[35]",
+    },
+    crate::trace::Register {
+        address: 0x40040890,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[36]",
+    },
+    crate::trace::Register {
+        address: 0x40040894,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[37]",
+    },
+    crate::trace::Register {
+        address: 0x40040898,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[38]",
+    },
+    crate::trace::Register {
+        address: 0x4004089c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[39]",
+    },
+    crate::trace::Register {
+        address: 0x400408a0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[40]",
+    },
+    crate::trace::Register {
+        address: 0x400408a4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[41]",
+    },
+    crate::trace::Register {
+        address: 0x400408a8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[42]",
+    },
+    crate::trace::Register {
+        address: 0x400408ac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[43]",
+    },
+    crate::trace::Register {
+        address: 0x400408b0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[44]",
+    },
+    crate::trace::Register {
+        address: 0x400408b4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[45]",
+    },
+    crate::trace::Register {
+        address: 0x400408b8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[46]",
+    },
+    crate::trace::Register {
+        address: 0x400408bc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[47]",
+    },
+    crate::trace::Register {
+        address: 0x400408c0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[48]",
+    },
+    crate::trace::Register {
+        address: 0x400408c4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[49]",
+    },
+    crate::trace::Register {
+        address: 0x400408c8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[50]",
+    },
+    crate::trace::Register {
+        address: 0x400408cc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[51]",
+    },
+    crate::trace::Register {
+        address: 0x400408d0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[52]",
+    },
+    crate::trace::Register {
+        address: 0x400408d4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[53]",
+    },
+    crate::trace::Register {
+        address: 0x400408d8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[54]",
+    },
+    crate::trace::Register {
+        address: 0x400408dc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[55]",
+    },
+    crate::trace::Register {
+        address: 0x400408e0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[56]",
+    },
+    crate::trace::Register {
+        address: 0x400408e4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[57]",
+    },
+    crate::trace::Register {
+        address: 0x400408e8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[58]",
+    },
+    crate::trace::Register {
+        address: 0x400408ec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[59]",
+    },
+    crate::trace::Register {
+        address: 0x400408f0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[60]",
+    },
+    crate::trace::Register {
+        address: 0x400408f4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[61]",
+    },
+    crate::trace::Register {
+        address: 0x400408f8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[62]",
+    },
+    crate::trace::Register {
+        address: 0x400408fc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[63]",
+    },
+    crate::trace::Register {
+        address: 0x40040900,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[64]",
+    },
+    crate::trace::Register {
+        address: 0x40040904,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[65]",
+    },
+    crate::trace::Register {
+        address: 0x40040908,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[66]",
+    },
+    crate::trace::Register {
+        address: 0x4004090c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[67]",
+    },
+    crate::trace::Register {
+        address: 0x40040910,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[68]",
+    },
+    crate::trace::Register {
+        address: 0x40040914,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[69]",
+    },
+    crate::trace::Register {
+        address: 0x40040918,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[70]",
+    },
+    crate::trace::Register {
+        address: 0x4004091c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[71]",
+    },
+    crate::trace::Register {
+        address: 0x40040920,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[72]",
+    },
+    crate::trace::Register {
+        address: 0x40040924,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[73]",
+    },
+    crate::trace::Register {
+        address: 0x40040928,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[74]",
+    },
+    crate::trace::Register {
+        address: 0x4004092c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[75]",
+    },
+    crate::trace::Register {
+        address: 0x40040930,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[76]",
+    },
+    crate::trace::Register {
+        address: 0x40040934,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[77]",
+    },
+    crate::trace::Register {
+        address: 0x40040938,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[78]",
+    },
+    crate::trace::Register {
+        address: 0x4004093c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[79]",
+    },
+    crate::trace::Register {
+        address: 0x40040940,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[80]",
+    },
+    crate::trace::Register {
+        address: 0x40040944,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[81]",
+    },
+    crate::trace::Register {
+        address: 0x40040948,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[82]",
+    },
+    crate::trace::Register {
+        address: 0x4004094c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[83]",
+    },
+    crate::trace::Register {
+        address: 0x40040950,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[84]",
+    },
+    crate::trace::Register {
+        address: 0x40040954,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[85]",
+    },
+    crate::trace::Register {
+        address: 0x40040958,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[86]",
+    },
+    crate::trace::Register {
+        address: 0x4004095c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[87]",
+    },
+    crate::trace::Register {
+        address: 0x40040960,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[88]",
+    },
+    crate::trace::Register {
+        address: 0x40040964,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[89]",
+    },
+    crate::trace::Register {
+        address: 0x40040968,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[90]",
+    },
+    crate::trace::Register {
+        address: 0x4004096c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[91]",
+    },
+    crate::trace::Register {
+        address: 0x40040970,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[92]",
+    },
+    crate::trace::Register {
+        address: 0x40040974,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[93]",
+    },
+    crate::trace::Register {
+        address: 0x40040978,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[94]",
+    },
+    crate::trace::Register {
+        address: 0x4004097c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[95]",
+    },
+    crate::trace::Register {
+        address: 0x40040980,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[96]",
+    },
+    crate::trace::Register {
+        address: 0x40040984,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[97]",
+    },
+    crate::trace::Register {
+        address: 0x40040988,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[98]",
+    },
+    crate::trace::Register {
+        address: 0x4004098c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[99]",
+    },
+    crate::trace::Register {
+        address: 0x40040990,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[100]",
+    },
+    crate::trace::Register {
+        address: 0x40040994,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[101]",
+    },
+    crate::trace::Register {
+        address: 0x40040998,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[102]",
+    },
+    crate::trace::Register {
+        address: 0x4004099c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[103]",
+    },
+    crate::trace::Register {
+        address: 0x400409a0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[104]",
+    },
+    crate::trace::Register {
+        address: 0x400409a4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[105]",
+    },
+    crate::trace::Register {
+        address: 0x400409a8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[106]",
+    },
+    crate::trace::Register {
+        address: 0x400409ac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[107]",
+    },
+    crate::trace::Register {
+        address: 0x400409b0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[108]",
+    },
+    crate::trace::Register {
+        address: 0x400409b4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[109]",
+    },
+    crate::trace::Register {
+        address: 0x400409b8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[110]",
+    },
+    crate::trace::Register {
+        address: 0x400409bc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[111]",
+    },
+    crate::trace::Register {
+        address: 0x400409c0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[112]",
+    },
+    crate::trace::Register {
+        address: 0x400409c4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[113]",
+    },
+    crate::trace::Register {
+        address: 0x400409c8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[114]",
+    },
+    crate::trace::Register {
+        address: 0x400409cc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[115]",
+    },
+    crate::trace::Register {
+        address: 0x400409d0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[116]",
+    },
+    crate::trace::Register {
+        address: 0x400409d4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[117]",
+    },
+    crate::trace::Register {
+        address: 0x400409d8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[118]",
+    },
+    crate::trace::Register {
+        address: 0x400409dc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[119]",
+    },
+    crate::trace::Register {
+        address: 0x400409e0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[120]",
+    },
+    crate::trace::Register {
+        address: 0x400409e4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[121]",
+    },
+    crate::trace::Register {
+        address: 0x400409e8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[122]",
+    },
+    crate::trace::Register {
+        address: 0x400409ec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[123]",
+    },
+    crate::trace::Register {
+        address: 0x400409f0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[124]",
+    },
+    crate::trace::Register {
+        address: 0x400409f4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[125]",
+    },
+    crate::trace::Register {
+        address: 0x400409f8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[126]",
+    },
+    crate::trace::Register {
+        address: 0x400409fc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[127]",
+    },
+    crate::trace::Register {
+        address: 0x40040a00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[128]",
+    },
+    crate::trace::Register {
+        address: 0x40040a04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[129]",
+    },
+    crate::trace::Register {
+        address: 0x40040a08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[130]",
+    },
+    crate::trace::Register {
+        address: 0x40040a0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[131]",
+    },
+    crate::trace::Register {
+        address: 0x40040a10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[132]",
+    },
+    crate::trace::Register {
+        address: 0x40040a14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[133]",
+    },
+    crate::trace::Register {
+        address: 0x40040a18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[134]",
+    },
+    crate::trace::Register {
+        address: 0x40040a1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[135]",
+    },
+    crate::trace::Register {
+        address: 0x40040a20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[136]",
+    },
+    crate::trace::Register {
+        address: 0x40040a24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[137]",
+    },
+    crate::trace::Register {
+        address: 0x40040a28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[138]",
+    },
+    crate::trace::Register {
+        address: 0x40040a2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[139]",
+    },
+    crate::trace::Register {
+        address: 0x40040a30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[140]",
+    },
+    crate::trace::Register {
+        address: 0x40040a34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[141]",
+    },
+    crate::trace::Register {
+        address: 0x40040a38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[142]",
+    },
+    crate::trace::Register {
+        address: 0x40040a3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[143]",
+    },
+    crate::trace::Register {
+        address: 0x40040a40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[144]",
+    },
+    crate::trace::Register {
+        address: 0x40040a44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[145]",
+    },
+    crate::trace::Register {
+        address: 0x40040a48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[146]",
+    },
+    crate::trace::Register {
+        address: 0x40040a4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[147]",
+    },
+    crate::trace::Register {
+        address: 0x40040a50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[148]",
+    },
+    crate::trace::Register {
+        address: 0x40040a54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[149]",
+    },
+    crate::trace::Register {
+        address: 0x40040a58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[150]",
+    },
+    crate::trace::Register {
+        address: 0x40040a5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[151]",
+    },
+    crate::trace::Register {
+        address: 0x40040a60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[152]",
+    },
+    crate::trace::Register {
+        address: 0x40040a64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[153]",
+    },
+    crate::trace::Register {
+        address: 0x40040a68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[154]",
+    },
+    crate::trace::Register {
+        address: 0x40040a6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[155]",
+    },
+    crate::trace::Register {
+        address: 0x40040a70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[156]",
+    },
+    crate::trace::Register {
+        address: 0x40040a74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[157]",
+    },
+    crate::trace::Register {
+        address: 0x40040a78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[158]",
+    },
+    crate::trace::Register {
+        address: 0x40040a7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[159]",
+    },
+    crate::trace::Register {
+        address: 0x40040a80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[160]",
+    },
+    crate::trace::Register {
+        address: 0x40040a84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[161]",
+    },
+    crate::trace::Register {
+        address: 0x40040a88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[162]",
+    },
+    crate::trace::Register {
+        address: 0x40040a8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[163]",
+    },
+    crate::trace::Register {
+        address: 0x40040a90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[164]",
+    },
+    crate::trace::Register {
+        address: 0x40040a94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[165]",
+    },
+    crate::trace::Register {
+        address: 0x40040a98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[166]",
+    },
+    crate::trace::Register {
+        address: 0x40040a9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[167]",
+    },
+    crate::trace::Register {
+        address: 0x40040aa0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[168]",
+    },
+    crate::trace::Register {
+        address: 0x40040aa4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[169]",
+    },
+    crate::trace::Register {
+        address: 0x40040aa8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[170]",
+    },
+    crate::trace::Register {
+        address: 0x40040aac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[171]",
+    },
+    crate::trace::Register {
+        address: 0x40040ab0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[172]",
+    },
+    crate::trace::Register {
+        address: 0x40040ab4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[173]",
+    },
+    crate::trace::Register {
+        address: 0x40040ab8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[174]",
+    },
+    crate::trace::Register {
+        address: 0x40040abc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[175]",
+    },
+    crate::trace::Register {
+        address: 0x40040ac0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[176]",
+    },
+    crate::trace::Register {
+        address: 0x40040ac4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[177]",
+    },
+    crate::trace::Register {
+        address: 0x40040ac8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[178]",
+    },
+    crate::trace::Register {
+        address: 0x40040acc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[179]",
+    },
+    crate::trace::Register {
+        address: 0x40040ad0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[180]",
+    },
+    crate::trace::Register {
+        address: 0x40040ad4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[181]",
+    },
+    crate::trace::Register {
+        address: 0x40040ad8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[182]",
+    },
+    crate::trace::Register {
+        address: 0x40040adc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[183]",
+    },
+    crate::trace::Register {
+        address: 0x40040ae0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[184]",
+    },
+    crate::trace::Register {
+        address: 0x40040ae4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[185]",
+    },
+    crate::trace::Register {
+        address: 0x40040ae8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[186]",
+    },
+    crate::trace::Register {
+        address: 0x40040aec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[187]",
+    },
+    crate::trace::Register {
+        address: 0x40040af0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[188]",
+    },
+    crate::trace::Register {
+        address: 0x40040af4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[189]",
+    },
+    crate::trace::Register {
+        address: 0x40040af8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[190]",
+    },
+    crate::trace::Register {
+        address: 0x40040afc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[191]",
+    },
+    crate::trace::Register {
+        address: 0x40040b00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[192]",
+    },
+    crate::trace::Register {
+        address: 0x40040b04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[193]",
+    },
+    crate::trace::Register {
+        address: 0x40040b08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[194]",
+    },
+    crate::trace::Register {
+        address: 0x40040b0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[195]",
+    },
+    crate::trace::Register {
+        address: 0x40040b10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[196]",
+    },
+    crate::trace::Register {
+        address: 0x40040b14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[197]",
+    },
+    crate::trace::Register {
+        address: 0x40040b18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[198]",
+    },
+    crate::trace::Register {
+        address: 0x40040b1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[199]",
+    },
+    crate::trace::Register {
+        address: 0x40040b20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[200]",
+    },
+    crate::trace::Register {
+        address: 0x40040b24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[201]",
+    },
+    crate::trace::Register {
+        address: 0x40040b28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[202]",
+    },
+    crate::trace::Register {
+        address: 0x40040b2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[203]",
+    },
+    crate::trace::Register {
+        address: 0x40040b30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[204]",
+    },
+    crate::trace::Register {
+        address: 0x40040b34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[205]",
+    },
+    crate::trace::Register {
+        address: 0x40040b38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[206]",
+    },
+    crate::trace::Register {
+        address: 0x40040b3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[207]",
+    },
+    crate::trace::Register {
+        address: 0x40040b40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[208]",
+    },
+    crate::trace::Register {
+        address: 0x40040b44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[209]",
+    },
+    crate::trace::Register {
+        address: 0x40040b48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[210]",
+    },
+    crate::trace::Register {
+        address: 0x40040b4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[211]",
+    },
+    crate::trace::Register {
+        address: 0x40040b50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[212]",
+    },
+    crate::trace::Register {
+        address: 0x40040b54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[213]",
+    },
+    crate::trace::Register {
+        address: 0x40040b58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[214]",
+    },
+    crate::trace::Register {
+        address: 0x40040b5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[215]",
+    },
+    crate::trace::Register {
+        address: 0x40040b60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[216]",
+    },
+    crate::trace::Register {
+        address: 0x40040b64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[217]",
+    },
+    crate::trace::Register {
+        address: 0x40040b68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[218]",
+    },
+    crate::trace::Register {
+        address: 0x40040b6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[219]",
+    },
+    crate::trace::Register {
+        address: 0x40040b70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[220]",
+    },
+    crate::trace::Register {
+        address: 0x40040b74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[221]",
+    },
+    crate::trace::Register {
+        address: 0x40040b78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[222]",
+    },
+    crate::trace::Register {
+        address: 0x40040b7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[223]",
+    },
+    crate::trace::Register {
+        address: 0x40040b80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[224]",
+    },
+    crate::trace::Register {
+        address: 0x40040b84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[225]",
+    },
+    crate::trace::Register {
+        address: 0x40040b88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[226]",
+    },
+    crate::trace::Register {
+        address: 0x40040b8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[227]",
+    },
+    crate::trace::Register {
+        address: 0x40040b90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[228]",
+    },
+    crate::trace::Register {
+        address: 0x40040b94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[229]",
+    },
+    crate::trace::Register {
+        address: 0x40040b98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[230]",
+    },
+    crate::trace::Register {
+        address: 0x40040b9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[231]",
+    },
+    crate::trace::Register {
+        address: 0x40040ba0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[232]",
+    },
+    crate::trace::Register {
+        address: 0x40040ba4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[233]",
+    },
+    crate::trace::Register {
+        address: 0x40040ba8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[234]",
+    },
+    crate::trace::Register {
+        address: 0x40040bac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[235]",
+    },
+    crate::trace::Register {
+        address: 0x40040bb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[236]",
+    },
+    crate::trace::Register {
+        address: 0x40040bb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[237]",
+    },
+    crate::trace::Register {
+        address: 0x40040bb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[238]",
+    },
+    crate::trace::Register {
+        address: 0x40040bbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[239]",
+    },
+    crate::trace::Register {
+        address: 0x40040bc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[240]",
+    },
+    crate::trace::Register {
+        address: 0x40040bc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[241]",
+    },
+    crate::trace::Register {
+        address: 0x40040bc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[242]",
+    },
+    crate::trace::Register {
+        address: 0x40040bcc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[243]",
+    },
+    crate::trace::Register {
+        address: 0x40040bd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[244]",
+    },
+    crate::trace::Register {
+        address: 0x40040bd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[245]",
+    },
+    crate::trace::Register {
+        address: 0x40040bd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[246]",
+    },
+    crate::trace::Register {
+        address: 0x40040bdc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[247]",
+    },
+    crate::trace::Register {
+        address: 0x40040be0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[248]",
+    },
+    crate::trace::Register {
+        address: 0x40040be4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[249]",
+    },
+    crate::trace::Register {
+        address: 0x40040be8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[250]",
+    },
+    crate::trace::Register {
+        address: 0x40040bec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[251]",
+    },
+    crate::trace::Register {
+        address: 0x40040bf0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[252]",
+    },
+    crate::trace::Register {
+        address: 0x40040bf4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[253]",
+    },
+    crate::trace::Register {
+        address: 0x40040bf8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[254]",
+    },
+    crate::trace::Register {
+        address: 0x40040bfc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[255]",
+    },
+    crate::trace::Register {
+        address: 0x40040c00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[256]",
+    },
+    crate::trace::Register {
+        address: 0x40040c04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[257]",
+    },
+    crate::trace::Register {
+        address: 0x40040c08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[258]",
+    },
+    crate::trace::Register {
+        address: 0x40040c0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[259]",
+    },
+    crate::trace::Register {
+        address: 0x40040c10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[260]",
+    },
+    crate::trace::Register {
+        address: 0x40040c14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[261]",
+    },
+    crate::trace::Register {
+        address: 0x40040c18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[262]",
+    },
+    crate::trace::Register {
+        address: 0x40040c1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[263]",
+    },
+    crate::trace::Register {
+        address: 0x40040c20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[264]",
+    },
+    crate::trace::Register {
+        address: 0x40040c24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[265]",
+    },
+    crate::trace::Register {
+        address: 0x40040c28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[266]",
+    },
+    crate::trace::Register {
+        address: 0x40040c2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[267]",
+    },
+    crate::trace::Register {
+        address: 0x40040c30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[268]",
+    },
+    crate::trace::Register {
+        address: 0x40040c34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[269]",
+    },
+    crate::trace::Register {
+        address: 0x40040c38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[270]",
+    },
+    crate::trace::Register {
+        address: 0x40040c3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[271]",
+    },
+    crate::trace::Register {
+        address: 0x40040c40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[272]",
+    },
+    crate::trace::Register {
+        address: 0x40040c44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[273]",
+    },
+    crate::trace::Register {
+        address: 0x40040c48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[274]",
+    },
+    crate::trace::Register {
+        address: 0x40040c4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[275]",
+    },
+    crate::trace::Register {
+        address: 0x40040c50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[276]",
+    },
+    crate::trace::Register {
+        address: 0x40040c54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[277]",
+    },
+    crate::trace::Register {
+        address: 0x40040c58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[278]",
+    },
+    crate::trace::Register {
+        address: 0x40040c5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[279]",
+    },
+    crate::trace::Register {
+        address: 0x40040c60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[280]",
+    },
+    crate::trace::Register {
+        address: 0x40040c64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[281]",
+    },
+    crate::trace::Register {
+        address: 0x40040c68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[282]",
+    },
+    crate::trace::Register {
+        address: 0x40040c6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[283]",
+    },
+    crate::trace::Register {
+        address: 0x40040c70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[284]",
+    },
+    crate::trace::Register {
+        address: 0x40040c74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[285]",
+    },
+    crate::trace::Register {
+        address: 0x40040c78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[286]",
+    },
+    crate::trace::Register {
+        address: 0x40040c7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[287]",
+    },
+    crate::trace::Register {
+        address: 0x40040c80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[288]",
+    },
+    crate::trace::Register {
+        address: 0x40040c84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[289]",
+    },
+    crate::trace::Register {
+        address: 0x40040c88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[290]",
+    },
+    crate::trace::Register {
+        address: 0x40040c8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[291]",
+    },
+    crate::trace::Register {
+        address: 0x40040c90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[292]",
+    },
+    crate::trace::Register {
+        address: 0x40040c94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[293]",
+    },
+    crate::trace::Register {
+        address: 0x40040c98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[294]",
+    },
+    crate::trace::Register {
+        address: 0x40040c9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[295]",
+    },
+    crate::trace::Register {
+        address: 0x40040ca0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[296]",
+    },
+    crate::trace::Register {
+        address: 0x40040ca4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[297]",
+    },
+    crate::trace::Register {
+        address: 0x40040ca8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[298]",
+    },
+    crate::trace::Register {
+        address: 0x40040cac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[299]",
+    },
+    crate::trace::Register {
+        address: 0x40040cb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[300]",
+    },
+    crate::trace::Register {
+        address: 0x40040cb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[301]",
+    },
+    crate::trace::Register {
+        address: 0x40040cb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[302]",
+    },
+    crate::trace::Register {
+        address: 0x40040cbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[303]",
+    },
+    crate::trace::Register {
+        address: 0x40040cc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[304]",
+    },
+    crate::trace::Register {
+        address: 0x40040cc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[305]",
+    },
+    crate::trace::Register {
+        address: 0x40040cc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[306]",
+    },
+    crate::trace::Register {
+        address: 0x40040ccc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[307]",
+    },
+    crate::trace::Register {
+        address: 0x40040cd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[308]",
+    },
+    crate::trace::Register {
+        address: 0x40040cd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[309]",
+    },
+    crate::trace::Register {
+        address: 0x40040cd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[310]",
+    },
+    crate::trace::Register {
+        address: 0x40040cdc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[311]",
+    },
+    crate::trace::Register {
+        address: 0x40040ce0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[312]",
+    },
+    crate::trace::Register {
+        address: 0x40040ce4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[313]",
+    },
+    crate::trace::Register {
+        address: 0x40040ce8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[314]",
+    },
+    crate::trace::Register {
+        address: 0x40040cec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[315]",
+    },
+    crate::trace::Register {
+        address: 0x40040cf0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[316]",
+    },
+    crate::trace::Register {
+        address: 0x40040cf4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[317]",
+    },
+    crate::trace::Register {
+        address: 0x40040cf8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[318]",
+    },
+    crate::trace::Register {
+        address: 0x40040cfc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[319]",
+    },
+    crate::trace::Register {
+        address: 0x40040d00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[320]",
+    },
+    crate::trace::Register {
+        address: 0x40040d03,
+        size: 1,
+        peripheral: "PFS",
+        register: "PWPR",
+    },
+    crate::trace::Register {
+        address: 0x40040d04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[321]",
+    },
+    crate::trace::Register {
+        address: 0x40040d08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[322]",
+    },
+    crate::trace::Register {
+        address: 0x40040d0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[323]",
+    },
+    crate::trace::Register {
+        address: 0x40040d10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[324]",
+    },
+    crate::trace::Register {
+        address: 0x40040d14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[325]",
+    },
+    crate::trace::Register {
+        address: 0x40040d18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[326]",
+    },
+    crate::trace::Register {
+        address: 0x40040d1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[327]",
+    },
+    crate::trace::Register {
+        address: 0x40040d20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[328]",
+    },
+    crate::trace::Register {
+        address: 0x40040d24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[329]",
+    },
+    crate::trace::Register {
+        address: 0x40040d28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[330]",
+    },
+    crate::trace::Register {
+        address: 0x40040d2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[331]",
+    },
+    crate::trace::Register {
+        address: 0x40040d30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[332]",
+    },
+    crate::trace::Register {
+        address: 0x40040d34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[333]",
+    },
+    crate::trace::Register {
+        address: 0x40040d38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[334]",
+    },
+    crate::trace::Register {
+        address: 0x40040d3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[335]",
+    },
+    crate::trace::Register {
+        address: 0x40040d40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[336]",
+    },
+    crate::trace::Register {
+        address: 0x40040d44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[337]",
+    },
+    crate::trace::Register {
+        address: 0x40040d48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[338]",
+    },
+    crate::trace::Register {
+        address: 0x40040d4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[339]",
+    },
+    crate::trace::Register {
+        address: 0x40040d50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[340]",
+    },
+    crate::trace::Register {
+        address: 0x40040d54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[341]",
+    },
+    crate::trace::Register {
+        address: 0x40040d58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[342]",
+    },
+    crate::trace::Register {
+        address: 0x40040d5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[343]",
+    },
+    crate::trace::Register {
+        address: 0x40040d60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[344]",
+    },
+    crate::trace::Register {
+        address: 0x40040d64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[345]",
+    },
+    crate::trace::Register {
+        address: 0x40040d68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[346]",
+    },
+    crate::trace::Register {
+        address: 0x40040d6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[347]",
+    },
+    crate::trace::Register {
+        address: 0x40040d70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[348]",
+    },
+    crate::trace::Register {
+        address: 0x40040d74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[349]",
+    },
+    crate::trace::Register {
+        address: 0x40040d78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[350]",
+    },
+    crate::trace::Register {
+        address: 0x40040d7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[351]",
+    },
+    crate::trace::Register {
+        address: 0x40040d80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[352]",
+    },
+    crate::trace::Register {
+        address: 0x40040d84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[353]",
+    },
+    crate::trace::Register {
+        address: 0x40040d88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[354]",
+    },
+    crate::trace::Register {
+        address: 0x40040d8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[355]",
+    },
+    crate::trace::Register {
+        address: 0x40040d90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[356]",
+    },
+    crate::trace::Register {
+        address: 0x40040d94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[357]",
+    },
+    crate::trace::Register {
+        address: 0x40040d98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[358]",
+    },
+    crate::trace::Register {
+        address: 0x40040d9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[359]",
+    },
+    crate::trace::Register {
+        address: 0x40040da0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[360]",
+    },
+    crate::trace::Register {
+        address: 0x40040da4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[361]",
+    },
+    crate::trace::Register {
+        address: 0x40040da8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[362]",
+    },
+    crate::trace::Register {
+        address: 0x40040dac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[363]",
+    },
+    crate::trace::Register {
+        address: 0x40040db0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[364]",
+    },
+    crate::trace::Register {
+        address: 0x40040db4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[365]",
+    },
+    crate::trace::Register {
+        address: 0x40040db8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[366]",
+    },
+    crate::trace::Register {
+        address: 0x40040dbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[367]",
+    },
+    crate::trace::Register {
+        address: 0x40040dc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[368]",
+    },
+    crate::trace::Register {
+        address: 0x40040dc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[369]",
+    },
+    crate::trace::Register {
+        address: 0x40040dc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[370]",
+    },
+    crate::trace::Register {
+        address: 0x40040dcc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[371]",
+    },
+    crate::trace::Register {
+        address: 0x40040dd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[372]",
+    },
+    crate::trace::Register {
+        address: 0x40040dd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[373]",
+    },
+    crate::trace::Register {
+        address: 0x40040dd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[374]",
+    },
+    crate::trace::Register {
+        address: 0x40040ddc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[375]",
+    },
+    crate::trace::Register {
+        address: 0x40040de0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[376]",
+    },
+    crate::trace::Register {
+        address: 0x40040de4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[377]",
+    },
+    crate::trace::Register {
+        address: 0x40040de8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[378]",
+    },
+    crate::trace::Register {
+        address: 0x40040dec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[379]",
+    },
+    crate::trace::Register {
+        address: 0x40040df0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[380]",
+    },
+    crate::trace::Register {
+        address: 0x40040df4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[381]",
+    },
+    crate::trace::Register {
+        address: 0x40040df8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[382]",
+    },
+    crate::trace::Register {
+        address: 0x40040dfc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[383]",
+    },
+    crate::trace::Register {
+        address: 0x40040e00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[384]",
+    },
+    crate::trace::Register {
+        address: 0x40040e04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[385]",
+    },
+    crate::trace::Register {
+        address: 0x40040e08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[386]",
+    },
+    crate::trace::Register {
+        address: 0x40040e0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[387]",
+    },
+    crate::trace::Register {
+        address: 0x40040e10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[388]",
+    },
+    crate::trace::Register {
+        address: 0x40040e14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[389]",
+    },
+    crate::trace::Register {
+        address: 0x40040e18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[390]",
+    },
+    crate::trace::Register {
+        address: 0x40040e1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[391]",
+    },
+    crate::trace::Register {
+        address: 0x40040e20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[392]",
+    },
+    crate::trace::Register {
+        address: 0x40040e24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[393]",
+    },
+    crate::trace::Register {
+        address: 0x40040e28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[394]",
+    },
+    crate::trace::Register {
+        address: 0x40040e2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[395]",
+    },
+    crate::trace::Register {
+        address: 0x40040e30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[396]",
+    },
+    crate::trace::Register {
+        address: 0x40040e34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[397]",
+    },
+    crate::trace::Register {
+        address: 0x40040e38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[398]",
+    },
+    crate::trace::Register {
+        address: 0x40040e3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[399]",
+    },
+    crate::trace::Register {
+        address: 0x40040e40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[400]",
+    },
+    crate::trace::Register {
+        address: 0x40040e44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[401]",
+    },
+    crate::trace::Register {
+        address: 0x40040e48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[402]",
+    },
+    crate::trace::Register {
+        address: 0x40040e4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[403]",
+    },
+    crate::trace::Register {
+        address: 0x40040e50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[404]",
+    },
+    crate::trace::Register {
+        address: 0x40040e54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[405]",
+    },
+    crate::trace::Register {
+        address: 0x40040e58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[406]",
+    },
+    crate::trace::Register {
+        address: 0x40040e5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[407]",
+    },
+    crate::trace::Register {
+        address: 0x40040e60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[408]",
+    },
+    crate::trace::Register {
+        address: 0x40040e64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[409]",
+    },
+    crate::trace::Register {
+        address: 0x40040e68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[410]",
+    },
+    crate::trace::Register {
+        address: 0x40040e6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[411]",
+    },
+    crate::trace::Register {
+        address: 0x40040e70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[412]",
+    },
+    crate::trace::Register {
+        address: 0x40040e74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[413]",
+    },
+    crate::trace::Register {
+        address: 0x40040e78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[414]",
+    },
+    crate::trace::Register {
+        address: 0x40040e7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[415]",
+    },
+    crate::trace::Register {
+        address: 0x40040e80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[416]",
+    },
+    crate::trace::Register {
+        address: 0x40040e84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[417]",
+    },
+    crate::trace::Register {
+        address: 0x40040e88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[418]",
+    },
+    crate::trace::Register {
+        address: 0x40040e8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[419]",
+    },
+    crate::trace::Register {
+        address: 0x40040e90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[420]",
+    },
+    crate::trace::Register {
+        address: 0x40040e94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[421]",
+    },
+    crate::trace::Register {
+        address: 0x40040e98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[422]",
+    },
+    crate::trace::Register {
+        address: 0x40040e9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[423]",
+    },
+    crate::trace::Register {
+        address: 0x40040ea0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[424]",
+    },
+    crate::trace::Register {
+        address: 0x40040ea4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[425]",
+    },
+    crate::trace::Register {
+        address: 0x40040ea8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[426]",
+    },
+    crate::trace::Register {
+        address: 0x40040eac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[427]",
+    },
+    crate::trace::Register {
+        address: 0x40040eb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[428]",
+    },
+    crate::trace::Register {
+        address: 0x40040eb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[429]",
+    },
+    crate::trace::Register {
+        address: 0x40040eb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[430]",
+    },
+    crate::trace::Register {
+        address: 0x40040ebc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[431]",
+    },
+    crate::trace::Register {
+        address: 0x40040ec0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[432]",
+    },
+    crate::trace::Register {
+        address: 0x40040ec4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[433]",
+    },
+    crate::trace::Register {
+        address: 0x40040ec8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[434]",
+    },
+    crate::trace::Register {
+        address: 0x40040ecc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[435]",
+    },
+    crate::trace::Register {
+        address: 0x40040ed0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[436]",
+    },
+    crate::trace::Register {
+        address: 0x40040ed4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[437]",
+    },
+    crate::trace::Register {
+        address: 0x40040ed8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[438]",
+    },
+    crate::trace::Register {
+        address: 0x40040edc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[439]",
+    },
+    crate::trace::Register {
+        address: 0x40040ee0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[440]",
+    },
+    crate::trace::Register {
+        address: 0x40040ee4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[441]",
+    },
+    crate::trace::Register {
+        address: 0x40040ee8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[442]",
+    },
+    crate::trace::Register {
+        address: 0x40040eec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[443]",
+    },
+    crate::trace::Register {
+        address: 0x40040ef0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[444]",
+    },
+    crate::trace::Register {
+        address: 0x40040ef4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[445]",
+    },
+    crate::trace::Register {
+        address: 0x40040ef8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[446]",
+    },
+    crate::trace::Register {
+        address: 0x40040efc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[447]",
+    },
+    crate::trace::Register {
+        address: 0x40040f00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[448]",
+    },
+    crate::trace::Register {
+        address: 0x40040f04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[449]",
+    },
+    crate::trace::Register {
+        address: 0x40040f08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[450]",
+    },
+    crate::trace::Register {
+        address: 0x40040f0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[451]",
+    },
+    crate::trace::Register {
+        address: 0x40040f10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[452]",
+    },
+    crate::trace::Register {
+        address: 0x40040f14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[453]",
+    },
+    crate::trace::Register {
+        address: 0x40040f18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[454]",
+    },
+    crate::trace::Register {
+        address: 0x40040f1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[455]",
+    },
+    crate::trace::Register {
+        address: 0x40040f20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[456]",
+    },
+    crate::trace::Register {
+        address: 0x40040f24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[457]",
+    },
+    crate::trace::Register {
+        address: 0x40040f28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[458]",
+    },
+    crate::trace::Register {
+        address: 0x40040f2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[459]",
+    },
+    crate::trace::Register {
+        address: 0x40040f30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[460]",
+    },
+    crate::trace::Register {
+        address: 0x40040f34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[461]",
+    },
+    crate::trace::Register {
+        address: 0x40040f38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[462]",
+    },
+    crate::trace::Register {
+        address: 0x40040f3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[463]",
+    },
+    crate::trace::Register {
+        address: 0x40040f40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[464]",
+    },
+    crate::trace::Register {
+        address: 0x40040f44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[465]",
+    },
+    crate::trace::Register {
+        address: 0x40040f48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[466]",
+    },
+    crate::trace::Register {
+        address: 0x40040f4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[467]",
+    },
+    crate::trace::Register {
+        address: 0x40040f50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[468]",
+    },
+    crate::trace::Register {
+        address: 0x40040f54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[469]",
+    },
+    crate::trace::Register {
+        address: 0x40040f58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[470]",
+    },
+    crate::trace::Register {
+        address: 0x40040f5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[471]",
+    },
+    crate::trace::Register {
+        address: 0x40040f60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[472]",
+    },
+    crate::trace::Register {
+        address: 0x40040f64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[473]",
+    },
+    crate::trace::Register {
+        address: 0x40040f68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[474]",
+    },
+    crate::trace::Register {
+        address: 0x40040f6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[475]",
+    },
+    crate::trace::Register {
+        address: 0x40040f70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[476]",
+    },
+    crate::trace::Register {
+        address: 0x40040f74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[477]",
+    },
+    crate::trace::Register {
+        address: 0x40040f78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[478]",
+    },
+    crate::trace::Register {
+        address: 0x40040f7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[479]",
+    },
+    crate::trace::Register {
+        address: 0x40040f80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[480]",
+    },
+    crate::trace::Register {
+        address: 0x40040f84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[481]",
+    },
+    crate::trace::Register {
+        address: 0x40040f88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[482]",
+    },
+    crate::trace::Register {
+        address: 0x40040f8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[483]",
+    },
+    crate::trace::Register {
+        address: 0x40040f90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[484]",
+    },
+    crate::trace::Register {
+        address: 0x40040f94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[485]",
+    },
+    crate::trace::Register {
+        address: 0x40040f98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[486]",
+    },
+    crate::trace::Register {
+        address: 0x40040f9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[487]",
+    },
+    crate::trace::Register {
+        address: 0x40040fa0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[488]",
+    },
+    crate::trace::Register {
+        address: 0x40040fa4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[489]",
+    },
+    crate::trace::Register {
+        address: 0x40040fa8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[490]",
+    },
+    crate::trace::Register {
+        address: 0x40040fac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[491]",
+    },
+    crate::trace::Register {
+        address: 0x40040fb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[492]",
+    },
+    crate::trace::Register {
+        address: 0x40040fb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[493]",
+    },
+    crate::trace::Register {
+        address: 0x40040fb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[494]",
+    },
+    crate::trace::Register {
+        address: 0x40040fbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[495]",
+    },
+    crate::trace::Register {
+        address: 0x40040fc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[496]",
+    },
+    crate::trace::Register {
+        address: 0x40040fc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[497]",
+    },
+    crate::trace::Register {
+        address: 0x40040fc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[498]",
+    },
+    crate::trace::Register {
+        address: 0x40040fcc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[499]",
+    },
+    crate::trace::Register {
+        address: 0x40040fd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[500]",
+    },
+    crate::trace::Register {
+        address: 0x40040fd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[501]",
+    },
+    crate::trace::Register {
+        address: 0x40040fd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[502]",
+    },
+    crate::trace::Register {
+        address: 0x40040fdc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[503]",
+    },
+    crate::trace::Register {
+        address: 0x40040fe0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[504]",
+    },
+    crate::trace::Register {
+        address: 0x40040fe4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[505]",
+    },
+    crate::trace::Register {
+        address: 0x40040fe8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[506]",
+    },
+    crate::trace::Register {
+        address: 0x40040fec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[507]",
+    },
+    crate::trace::Register {
+        address: 0x40040ff0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[508]",
+    },
+    crate::trace::Register {
+        address: 0x40040ff4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[509]",
+    },
+    crate::trace::Register {
+        address: 0x40040ff8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[510]",
+    },
+    crate::trace::Register {
+        address: 0x40040ffc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[511]",
+    },
+    crate::trace::Register {
+        address: 0x40041000,
+        size: 1,
+        peripheral: "ELC",
+        register: "ELCR",
+    },
+    crate::trace::Register {
+        address: 0x40041002,
+        size: 1,
+        peripheral: "ELC",
+        register: "ELSEGR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40041004,
+        size: 1,
+        peripheral: "ELC",
+        register: "ELSEGR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40041010,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40041014,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40041018,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4004101c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40041020,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40041024,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40041028,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[6]",
+    },
+    crate::trace::Register {
+        address: 0x4004102c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40041030,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[8]",
+    },
+    crate::trace::Register {
+        address: 0x40041034,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[9]",
+    },
+    crate::trace::Register {
+        address: 0x40041038,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[10]",
+    },
+    crate::trace::Register {
+        address: 0x4004103c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[11]",
+    },
+    crate::trace::Register {
+        address: 0x40041040,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[12]",
+    },
+    crate::trace::Register {
+        address: 0x40041044,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[13]",
+    },
+    crate::trace::Register {
+        address: 0x40041048,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[14]",
+    },
+    crate::trace::Register {
+        address: 0x4004104c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[15]",
+    },
+    crate::trace::Register {
+        address: 0x40041050,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[16]",
+    },
+    crate::trace::Register {
+        address: 0x40041054,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[17]",
+    },
+    crate::trace::Register {
+        address: 0x40041058,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[18]",
+    },
+    crate::trace::Register {
+        address: 0x40047000,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRB",
+    },
+    crate::trace::Register {
+        address: 0x40047004,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRC",
+    },
+    crate::trace::Register {
+        address: 0x40047008,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRD",
+    },
+    crate::trace::Register {
+        address: 0x4004700c,
+        size: 2,
+        peripheral: "MSTP",
+        register: "LSMRWDIS",
+    },
+    crate::trace::Register {
+        address: 0x40078000,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTWP",
+    },
+    crate::trace::Register {
+        address: 0x40078004,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTSTR",
+    },
+    crate::trace::Register {
+        address: 0x40078008,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTSTP",
+    },
+    crate::trace::Register {
+        address: 0x4007800c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCLR",
+    },
+    crate::trace::Register {
+        address: 0x40078010,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTSSR",
+    },
+    crate::trace::Register {
+        address: 0x40078014,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTPSR",
+    },
+    crate::trace::Register {
+        address: 0x40078018,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCSR",
+    },
+    crate::trace::Register {
+        address: 0x4007801c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTUPSR",
+    },
+    crate::trace::Register {
+        address: 0x40078020,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTDNSR",
+    },
+    crate::trace::Register {
+        address: 0x40078024,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTICASR",
+    },
+    crate::trace::Register {
+        address: 0x40078028,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTICBSR",
+    },
+    crate::trace::Register {
+        address: 0x4007802c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCR",
+    },
+    crate::trace::Register {
+        address: 0x40078030,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTUDDTYC",
+    },
+    crate::trace::Register {
+        address: 0x40078034,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTIOR",
+    },
+    crate::trace::Register {
+        address: 0x40078038,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTINTAD",
+    },
+    crate::trace::Register {
+        address: 0x4007803c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTST",
+    },
+    crate::trace::Register {
+        address: 0x40078040,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTBER",
+    },
+    crate::trace::Register {
+        address: 0x40078048,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCNT",
+    },
+    crate::trace::Register {
+        address: 0x4007804c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCCRA",
+    },
+    crate::trace::Register {
+        address: 0x40078050,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCCRB",
+    },
+    crate::trace::Register {
+        address: 0x40078054,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCCRC",
+    },
+    crate::trace::Register {
+        address: 0x40078058,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCCRE",
+    },
+    crate::trace::Register {
+        address: 0x4007805c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCCRD",
+    },
+    crate::trace::Register {
+        address: 0x40078060,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTCCRF",
+    },
+    crate::trace::Register {
+        address: 0x40078064,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTPR",
+    },
+    crate::trace::Register {
+        address: 0x40078068,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTPBR",
+    },
+    crate::trace::Register {
+        address: 0x40078088,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTDTCR",
+    },
+    crate::trace::Register {
+        address: 0x4007808c,
+        size: 4,
+        peripheral: "GPT320",
+        register: "GTDVU",
+    },
+    crate::trace::Register {
+        address: 0x40078400,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTWP",
+    },
+    crate::trace::Register {
+        address: 0x40078404,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTSTR",
+    },
+    crate::trace::Register {
+        address: 0x40078408,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTSTP",
+    },
+    crate::trace::Register {
+        address: 0x4007840c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCLR",
+    },
+    crate::trace::Register {
+        address: 0x40078410,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTSSR",
+    },
+    crate::trace::Register {
+        address: 0x40078414,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTPSR",
+    },
+    crate::trace::Register {
+        address: 0x40078418,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCSR",
+    },
+    crate::trace::Register {
+        address: 0x4007841c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTUPSR",
+    },
+    crate::trace::Register {
+        address: 0x40078420,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTDNSR",
+    },
+    crate::trace::Register {
+        address: 0x40078424,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTICASR",
+    },
+    crate::trace::Register {
+        address: 0x40078428,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTICBSR",
+    },
+    crate::trace::Register {
+        address: 0x4007842c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCR",
+    },
+    crate::trace::Register {
+        address: 0x40078430,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTUDDTYC",
+    },
+    crate::trace::Register {
+        address: 0x40078434,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTIOR",
+    },
+    crate::trace::Register {
+        address: 0x40078438,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTINTAD",
+    },
+    crate::trace::Register {
+        address: 0x4007843c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTST",
+    },
+    crate::trace::Register {
+        address: 0x40078440,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTBER",
+    },
+    crate::trace::Register {
+        address: 0x40078448,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCNT",
+    },
+    crate::trace::Register {
+        address: 0x4007844c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCCRA",
+    },
+    crate::trace::Register {
+        address: 0x40078450,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCCRB",
+    },
+    crate::trace::Register {
+        address: 0x40078454,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCCRC",
+    },
+    crate::trace::Register {
+        address: 0x40078458,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCCRE",
+    },
+    crate::trace::Register {
+        address: 0x4007845c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCCRD",
+    },
+    crate::trace::Register {
+        address: 0x40078460,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTCCRF",
+    },
+    crate::trace::Register {
+        address: 0x40078464,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTPR",
+    },
+    crate::trace::Register {
+        address: 0x40078468,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTPBR",
+    },
+    crate::trace::Register {
+        address: 0x40078488,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTDTCR",
+    },
+    crate::trace::Register {
+        address: 0x4007848c,
+        size: 4,
+        peripheral: "GPT164",
+        register: "GTDVU",
+    },
+];
+#[cfg(feature = "mock")]
+pub const MOCK_RESETS: &[crate::mock::Reset] = &[
+    crate::mock::Reset {
+        address: 0x40047008,
+        size: 4,
+        value: 0xffffffff,
+    },
+];
@@ -0,0 +1,906 @@
+// This file is autogenerated by ra-metapac-gen: do not edit by hand.
+pub const NAME: &str = "R7FA6M5BH3CFC";
+pub const FAMILY: &str = "R7FA6M5";
+pub const CORE: &str = "Cortex-M33";
+pub const INTERRUPT_COUNT: usize = 96;
+pub const MEMORY: &[MemoryRegion] = &[
+    MemoryRegion {
+        name: "FLASH",
+        kind: "Flash",
+        address: 0,
+        size: 2097152,
+    },
+    MemoryRegion {
+        name: "RAM",
+        kind: "RAM",
+        address: 536870912,
+        size: 524288,
+    },
+    MemoryRegion {
+        name: "DATA_FLASH",
+        kind: "Flash",
+        address: 134217728,
+        size: 8192,
+    },
+    MemoryRegion {
+        name: "OPTION_SETTING",
+        kind: "OptionSetting",
+        address: 16818432,
+        size: 80,
+    },
+    MemoryRegion {
+        name: "OPTION_SETTING_S",
+        kind: "OptionSetting",
+        address: 16818688,
+        size: 256,
+    },
+    MemoryRegion {
+        name: "ID_CODE",
+        kind: "IdCode",
+        address: 16818512,
+        size: 16,
+    },
+];
+pub const PERIPHERALS: &[Peripheral] = &[
+    Peripheral {
+        name: "SYSC",
+        address: 1073864704,
+        kind: "sysc",
+        version: "ra6m5",
+        mstp: None,
+        bit_width: None,
+        clock: Some("ICLK"),
+        instance: None,
+    },
+    Peripheral {
+        name: "MSTP",
+        address: 1074282496,
+        kind: "mstp",
+        version: "v2",
+        mstp: None,
+        bit_width: None,
+        clock: Some("ICLK"),
+        instance: None,
+    },
+    Peripheral {
+        name: "ICU",
+        address: 1073766400,
+        kind: "icu",
+        version: "ra6m5",
+        mstp: None,
+        bit_width: None,
+        clock: Some("ICLK"),
+        instance: None,
+    },
+    Peripheral {
+        name: "ELC",
+        address: 1074008064,
+        kind: "elc",
+        version: "v2",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: None,
+    },
+    Peripheral {
+        name: "PFS",
+        address: 1074268160,
+        kind: "pfs",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: None,
+    },
+    Peripheral {
+        name: "PORT0",
+        address: 1074266112,
+        kind: "port",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: Some(Instance {
+            group: "PORT",
+            index: 0,
+        }),
+    },
+    Peripheral {
+        name: "PORT1",
+        address: 1074266144,
+        kind: "port",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKB"),
+        instance: Some(Instance {
+            group: "PORT",
+            index: 1,
+        }),
+    },
+    Peripheral {
+        name: "GPT0",
+        address: 1075220480,
+        kind: "timer",
+        version: "v1",
+        mstp: Some(Mstp {
+            register: "MSTPCRD",
+            bit: 5,
+        }),
+        bit_width: Some(32),
+        clock: Some("PCLKD"),
+        instance: Some(Instance { group: "GPT", index: 0 }),
+    },
+    Peripheral {
+        name: "GPT1",
+        address: 1075220736,
+        kind: "timer",
+        version: "v1",
+        mstp: Some(Mstp {
+            register: "MSTPCRD",
+            bit: 5,
+        }),
+        bit_width: Some(32),
+        clock: Some("PCLKD"),
+        instance: Some(Instance { group: "GPT", index: 1 }),
+    },
+    Peripheral {
+        name: "GPT4",
+        address: 1075221504,
+        kind: "timer",
+        version: "v1",
+        mstp: Some(Mstp {
+            register: "MSTPCRD",
+            bit: 5,
+        }),
+        bit_width: Some(16),
+        clock: Some("PCLKD"),
+        instance: Some(Instance { group: "GPT", index: 4 }),
+    },
+    Peripheral {
+        name: "SCI0",
+        address: 1074888704,
+        kind: "sci",
+        version: "v1",
+        mstp: None,
+        bit_width: None,
+        clock: Some("PCLKA"),
+        instance: Some(Instance { group: "SCI", index: 0 }),
+    },
+];
+pub const EVENTS: &[Event] = &[
+    Event {
+        name: "DMAC0_INT",
+        id: 32,
+        irq_slots: &[],
+        dtc: true,
+        dmac: false,
+    },
+    Event {
+        name: "GPT0_CAPTURE_COMPARE_A",
+        id: 117,
+        irq_slots: &[],
+        dtc: true,
+        dmac: true,
+    },
+    Event {
+        name: "GPT0_COUNTER_OVERFLOW",
+        id: 123,
+        irq_slots: &[],
+        dtc: true,
+        dmac: true,
+    },
+    Event {
+        name: "ICU_IRQ0",
+        id: 1,
+        irq_slots: &[],
+        dtc: true,
+        dmac: true,
+    },
+    Event {
+        name: "ICU_IRQ1",
+        id: 2,
+        irq_slots: &[],
+        dtc: true,
+        dmac: true,
+    },
+    Event {
+        name: "SCI0_ERI",
+        id: 411,
+        irq_slots: &[],
+        dtc: false,
+        dmac: false,
+    },
+    Event {
+        name: "SCI0_RXI",
+        id: 408,
+        irq_slots: &[],
+        dtc: true,
+        dmac: true,
+    },
+    Event {
+        name: "SCI0_TEI",
+        id: 410,
+        irq_slots: &[],
+        dtc: false,
+        dmac: false,
+    },
+    Event {
+        name: "SCI0_TXI",
+        id: 409,
+        irq_slots: &[],
+        dtc: true,
+        dmac: true,
+    },
+];
+pub const DMA: Dma = Dma { dtc: true, dmac_channels: 8 };
+pub const CLOCKS: Option<Clocks> = Some(Clocks {
+    sources: &[
+        ClockSource {
+            name: "HOCO",
+            select: 0,
+            frequencies: &[16000000, 18000000, 20000000],
+        },
+        ClockSource {
+            name: "MOCO",
+            select: 1,
+            frequencies: &[8000000],
+        },
+        ClockSource {
+            name: "LOCO",
+            select: 2,
+            frequencies: &[32768],
+        },
+        ClockSource {
+            name: "MOSC",
+            select: 3,
+            frequencies: &[],
+        },
+        ClockSource {
+            name: "SOSC",
+            select: 4,
+            frequencies: &[32768],
+        },
+        ClockSource {
+            name: "PLL",
+            select: 5,
+            frequencies: &[],
+        },
+    ],
+    domains: &[
+        ClockDomain {
+            name: "BCLK",
+            register: "SCKDIVCR",
+            field: "BCK",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(100000000),
+        },
+        ClockDomain {
+            name: "FCLK",
+            register: "SCKDIVCR",
+            field: "FCK",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(50000000),
+        },
+        ClockDomain {
+            name: "ICLK",
+            register: "SCKDIVCR",
+            field: "ICK",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(200000000),
+        },
+        ClockDomain {
+            name: "PCLKA",
+            register: "SCKDIVCR",
+            field: "PCKA",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(100000000),
+        },
+        ClockDomain {
+            name: "PCLKB",
+            register: "SCKDIVCR",
+            field: "PCKB",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(50000000),
+        },
+        ClockDomain {
+            name: "PCLKC",
+            register: "SCKDIVCR",
+            field: "PCKC",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(50000000),
+        },
+        ClockDomain {
+            name: "PCLKD",
+            register: "SCKDIVCR",
+            field: "PCKD",
+            dividers: &[
+                ClockDivider {
+                    value: 0,
+                    divider: 1,
+                },
+                ClockDivider {
+                    value: 1,
+                    divider: 2,
+                },
+                ClockDivider {
+                    value: 2,
+                    divider: 4,
+                },
+                ClockDivider {
+                    value: 3,
+                    divider: 8,
+                },
+                ClockDivider {
+                    value: 4,
+                    divider: 16,
+                },
+                ClockDivider {
+                    value: 5,
+                    divider: 32,
+                },
+                ClockDivider {
+                    value: 6,
+                    divider: 64,
+                },
+            ],
+            max_frequency: Some(100000000),
+        },
+    ],
+    pll: Some(Pll {
+        input_dividers: &[
+            ClockDivider {
+                value: 0,
+                divider: 1,
+            },
+            ClockDivider {
+                value: 1,
+                divider: 2,
+            },
+            ClockDivider {
+                value: 2,
+                divider: 3,
+            },
+        ],
+        input_min_frequency: Some(8000000),
+        input_max_frequency: Some(48000000),
+        output_min_frequency: Some(120000000),
+        output_max_frequency: Some(200000000),
+        multiplier_min: Some(10.0),
+        multiplier_max: Some(30.0),
+    }),
+    manual_limits: true,
+});
+pub const ELC_EVENTS: &[ElcEvent] = &[
+    ElcEvent {
+        name: "ICU_IRQ0",
+        id: 1,
+    },
+    ElcEvent {
+        name: "ICU_IRQ1",
+        id: 2,
+    },
+    ElcEvent {
+        name: "DMAC0_INT",
+        id: 32,
+    },
+    ElcEvent {
+        name: "GPT0_CAPTURE_COMPARE_A",
+        id: 117,
+    },
+    ElcEvent {
+        name: "GPT0_COUNTER_OVERFLOW",
+        id: 123,
+    },
+    ElcEvent {
+        name: "SCI0_RXI",
+        id: 408,
+    },
+    ElcEvent {
+        name: "SCI0_TXI",
+        id: 409,
+    },
+    ElcEvent {
+        name: "SCI0_TEI",
+        id: 410,
+    },
+    ElcEvent {
+        name: "SCI0_ERI",
+        id: 411,
+    },
+];
+pub const ELC_DESTINATIONS: &[ElcDestination] = &[
+    ElcDestination {
+        name: "GPT_A",
+        index: 0,
+    },
+    ElcDestination {
+        name: "GPT_B",
+        index: 1,
+    },
+    ElcDestination {
+        name: "ADC0",
+        index: 8,
+    },
+    ElcDestination {
+        name: "ADC0_B",
+        index: 9,
+    },
+    ElcDestination {
+        name: "DAC0",
+        index: 12,
+    },
+    ElcDestination {
+        name: "IOPORT1",
+        index: 14,
+    },
+    ElcDestination {
+        name: "CTSU",
+        index: 18,
+    },
+];
+pub const PACKAGES: &[Package] = &[
+    Package {
+        name: "BGA176",
+        pins: &[
+            Pin {
+                position: "A1",
+                signals: &["p400"],
+                port: Some(4u8),
+                number: Some(0u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT6",
+                        signal: "GTIOC6A",
+                        psel: 3,
+                    },
+                    PinFunction {
+                        peripheral: "SCI4",
+                        signal: "SCK4",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "SCK7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "IIC0",
+                        signal: "SCL0",
+                        psel: 7,
+                    },
+                ],
+            },
+            Pin {
+                position: "A2",
+                signals: &["p401"],
+                port: Some(4u8),
+                number: Some(1u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT6",
+                        signal: "GTIOC6B",
+                        psel: 3,
+                    },
+                    PinFunction {
+                        peripheral: "SCI4",
+                        signal: "CTS4_RTS4",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "TXD7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "MOSI7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "SDA7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "IIC0",
+                        signal: "SDA0",
+                        psel: 7,
+                    },
+                ],
+            },
+            Pin {
+                position: "A3",
+                signals: &["VCC"],
+                port: None,
+                number: None,
+                functions: &[],
+            },
+            Pin {
+                position: "B1",
+                signals: &["p101"],
+                port: Some(1u8),
+                number: Some(1u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT5",
+                        signal: "GTIOC5A",
+                        psel: 3,
+                    },
+                    PinFunction {
+                        peripheral: "SCI0",
+                        signal: "TXD0",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI0",
+                        signal: "MOSI0",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI0",
+                        signal: "SDA0",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI1",
+                        signal: "CTS1_RTS1",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "IIC1",
+                        signal: "SDA1",
+                        psel: 7,
+                    },
+                ],
+            },
+            Pin {
+                position: "B2",
+                signals: &["pa00"],
+                port: Some(10u8),
+                number: Some(0u8),
+                functions: &[],
+            },
+        ],
+    },
+    Package {
+        name: "LQFP176",
+        pins: &[
+            Pin {
+                position: "1",
+                signals: &["p400"],
+                port: Some(4u8),
+                number: Some(0u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT6",
+                        signal: "GTIOC6A",
+                        psel: 3,
+                    },
+                    PinFunction {
+                        peripheral: "SCI4",
+                        signal: "SCK4",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "SCK7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "IIC0",
+                        signal: "SCL0",
+                        psel: 7,
+                    },
+                ],
+            },
+            Pin {
+                position: "2",
+                signals: &["p401"],
+                port: Some(4u8),
+                number: Some(1u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT6",
+                        signal: "GTIOC6B",
+                        psel: 3,
+                    },
+                    PinFunction {
+                        peripheral: "SCI4",
+                        signal: "CTS4_RTS4",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "TXD7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "MOSI7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "SCI7",
+                        signal: "SDA7",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "IIC0",
+                        signal: "SDA0",
+                        psel: 7,
+                    },
+                ],
+            },
+            Pin {
+                position: "3",
+                signals: &["VCC"],
+                port: None,
+                number: None,
+                functions: &[],
+            },
+            Pin {
+                position: "4",
+                signals: &["p101"],
+                port: Some(1u8),
+                number: Some(1u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT5",
+                        signal: "GTIOC5A",
+                        psel: 3,
+                    },
+                    PinFunction {
+                        peripheral: "SCI0",
+                        signal: "TXD0",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI0",
+                        signal: "MOSI0",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI0",
+                        signal: "SDA0",
+                        psel: 4,
+                    },
+                    PinFunction {
+                        peripheral: "SCI1",
+                        signal: "CTS1_RTS1",
+                        psel: 5,
+                    },
+                    PinFunction {
+                        peripheral: "IIC1",
+                        signal: "SDA1",
+                        psel: 7,
+                    },
+                ],
+            },
+            Pin {
+                position: "5",
+                signals: &["pa00"],
+                port: Some(10u8),
+                number: Some(0u8),
+                functions: &[],
+            },
+            Pin {
+                position: "7",
+                signals: &["p105"],
+                port: Some(1u8),
+                number: Some(5u8),
+                functions: &[
+                    PinFunction {
+                        peripheral: "GPT1",
+                        signal: "GTIOC1A",
+                        psel: 3,
+                    },
+                ],
+            },
+        ],
+    },
+];
+pub const REGISTER_RESETS: &[RegisterReset] = &[
+    RegisterReset {
+        peripheral: "MSTP",
+        register: "MSTPCRD",
+        address: 0x4008400c,
+        bit_size: 32,
+        value: 0xffffffff,
+    },
+    RegisterReset {
+        peripheral: "GPT0",
+        register: "GTCNT",
+        address: 0x40169048,
+        bit_size: 32,
+        value: 0x00000000,
+    },
+    RegisterReset {
+        peripheral: "GPT1",
+        register: "GTCNT",
+        address: 0x40169148,
+        bit_size: 32,
+        value: 0x00000000,
+    },
+    RegisterReset {
+        peripheral: "GPT4",
+        register: "GTCNT",
+        address: 0x40169448,
+        bit_size: 32,
+        value: 0x00000000,
+    },
+];
@@ -0,0 +1,8599 @@
+// This file is autogenerated by ra-metapac-gen: do not edit by hand.
+pub mod peripherals {
+    #[derive(Copy, Clone)]
+    pub struct SYSC(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for SYSC {}
+    impl SYSC {
+        pub const REGS: crate::_peripherals::sysc_ra6m5::Sysc = unsafe {
+            crate::_peripherals::sysc_ra6m5::Sysc::from_ptr(0x4001e000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for SYSC {
+        type Target = crate::_peripherals::sysc_ra6m5::Sysc;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for SYSC {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[0]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct MSTP(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for MSTP {}
+    impl MSTP {
+        pub const REGS: crate::_peripherals::mstp_v2::Mstp = unsafe {
+            crate::_peripherals::mstp_v2::Mstp::from_ptr(0x40084000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for MSTP {
+        type Target = crate::_peripherals::mstp_v2::Mstp;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for MSTP {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[1]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct ICU(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for ICU {}
+    impl ICU {
+        pub const REGS: crate::_peripherals::icu_ra6m5::Icu = unsafe {
+            crate::_peripherals::icu_ra6m5::Icu::from_ptr(0x40006000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for ICU {
+        type Target = crate::_peripherals::icu_ra6m5::Icu;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for ICU {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[2]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct ELC(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for ELC {}
+    impl ELC {
+        pub const REGS: crate::_peripherals::elc_v2::Elc = unsafe {
+            crate::_peripherals::elc_v2::Elc::from_ptr(0x40041000 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for ELC {
+        type Target = crate::_peripherals::elc_v2::Elc;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for ELC {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[3]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct PFS(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for PFS {}
+    impl PFS {
+        pub const REGS: crate::_peripherals::pfs_v1::Pfs = unsafe {
+            crate::_peripherals::pfs_v1::Pfs::from_ptr(0x40080800 as *mut ())
+        };
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for PFS {
+        type Target = crate::_peripherals::pfs_v1::Pfs;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for PFS {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[4]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct PORT0(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for PORT0 {}
+    impl PORT0 {
+        pub const REGS: crate::_peripherals::port_v1::Port = unsafe {
+            crate::_peripherals::port_v1::Port::from_ptr(0x40080000 as *mut ())
+        };
+        pub const INDEX: usize = 0;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for PORT0 {
+        type Target = crate::_peripherals::port_v1::Port;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for PORT0 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[5]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct PORT1(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for PORT1 {}
+    impl PORT1 {
+        pub const REGS: crate::_peripherals::port_v1::Port = unsafe {
+            crate::_peripherals::port_v1::Port::from_ptr(0x40080020 as *mut ())
+        };
+        pub const INDEX: usize = 1;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for PORT1 {
+        type Target = crate::_peripherals::port_v1::Port;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for PORT1 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[6]
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct GPT0(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for GPT0 {}
+    impl GPT0 {
+        pub const REGS: crate::_peripherals::timer_v1::Gpt = unsafe {
+            crate::_peripherals::timer_v1::Gpt::from_ptr(0x40169000 as *mut ())
+        };
+        pub const INDEX: usize = 0;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for GPT0 {
+        type Target = crate::_peripherals::timer_v1::Gpt;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for GPT0 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[7]
+        }
+    }
+    impl crate::MstpPeripheral for GPT0 {
+        fn enable_clock() {
+            critical_section::with(|_| {
+                MSTP::REGS
+                    .mstpcrd()
+                    .write(|w| w.0 = MSTP::REGS.mstpcrd().read().0 & !(1 << 5));
+            });
+        }
+        fn disable_clock() {
+            critical_section::with(|_| {
+                MSTP::REGS
+                    .mstpcrd()
+                    .write(|w| w.0 = MSTP::REGS.mstpcrd().read().0 | 1 << 5);
+            });
+        }
+        fn is_clock_enabled() -> bool {
+            MSTP::REGS.mstpcrd().read().0 & (1 << 5) == 0
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct GPT1(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for GPT1 {}
+    impl GPT1 {
+        pub const REGS: crate::_peripherals::timer_v1::Gpt = unsafe {
+            crate::_peripherals::timer_v1::Gpt::from_ptr(0x40169100 as *mut ())
+        };
+        pub const INDEX: usize = 1;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for GPT1 {
+        type Target = crate::_peripherals::timer_v1::Gpt;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for GPT1 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[8]
+        }
+    }
+    impl crate::MstpPeripheral for GPT1 {
+        fn enable_clock() {
+            critical_section::with(|_| {
+                MSTP::REGS
+                    .mstpcrd()
+                    .write(|w| w.0 = MSTP::REGS.mstpcrd().read().0 & !(1 << 5));
+            });
+        }
+        fn disable_clock() {
+            critical_section::with(|_| {
+                MSTP::REGS
+                    .mstpcrd()
+                    .write(|w| w.0 = MSTP::REGS.mstpcrd().read().0 | 1 << 5);
+            });
+        }
+        fn is_clock_enabled() -> bool {
+            MSTP::REGS.mstpcrd().read().0 & (1 << 5) == 0
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct GPT4(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for GPT4 {}
+    impl GPT4 {
+        pub const REGS: crate::_peripherals::timer_v1::Gpt = unsafe {
+            crate::_peripherals::timer_v1::Gpt::from_ptr(0x40169400 as *mut ())
+        };
+        pub const INDEX: usize = 4;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for GPT4 {
+        type Target = crate::_peripherals::timer_v1::Gpt;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for GPT4 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[9]
+        }
+    }
+    impl crate::MstpPeripheral for GPT4 {
+        fn enable_clock() {
+            critical_section::with(|_| {
+                MSTP::REGS
+                    .mstpcrd()
+                    .write(|w| w.0 = MSTP::REGS.mstpcrd().read().0 & !(1 << 5));
+            });
+        }
+        fn disable_clock() {
+            critical_section::with(|_| {
+                MSTP::REGS
+                    .mstpcrd()
+                    .write(|w| w.0 = MSTP::REGS.mstpcrd().read().0 | 1 << 5);
+            });
+        }
+        fn is_clock_enabled() -> bool {
+            MSTP::REGS.mstpcrd().read().0 & (1 << 5) == 0
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct SCI0(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for SCI0 {}
+    impl SCI0 {
+        pub const REGS: crate::_peripherals::sci_v1::Sci = unsafe {
+            crate::_peripherals::sci_v1::Sci::from_ptr(0x40118000 as *mut ())
+        };
+        pub const INDEX: usize = 0;
+        pub unsafe fn steal() -> Self {
+            Self(())
+        }
+    }
+    impl core::ops::Deref for SCI0 {
+        type Target = crate::_peripherals::sci_v1::Sci;
+        fn deref(&self) -> &Self::Target {
+            &Self::REGS
+        }
+    }
+    #[cfg(feature = "metadata")]
+    impl crate::Peripheral for SCI0 {
+        fn metadata() -> &'static crate::metadata::Peripheral {
+            &crate::metadata::PERIPHERALS[10]
+        }
+    }
+    pub const GPT_REGS: [Option<crate::_peripherals::timer_v1::Gpt>; 5] = [
+        Some(GPT0::REGS),
+        Some(GPT1::REGS),
+        None,
+        None,
+        Some(GPT4::REGS),
+    ];
+    pub const PORT_REGS: [Option<crate::_peripherals::port_v1::Port>; 2] = [
+        Some(PORT0::REGS),
+        Some(PORT1::REGS),
+    ];
+    pub const SCI_REGS: [Option<crate::_peripherals::sci_v1::Sci>; 1] = [
+        Some(SCI0::REGS),
+    ];
+    pub struct Peripherals {
+        #[cfg(feature = "embassy")]
+        pub sysc: embassy_hal_internal::Peri<'static, SYSC>,
+        #[cfg(not(feature = "embassy"))]
+        pub sysc: SYSC,
+        #[cfg(feature = "embassy")]
+        pub mstp: embassy_hal_internal::Peri<'static, MSTP>,
+        #[cfg(not(feature = "embassy"))]
+        pub mstp: MSTP,
+        #[cfg(feature = "embassy")]
+        pub icu: embassy_hal_internal::Peri<'static, ICU>,
+        #[cfg(not(feature = "embassy"))]
+        pub icu: ICU,
+        #[cfg(feature = "embassy")]
+        pub elc: embassy_hal_internal::Peri<'static, ELC>,
+        #[cfg(not(feature = "embassy"))]
+        pub elc: ELC,
+        #[cfg(feature = "embassy")]
+        pub pfs: embassy_hal_internal::Peri<'static, PFS>,
+        #[cfg(not(feature = "embassy"))]
+        pub pfs: PFS,
+        #[cfg(feature = "embassy")]
+        pub port0: embassy_hal_internal::Peri<'static, PORT0>,
+        #[cfg(not(feature = "embassy"))]
+        pub port0: PORT0,
+        #[cfg(feature = "embassy")]
+        pub port1: embassy_hal_internal::Peri<'static, PORT1>,
+        #[cfg(not(feature = "embassy"))]
+        pub port1: PORT1,
+        #[cfg(feature = "embassy")]
+        pub gpt0: embassy_hal_internal::Peri<'static, GPT0>,
+        #[cfg(not(feature = "embassy"))]
+        pub gpt0: GPT0,
+        #[cfg(feature = "embassy")]
+        pub gpt1: embassy_hal_internal::Peri<'static, GPT1>,
+        #[cfg(not(feature = "embassy"))]
+        pub gpt1: GPT1,
+        #[cfg(feature = "embassy")]
+        pub gpt4: embassy_hal_internal::Peri<'static, GPT4>,
+        #[cfg(not(feature = "embassy"))]
+        pub gpt4: GPT4,
+        #[cfg(feature = "embassy")]
+        pub sci0: embassy_hal_internal::Peri<'static, SCI0>,
+        #[cfg(not(feature = "embassy"))]
+        pub sci0: SCI0,
+        #[cfg(feature = "embassy")]
+        pub iel0: embassy_hal_internal::Peri<'static, IEL0>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel0: IEL0,
+        #[cfg(feature = "embassy")]
+        pub iel1: embassy_hal_internal::Peri<'static, IEL1>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel1: IEL1,
+        #[cfg(feature = "embassy")]
+        pub iel2: embassy_hal_internal::Peri<'static, IEL2>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel2: IEL2,
+        #[cfg(feature = "embassy")]
+        pub iel3: embassy_hal_internal::Peri<'static, IEL3>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel3: IEL3,
+        #[cfg(feature = "embassy")]
+        pub iel4: embassy_hal_internal::Peri<'static, IEL4>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel4: IEL4,
+        #[cfg(feature = "embassy")]
+        pub iel5: embassy_hal_internal::Peri<'static, IEL5>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel5: IEL5,
+        #[cfg(feature = "embassy")]
+        pub iel6: embassy_hal_internal::Peri<'static, IEL6>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel6: IEL6,
+        #[cfg(feature = "embassy")]
+        pub iel7: embassy_hal_internal::Peri<'static, IEL7>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel7: IEL7,
+        #[cfg(feature = "embassy")]
+        pub iel8: embassy_hal_internal::Peri<'static, IEL8>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel8: IEL8,
+        #[cfg(feature = "embassy")]
+        pub iel9: embassy_hal_internal::Peri<'static, IEL9>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel9: IEL9,
+        #[cfg(feature = "embassy")]
+        pub iel10: embassy_hal_internal::Peri<'static, IEL10>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel10: IEL10,
+        #[cfg(feature = "embassy")]
+        pub iel11: embassy_hal_internal::Peri<'static, IEL11>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel11: IEL11,
+        #[cfg(feature = "embassy")]
+        pub iel12: embassy_hal_internal::Peri<'static, IEL12>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel12: IEL12,
+        #[cfg(feature = "embassy")]
+        pub iel13: embassy_hal_internal::Peri<'static, IEL13>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel13: IEL13,
+        #[cfg(feature = "embassy")]
+        pub iel14: embassy_hal_internal::Peri<'static, IEL14>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel14: IEL14,
+        #[cfg(feature = "embassy")]
+        pub iel15: embassy_hal_internal::Peri<'static, IEL15>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel15: IEL15,
+        #[cfg(feature = "embassy")]
+        pub iel16: embassy_hal_internal::Peri<'static, IEL16>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel16: IEL16,
+        #[cfg(feature = "embassy")]
+        pub iel17: embassy_hal_internal::Peri<'static, IEL17>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel17: IEL17,
+        #[cfg(feature = "embassy")]
+        pub iel18: embassy_hal_internal::Peri<'static, IEL18>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel18: IEL18,
+        #[cfg(feature = "embassy")]
+        pub iel19: embassy_hal_internal::Peri<'static, IEL19>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel19: IEL19,
+        #[cfg(feature = "embassy")]
+        pub iel20: embassy_hal_internal::Peri<'static, IEL20>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel20: IEL20,
+        #[cfg(feature = "embassy")]
+        pub iel21: embassy_hal_internal::Peri<'static, IEL21>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel21: IEL21,
+        #[cfg(feature = "embassy")]
+        pub iel22: embassy_hal_internal::Peri<'static, IEL22>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel22: IEL22,
+        #[cfg(feature = "embassy")]
+        pub iel23: embassy_hal_internal::Peri<'static, IEL23>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel23: IEL23,
+        #[cfg(feature = "embassy")]
+        pub iel24: embassy_hal_internal::Peri<'static, IEL24>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel24: IEL24,
+        #[cfg(feature = "embassy")]
+        pub iel25: embassy_hal_internal::Peri<'static, IEL25>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel25: IEL25,
+        #[cfg(feature = "embassy")]
+        pub iel26: embassy_hal_internal::Peri<'static, IEL26>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel26: IEL26,
+        #[cfg(feature = "embassy")]
+        pub iel27: embassy_hal_internal::Peri<'static, IEL27>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel27: IEL27,
+        #[cfg(feature = "embassy")]
+        pub iel28: embassy_hal_internal::Peri<'static, IEL28>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel28: IEL28,
+        #[cfg(feature = "embassy")]
+        pub iel29: embassy_hal_internal::Peri<'static, IEL29>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel29: IEL29,
+        #[cfg(feature = "embassy")]
+        pub iel30: embassy_hal_internal::Peri<'static, IEL30>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel30: IEL30,
+        #[cfg(feature = "embassy")]
+        pub iel31: embassy_hal_internal::Peri<'static, IEL31>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel31: IEL31,
+        #[cfg(feature = "embassy")]
+        pub iel32: embassy_hal_internal::Peri<'static, IEL32>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel32: IEL32,
+        #[cfg(feature = "embassy")]
+        pub iel33: embassy_hal_internal::Peri<'static, IEL33>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel33: IEL33,
+        #[cfg(feature = "embassy")]
+        pub iel34: embassy_hal_internal::Peri<'static, IEL34>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel34: IEL34,
+        #[cfg(feature = "embassy")]
+        pub iel35: embassy_hal_internal::Peri<'static, IEL35>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel35: IEL35,
+        #[cfg(feature = "embassy")]
+        pub iel36: embassy_hal_internal::Peri<'static, IEL36>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel36: IEL36,
+        #[cfg(feature = "embassy")]
+        pub iel37: embassy_hal_internal::Peri<'static, IEL37>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel37: IEL37,
+        #[cfg(feature = "embassy")]
+        pub iel38: embassy_hal_internal::Peri<'static, IEL38>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel38: IEL38,
+        #[cfg(feature = "embassy")]
+        pub iel39: embassy_hal_internal::Peri<'static, IEL39>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel39: IEL39,
+        #[cfg(feature = "embassy")]
+        pub iel40: embassy_hal_internal::Peri<'static, IEL40>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel40: IEL40,
+        #[cfg(feature = "embassy")]
+        pub iel41: embassy_hal_internal::Peri<'static, IEL41>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel41: IEL41,
+        #[cfg(feature = "embassy")]
+        pub iel42: embassy_hal_internal::Peri<'static, IEL42>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel42: IEL42,
+        #[cfg(feature = "embassy")]
+        pub iel43: embassy_hal_internal::Peri<'static, IEL43>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel43: IEL43,
+        #[cfg(feature = "embassy")]
+        pub iel44: embassy_hal_internal::Peri<'static, IEL44>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel44: IEL44,
+        #[cfg(feature = "embassy")]
+        pub iel45: embassy_hal_internal::Peri<'static, IEL45>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel45: IEL45,
+        #[cfg(feature = "embassy")]
+        pub iel46: embassy_hal_internal::Peri<'static, IEL46>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel46: IEL46,
+        #[cfg(feature = "embassy")]
+        pub iel47: embassy_hal_internal::Peri<'static, IEL47>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel47: IEL47,
+        #[cfg(feature = "embassy")]
+        pub iel48: embassy_hal_internal::Peri<'static, IEL48>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel48: IEL48,
+        #[cfg(feature = "embassy")]
+        pub iel49: embassy_hal_internal::Peri<'static, IEL49>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel49: IEL49,
+        #[cfg(feature = "embassy")]
+        pub iel50: embassy_hal_internal::Peri<'static, IEL50>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel50: IEL50,
+        #[cfg(feature = "embassy")]
+        pub iel51: embassy_hal_internal::Peri<'static, IEL51>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel51: IEL51,
+        #[cfg(feature = "embassy")]
+        pub iel52: embassy_hal_internal::Peri<'static, IEL52>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel52: IEL52,
+        #[cfg(feature = "embassy")]
+        pub iel53: embassy_hal_internal::Peri<'static, IEL53>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel53: IEL53,
+        #[cfg(feature = "embassy")]
+        pub iel54: embassy_hal_internal::Peri<'static, IEL54>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel54: IEL54,
+        #[cfg(feature = "embassy")]
+        pub iel55: embassy_hal_internal::Peri<'static, IEL55>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel55: IEL55,
+        #[cfg(feature = "embassy")]
+        pub iel56: embassy_hal_internal::Peri<'static, IEL56>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel56: IEL56,
+        #[cfg(feature = "embassy")]
+        pub iel57: embassy_hal_internal::Peri<'static, IEL57>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel57: IEL57,
+        #[cfg(feature = "embassy")]
+        pub iel58: embassy_hal_internal::Peri<'static, IEL58>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel58: IEL58,
+        #[cfg(feature = "embassy")]
+        pub iel59: embassy_hal_internal::Peri<'static, IEL59>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel59: IEL59,
+        #[cfg(feature = "embassy")]
+        pub iel60: embassy_hal_internal::Peri<'static, IEL60>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel60: IEL60,
+        #[cfg(feature = "embassy")]
+        pub iel61: embassy_hal_internal::Peri<'static, IEL61>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel61: IEL61,
+        #[cfg(feature = "embassy")]
+        pub iel62: embassy_hal_internal::Peri<'static, IEL62>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel62: IEL62,
+        #[cfg(feature = "embassy")]
+        pub iel63: embassy_hal_internal::Peri<'static, IEL63>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel63: IEL63,
+        #[cfg(feature = "embassy")]
+        pub iel64: embassy_hal_internal::Peri<'static, IEL64>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel64: IEL64,
+        #[cfg(feature = "embassy")]
+        pub iel65: embassy_hal_internal::Peri<'static, IEL65>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel65: IEL65,
+        #[cfg(feature = "embassy")]
+        pub iel66: embassy_hal_internal::Peri<'static, IEL66>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel66: IEL66,
+        #[cfg(feature = "embassy")]
+        pub iel67: embassy_hal_internal::Peri<'static, IEL67>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel67: IEL67,
+        #[cfg(feature = "embassy")]
+        pub iel68: embassy_hal_internal::Peri<'static, IEL68>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel68: IEL68,
+        #[cfg(feature = "embassy")]
+        pub iel69: embassy_hal_internal::Peri<'static, IEL69>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel69: IEL69,
+        #[cfg(feature = "embassy")]
+        pub iel70: embassy_hal_internal::Peri<'static, IEL70>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel70: IEL70,
+        #[cfg(feature = "embassy")]
+        pub iel71: embassy_hal_internal::Peri<'static, IEL71>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel71: IEL71,
+        #[cfg(feature = "embassy")]
+        pub iel72: embassy_hal_internal::Peri<'static, IEL72>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel72: IEL72,
+        #[cfg(feature = "embassy")]
+        pub iel73: embassy_hal_internal::Peri<'static, IEL73>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel73: IEL73,
+        #[cfg(feature = "embassy")]
+        pub iel74: embassy_hal_internal::Peri<'static, IEL74>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel74: IEL74,
+        #[cfg(feature = "embassy")]
+        pub iel75: embassy_hal_internal::Peri<'static, IEL75>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel75: IEL75,
+        #[cfg(feature = "embassy")]
+        pub iel76: embassy_hal_internal::Peri<'static, IEL76>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel76: IEL76,
+        #[cfg(feature = "embassy")]
+        pub iel77: embassy_hal_internal::Peri<'static, IEL77>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel77: IEL77,
+        #[cfg(feature = "embassy")]
+        pub iel78: embassy_hal_internal::Peri<'static, IEL78>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel78: IEL78,
+        #[cfg(feature = "embassy")]
+        pub iel79: embassy_hal_internal::Peri<'static, IEL79>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel79: IEL79,
+        #[cfg(feature = "embassy")]
+        pub iel80: embassy_hal_internal::Peri<'static, IEL80>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel80: IEL80,
+        #[cfg(feature = "embassy")]
+        pub iel81: embassy_hal_internal::Peri<'static, IEL81>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel81: IEL81,
+        #[cfg(feature = "embassy")]
+        pub iel82: embassy_hal_internal::Peri<'static, IEL82>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel82: IEL82,
+        #[cfg(feature = "embassy")]
+        pub iel83: embassy_hal_internal::Peri<'static, IEL83>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel83: IEL83,
+        #[cfg(feature = "embassy")]
+        pub iel84: embassy_hal_internal::Peri<'static, IEL84>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel84: IEL84,
+        #[cfg(feature = "embassy")]
+        pub iel85: embassy_hal_internal::Peri<'static, IEL85>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel85: IEL85,
+        #[cfg(feature = "embassy")]
+        pub iel86: embassy_hal_internal::Peri<'static, IEL86>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel86: IEL86,
+        #[cfg(feature = "embassy")]
+        pub iel87: embassy_hal_internal::Peri<'static, IEL87>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel87: IEL87,
+        #[cfg(feature = "embassy")]
+        pub iel88: embassy_hal_internal::Peri<'static, IEL88>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel88: IEL88,
+        #[cfg(feature = "embassy")]
+        pub iel89: embassy_hal_internal::Peri<'static, IEL89>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel89: IEL89,
+        #[cfg(feature = "embassy")]
+        pub iel90: embassy_hal_internal::Peri<'static, IEL90>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel90: IEL90,
+        #[cfg(feature = "embassy")]
+        pub iel91: embassy_hal_internal::Peri<'static, IEL91>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel91: IEL91,
+        #[cfg(feature = "embassy")]
+        pub iel92: embassy_hal_internal::Peri<'static, IEL92>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel92: IEL92,
+        #[cfg(feature = "embassy")]
+        pub iel93: embassy_hal_internal::Peri<'static, IEL93>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel93: IEL93,
+        #[cfg(feature = "embassy")]
+        pub iel94: embassy_hal_internal::Peri<'static, IEL94>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel94: IEL94,
+        #[cfg(feature = "embassy")]
+        pub iel95: embassy_hal_internal::Peri<'static, IEL95>,
+        #[cfg(not(feature = "embassy"))]
+        pub iel95: IEL95,
+        #[cfg(feature = "embassy")]
+        pub p101: embassy_hal_internal::Peri<'static, P101>,
+        #[cfg(not(feature = "embassy"))]
+        pub p101: P101,
+        #[cfg(any(package_lqfp176))]
+        #[cfg(feature = "embassy")]
+        pub p105: embassy_hal_internal::Peri<'static, P105>,
+        #[cfg(any(package_lqfp176))]
+        #[cfg(not(feature = "embassy"))]
+        pub p105: P105,
+        #[cfg(feature = "embassy")]
+        pub p400: embassy_hal_internal::Peri<'static, P400>,
+        #[cfg(not(feature = "embassy"))]
+        pub p400: P400,
+        #[cfg(feature = "embassy")]
+        pub p401: embassy_hal_internal::Peri<'static, P401>,
+        #[cfg(not(feature = "embassy"))]
+        pub p401: P401,
+        #[cfg(feature = "embassy")]
+        pub pa00: embassy_hal_internal::Peri<'static, PA00>,
+        #[cfg(not(feature = "embassy"))]
+        pub pa00: PA00,
+    }
+    impl Peripherals {
+        pub unsafe fn steal() -> Self {
+            Self {
+                #[cfg(feature = "embassy")]
+                sysc: embassy_hal_internal::Peri::new_unchecked(SYSC(())),
+                #[cfg(not(feature = "embassy"))]
+                sysc: SYSC(()),
+                #[cfg(feature = "embassy")]
+                mstp: embassy_hal_internal::Peri::new_unchecked(MSTP(())),
+                #[cfg(not(feature = "embassy"))]
+                mstp: MSTP(()),
+                #[cfg(feature = "embassy")]
+                icu: embassy_hal_internal::Peri::new_unchecked(ICU(())),
+                #[cfg(not(feature = "embassy"))]
+                icu: ICU(()),
+                #[cfg(feature = "embassy")]
+                elc: embassy_hal_internal::Peri::new_unchecked(ELC(())),
+                #[cfg(not(feature = "embassy"))]
+                elc: ELC(()),
+                #[cfg(feature = "embassy")]
+                pfs: embassy_hal_internal::Peri::new_unchecked(PFS(())),
+                #[cfg(not(feature = "embassy"))]
+                pfs: PFS(()),
+                #[cfg(feature = "embassy")]
+                port0: embassy_hal_internal::Peri::new_unchecked(PORT0(())),
+                #[cfg(not(feature = "embassy"))]
+                port0: PORT0(()),
+                #[cfg(feature = "embassy")]
+                port1: embassy_hal_internal::Peri::new_unchecked(PORT1(())),
+                #[cfg(not(feature = "embassy"))]
+                port1: PORT1(()),
+                #[cfg(feature = "embassy")]
+                gpt0: embassy_hal_internal::Peri::new_unchecked(GPT0(())),
+                #[cfg(not(feature = "embassy"))]
+                gpt0: GPT0(()),
+                #[cfg(feature = "embassy")]
+                gpt1: embassy_hal_internal::Peri::new_unchecked(GPT1(())),
+                #[cfg(not(feature = "embassy"))]
+                gpt1: GPT1(()),
+                #[cfg(feature = "embassy")]
+                gpt4: embassy_hal_internal::Peri::new_unchecked(GPT4(())),
+                #[cfg(not(feature = "embassy"))]
+                gpt4: GPT4(()),
+                #[cfg(feature = "embassy")]
+                sci0: embassy_hal_internal::Peri::new_unchecked(SCI0(())),
+                #[cfg(not(feature = "embassy"))]
+                sci0: SCI0(()),
+                #[cfg(feature = "embassy")]
+                iel0: embassy_hal_internal::Peri::new_unchecked(IEL0(())),
+                #[cfg(not(feature = "embassy"))]
+                iel0: IEL0(()),
+                #[cfg(feature = "embassy")]
+                iel1: embassy_hal_internal::Peri::new_unchecked(IEL1(())),
+                #[cfg(not(feature = "embassy"))]
+                iel1: IEL1(()),
+                #[cfg(feature = "embassy")]
+                iel2: embassy_hal_internal::Peri::new_unchecked(IEL2(())),
+                #[cfg(not(feature = "embassy"))]
+                iel2: IEL2(()),
+                #[cfg(feature = "embassy")]
+                iel3: embassy_hal_internal::Peri::new_unchecked(IEL3(())),
+                #[cfg(not(feature = "embassy"))]
+                iel3: IEL3(()),
+                #[cfg(feature = "embassy")]
+                iel4: embassy_hal_internal::Peri::new_unchecked(IEL4(())),
+                #[cfg(not(feature = "embassy"))]
+                iel4: IEL4(()),
+                #[cfg(feature = "embassy")]
+                iel5: embassy_hal_internal::Peri::new_unchecked(IEL5(())),
+                #[cfg(not(feature = "embassy"))]
+                iel5: IEL5(()),
+                #[cfg(feature = "embassy")]
+                iel6: embassy_hal_internal::Peri::new_unchecked(IEL6(())),
+                #[cfg(not(feature = "embassy"))]
+                iel6: IEL6(()),
+                #[cfg(feature = "embassy")]
+                iel7: embassy_hal_internal::Peri::new_unchecked(IEL7(())),
+                #[cfg(not(feature = "embassy"))]
+                iel7: IEL7(()),
+                #[cfg(feature = "embassy")]
+                iel8: embassy_hal_internal::Peri::new_unchecked(IEL8(())),
+                #[cfg(not(feature = "embassy"))]
+                iel8: IEL8(()),
+                #[cfg(feature = "embassy")]
+                iel9: embassy_hal_internal::Peri::new_unchecked(IEL9(())),
+                #[cfg(not(feature = "embassy"))]
+                iel9: IEL9(()),
+                #[cfg(feature = "embassy")]
+                iel10: embassy_hal_internal::Peri::new_unchecked(IEL10(())),
+                #[cfg(not(feature = "embassy"))]
+                iel10: IEL10(()),
+                #[cfg(feature = "embassy")]
+                iel11: embassy_hal_internal::Peri::new_unchecked(IEL11(())),
+                #[cfg(not(feature = "embassy"))]
+                iel11: IEL11(()),
+                #[cfg(feature = "embassy")]
+                iel12: embassy_hal_internal::Peri::new_unchecked(IEL12(())),
+                #[cfg(not(feature = "embassy"))]
+                iel12: IEL12(()),
+                #[cfg(feature = "embassy")]
+                iel13: embassy_hal_internal::Peri::new_unchecked(IEL13(())),
+                #[cfg(not(feature = "embassy"))]
+                iel13: IEL13(()),
+                #[cfg(feature = "embassy")]
+                iel14: embassy_hal_internal::Peri::new_unchecked(IEL14(())),
+                #[cfg(not(feature = "embassy"))]
+                iel14: IEL14(()),
+                #[cfg(feature = "embassy")]
+                iel15: embassy_hal_internal::Peri::new_unchecked(IEL15(())),
+                #[cfg(not(feature = "embassy"))]
+                iel15: IEL15(()),
+                #[cfg(feature = "embassy")]
+                iel16: embassy_hal_internal::Peri::new_unchecked(IEL16(())),
+                #[cfg(not(feature = "embassy"))]
+                iel16: IEL16(()),
+                #[cfg(feature = "embassy")]
+                iel17: embassy_hal_internal::Peri::new_unchecked(IEL17(())),
+                #[cfg(not(feature = "embassy"))]
+                iel17: IEL17(()),
+                #[cfg(feature = "embassy")]
+                iel18: embassy_hal_internal::Peri::new_unchecked(IEL18(())),
+                #[cfg(not(feature = "embassy"))]
+                iel18: IEL18(()),
+                #[cfg(feature = "embassy")]
+                iel19: embassy_hal_internal::Peri::new_unchecked(IEL19(())),
+                #[cfg(not(feature = "embassy"))]
+                iel19: IEL19(()),
+                #[cfg(feature = "embassy")]
+                iel20: embassy_hal_internal::Peri::new_unchecked(IEL20(())),
+                #[cfg(not(feature = "embassy"))]
+                iel20: IEL20(()),
+                #[cfg(feature = "embassy")]
+                iel21: embassy_hal_internal::Peri::new_unchecked(IEL21(())),
+                #[cfg(not(feature = "embassy"))]
+                iel21: IEL21(()),
+                #[cfg(feature = "embassy")]
+                iel22: embassy_hal_internal::Peri::new_unchecked(IEL22(())),
+                #[cfg(not(feature = "embassy"))]
+                iel22: IEL22(()),
+                #[cfg(feature = "embassy")]
+                iel23: embassy_hal_internal::Peri::new_unchecked(IEL23(())),
+                #[cfg(not(feature = "embassy"))]
+                iel23: IEL23(()),
+                #[cfg(feature = "embassy")]
+                iel24: embassy_hal_internal::Peri::new_unchecked(IEL24(())),
+                #[cfg(not(feature = "embassy"))]
+                iel24: IEL24(()),
+                #[cfg(feature = "embassy")]
+                iel25: embassy_hal_internal::Peri::new_unchecked(IEL25(())),
+                #[cfg(not(feature = "embassy"))]
+                iel25: IEL25(()),
+                #[cfg(feature = "embassy")]
+                iel26: embassy_hal_internal::Peri::new_unchecked(IEL26(())),
+                #[cfg(not(feature = "embassy"))]
+                iel26: IEL26(()),
+                #[cfg(feature = "embassy")]
+                iel27: embassy_hal_internal::Peri::new_unchecked(IEL27(())),
+                #[cfg(not(feature = "embassy"))]
+                iel27: IEL27(()),
+                #[cfg(feature = "embassy")]
+                iel28: embassy_hal_internal::Peri::new_unchecked(IEL28(())),
+                #[cfg(not(feature = "embassy"))]
+                iel28: IEL28(()),
+                #[cfg(feature = "embassy")]
+                iel29: embassy_hal_internal::Peri::new_unchecked(IEL29(())),
+                #[cfg(not(feature = "embassy"))]
+                iel29: IEL29(()),
+                #[cfg(feature = "embassy")]
+                iel30: embassy_hal_internal::Peri::new_unchecked(IEL30(())),
+                #[cfg(not(feature = "embassy"))]
+                iel30: IEL30(()),
+                #[cfg(feature = "embassy")]
+                iel31: embassy_hal_internal::Peri::new_unchecked(IEL31(())),
+                #[cfg(not(feature = "embassy"))]
+                iel31: IEL31(()),
+                #[cfg(feature = "embassy")]
+                iel32: embassy_hal_internal::Peri::new_unchecked(IEL32(())),
+                #[cfg(not(feature = "embassy"))]
+                iel32: IEL32(()),
+                #[cfg(feature = "embassy")]
+                iel33: embassy_hal_internal::Peri::new_unchecked(IEL33(())),
+                #[cfg(not(feature = "embassy"))]
+                iel33: IEL33(()),
+                #[cfg(feature = "embassy")]
+                iel34: embassy_hal_internal::Peri::new_unchecked(IEL34(())),
+                #[cfg(not(feature = "embassy"))]
+                iel34: IEL34(()),
+                #[cfg(feature = "embassy")]
+                iel35: embassy_hal_internal::Peri::new_unchecked(IEL35(())),
+                #[cfg(not(feature = "embassy"))]
+                iel35: IEL35(()),
+                #[cfg(feature = "embassy")]
+                iel36: embassy_hal_internal::Peri::new_unchecked(IEL36(())),
+                #[cfg(not(feature = "embassy"))]
+                iel36: IEL36(()),
+                #[cfg(feature = "embassy")]
+                iel37: embassy_hal_internal::Peri::new_unchecked(IEL37(())),
+                #[cfg(not(feature = "embassy"))]
+                iel37: IEL37(()),
+                #[cfg(feature = "embassy")]
+                iel38: embassy_hal_internal::Peri::new_unchecked(IEL38(())),
+                #[cfg(not(feature = "embassy"))]
+                iel38: IEL38(()),
+                #[cfg(feature = "embassy")]
+                iel39: embassy_hal_internal::Peri::new_unchecked(IEL39(())),
+                #[cfg(not(feature = "embassy"))]
+                iel39: IEL39(()),
+                #[cfg(feature = "embassy")]
+                iel40: embassy_hal_internal::Peri::new_unchecked(IEL40(())),
+                #[cfg(not(feature = "embassy"))]
+                iel40: IEL40(()),
+                #[cfg(feature = "embassy")]
+                iel41: embassy_hal_internal::Peri::new_unchecked(IEL41(())),
+                #[cfg(not(feature = "embassy"))]
+                iel41: IEL41(()),
+                #[cfg(feature = "embassy")]
+                iel42: embassy_hal_internal::Peri::new_unchecked(IEL42(())),
+                #[cfg(not(feature = "embassy"))]
+                iel42: IEL42(()),
+                #[cfg(feature = "embassy")]
+                iel43: embassy_hal_internal::Peri::new_unchecked(IEL43(())),
+                #[cfg(not(feature = "embassy"))]
+                iel43: IEL43(()),
+                #[cfg(feature = "embassy")]
+                iel44: embassy_hal_internal::Peri::new_unchecked(IEL44(())),
+                #[cfg(not(feature = "embassy"))]
+                iel44: IEL44(()),
+                #[cfg(feature = "embassy")]
+                iel45: embassy_hal_internal::Peri::new_unchecked(IEL45(())),
+                #[cfg(not(feature = "embassy"))]
+                iel45: IEL45(()),
+                #[cfg(feature = "embassy")]
+                iel46: embassy_hal_internal::Peri::new_unchecked(IEL46(())),
+                #[cfg(not(feature = "embassy"))]
+                iel46: IEL46(()),
+                #[cfg(feature = "embassy")]
+                iel47: embassy_hal_internal::Peri::new_unchecked(IEL47(())),
+                #[cfg(not(feature = "embassy"))]
+                iel47: IEL47(()),
+                #[cfg(feature = "embassy")]
+                iel48: embassy_hal_internal::Peri::new_unchecked(IEL48(())),
+                #[cfg(not(feature = "embassy"))]
+                iel48: IEL48(()),
+                #[cfg(feature = "embassy")]
+                iel49: embassy_hal_internal::Peri::new_unchecked(IEL49(())),
+                #[cfg(not(feature = "embassy"))]
+                iel49: IEL49(()),
+                #[cfg(feature = "embassy")]
+                iel50: embassy_hal_internal::Peri::new_unchecked(IEL50(())),
+                #[cfg(not(feature = "embassy"))]
+                iel50: IEL50(()),
+                #[cfg(feature = "embassy")]
+                iel51: embassy_hal_internal::Peri::new_unchecked(IEL51(())),
+                #[cfg(not(feature = "embassy"))]
+                iel51: IEL51(()),
+                #[cfg(feature = "embassy")]
+                iel52: embassy_hal_internal::Peri::new_unchecked(IEL52(())),
+                #[cfg(not(feature = "embassy"))]
+                iel52: IEL52(()),
+                #[cfg(feature = "embassy")]
+                iel53: embassy_hal_internal::Peri::new_unchecked(IEL53(())),
+                #[cfg(not(feature = "embassy"))]
+                iel53: IEL53(()),
+                #[cfg(feature = "embassy")]
+                iel54: embassy_hal_internal::Peri::new_unchecked(IEL54(())),
+                #[cfg(not(feature = "embassy"))]
+                iel54: IEL54(()),
+                #[cfg(feature = "embassy")]
+                iel55: embassy_hal_internal::Peri::new_unchecked(IEL55(())),
+                #[cfg(not(feature = "embassy"))]
+                iel55: IEL55(()),
+                #[cfg(feature = "embassy")]
+                iel56: embassy_hal_internal::Peri::new_unchecked(IEL56(())),
+                #[cfg(not(feature = "embassy"))]
+                iel56: IEL56(()),
+                #[cfg(feature = "embassy")]
+                iel57: embassy_hal_internal::Peri::new_unchecked(IEL57(())),
+                #[cfg(not(feature = "embassy"))]
+                iel57: IEL57(()),
+                #[cfg(feature = "embassy")]
+                iel58: embassy_hal_internal::Peri::new_unchecked(IEL58(())),
+                #[cfg(not(feature = "embassy"))]
+                iel58: IEL58(()),
+                #[cfg(feature = "embassy")]
+                iel59: embassy_hal_internal::Peri::new_unchecked(IEL59(())),
+                #[cfg(not(feature = "embassy"))]
+                iel59: IEL59(()),
+                #[cfg(feature = "embassy")]
+                iel60: embassy_hal_internal::Peri::new_unchecked(IEL60(())),
+                #[cfg(not(feature = "embassy"))]
+                iel60: IEL60(()),
+                #[cfg(feature = "embassy")]
+                iel61: embassy_hal_internal::Peri::new_unchecked(IEL61(())),
+                #[cfg(not(feature = "embassy"))]
+                iel61: IEL61(()),
+                #[cfg(feature = "embassy")]
+                iel62: embassy_hal_internal::Peri::new_unchecked(IEL62(())),
+                #[cfg(not(feature = "embassy"))]
+                iel62: IEL62(()),
+                #[cfg(feature = "embassy")]
+                iel63: embassy_hal_internal::Peri::new_unchecked(IEL63(())),
+                #[cfg(not(feature = "embassy"))]
+                iel63: IEL63(()),
+                #[cfg(feature = "embassy")]
+                iel64: embassy_hal_internal::Peri::new_unchecked(IEL64(())),
+                #[cfg(not(feature = "embassy"))]
+                iel64: IEL64(()),
+                #[cfg(feature = "embassy")]
+                iel65: embassy_hal_internal::Peri::new_unchecked(IEL65(())),
+                #[cfg(not(feature = "embassy"))]
+                iel65: IEL65(()),
+                #[cfg(feature = "embassy")]
+                iel66: embassy_hal_internal::Peri::new_unchecked(IEL66(())),
+                #[cfg(not(feature = "embassy"))]
+                iel66: IEL66(()),
+                #[cfg(feature = "embassy")]
+                iel67: embassy_hal_internal::Peri::new_unchecked(IEL67(())),
+                #[cfg(not(feature = "embassy"))]
+                iel67: IEL67(()),
+                #[cfg(feature = "embassy")]
+                iel68: embassy_hal_internal::Peri::new_unchecked(IEL68(())),
+                #[cfg(not(feature = "embassy"))]
+                iel68: IEL68(()),
+                #[cfg(feature = "embassy")]
+                iel69: embassy_hal_internal::Peri::new_unchecked(IEL69(())),
+                #[cfg(not(feature = "embassy"))]
+                iel69: IEL69(()),
+                #[cfg(feature = "embassy")]
+                iel70: embassy_hal_internal::Peri::new_unchecked(IEL70(())),
+                #[cfg(not(feature = "embassy"))]
+                iel70: IEL70(()),
+                #[cfg(feature = "embassy")]
+                iel71: embassy_hal_internal::Peri::new_unchecked(IEL71(())),
+                #[cfg(not(feature = "embassy"))]
+                iel71: IEL71(()),
+                #[cfg(feature = "embassy")]
+                iel72: embassy_hal_internal::Peri::new_unchecked(IEL72(())),
+                #[cfg(not(feature = "embassy"))]
+                iel72: IEL72(()),
+                #[cfg(feature = "embassy")]
+                iel73: embassy_hal_internal::Peri::new_unchecked(IEL73(())),
+                #[cfg(not(feature = "embassy"))]
+                iel73: IEL73(()),
+                #[cfg(feature = "embassy")]
+                iel74: embassy_hal_internal::Peri::new_unchecked(IEL74(())),
+                #[cfg(not(feature = "embassy"))]
+                iel74: IEL74(()),
+                #[cfg(feature = "embassy")]
+                iel75: embassy_hal_internal::Peri::new_unchecked(IEL75(())),
+                #[cfg(not(feature = "embassy"))]
+                iel75: IEL75(()),
+                #[cfg(feature = "embassy")]
+                iel76: embassy_hal_internal::Peri::new_unchecked(IEL76(())),
+                #[cfg(not(feature = "embassy"))]
+                iel76: IEL76(()),
+                #[cfg(feature = "embassy")]
+                iel77: embassy_hal_internal::Peri::new_unchecked(IEL77(())),
+                #[cfg(not(feature = "embassy"))]
+                iel77: IEL77(()),
+                #[cfg(feature = "embassy")]
+                iel78: embassy_hal_internal::Peri::new_unchecked(IEL78(())),
+                #[cfg(not(feature = "embassy"))]
+                iel78: IEL78(()),
+                #[cfg(feature = "embassy")]
+                iel79: embassy_hal_internal::Peri::new_unchecked(IEL79(())),
+                #[cfg(not(feature = "embassy"))]
+                iel79: IEL79(()),
+                #[cfg(feature = "embassy")]
+                iel80: embassy_hal_internal::Peri::new_unchecked(IEL80(())),
+                #[cfg(not(feature = "embassy"))]
+                iel80: IEL80(()),
+                #[cfg(feature = "embassy")]
+                iel81: embassy_hal_internal::Peri::new_unchecked(IEL81(())),
+                #[cfg(not(feature = "embassy"))]
+                iel81: IEL81(()),
+                #[cfg(feature = "embassy")]
+                iel82: embassy_hal_internal::Peri::new_unchecked(IEL82(())),
+                #[cfg(not(feature = "embassy"))]
+                iel82: IEL82(()),
+                #[cfg(feature = "embassy")]
+                iel83: embassy_hal_internal::Peri::new_unchecked(IEL83(())),
+                #[cfg(not(feature = "embassy"))]
+                iel83: IEL83(()),
+                #[cfg(feature = "embassy")]
+                iel84: embassy_hal_internal::Peri::new_unchecked(IEL84(())),
+                #[cfg(not(feature = "embassy"))]
+                iel84: IEL84(()),
+                #[cfg(feature = "embassy")]
+                iel85: embassy_hal_internal::Peri::new_unchecked(IEL85(())),
+                #[cfg(not(feature = "embassy"))]
+                iel85: IEL85(()),
+                #[cfg(feature = "embassy")]
+                iel86: embassy_hal_internal::Peri::new_unchecked(IEL86(())),
+                #[cfg(not(feature = "embassy"))]
+                iel86: IEL86(()),
+                #[cfg(feature = "embassy")]
+                iel87: embassy_hal_internal::Peri::new_unchecked(IEL87(())),
+                #[cfg(not(feature = "embassy"))]
+                iel87: IEL87(()),
+                #[cfg(feature = "embassy")]
+                iel88: embassy_hal_internal::Peri::new_unchecked(IEL88(())),
+                #[cfg(not(feature = "embassy"))]
+                iel88: IEL88(()),
+                #[cfg(feature = "embassy")]
+                iel89: embassy_hal_internal::Peri::new_unchecked(IEL89(())),
+                #[cfg(not(feature = "embassy"))]
+                iel89: IEL89(()),
+                #[cfg(feature = "embassy")]
+                iel90: embassy_hal_internal::Peri::new_unchecked(IEL90(())),
+                #[cfg(not(feature = "embassy"))]
+                iel90: IEL90(()),
+                #[cfg(feature = "embassy")]
+                iel91: embassy_hal_internal::Peri::new_unchecked(IEL91(())),
+                #[cfg(not(feature = "embassy"))]
+                iel91: IEL91(()),
+                #[cfg(feature = "embassy")]
+                iel92: embassy_hal_internal::Peri::new_unchecked(IEL92(())),
+                #[cfg(not(feature = "embassy"))]
+                iel92: IEL92(()),
+                #[cfg(feature = "embassy")]
+                iel93: embassy_hal_internal::Peri::new_unchecked(IEL93(())),
+                #[cfg(not(feature = "embassy"))]
+                iel93: IEL93(()),
+                #[cfg(feature = "embassy")]
+                iel94: embassy_hal_internal::Peri::new_unchecked(IEL94(())),
+                #[cfg(not(feature = "embassy"))]
+                iel94: IEL94(()),
+                #[cfg(feature = "embassy")]
+                iel95: embassy_hal_internal::Peri::new_unchecked(IEL95(())),
+                #[cfg(not(feature = "embassy"))]
+                iel95: IEL95(()),
+                #[cfg(feature = "embassy")]
+                p101: embassy_hal_internal::Peri::new_unchecked(P101(())),
+                #[cfg(not(feature = "embassy"))]
+                p101: P101(()),
+                #[cfg(any(package_lqfp176))]
+                #[cfg(feature = "embassy")]
+                p105: embassy_hal_internal::Peri::new_unchecked(P105(())),
+                #[cfg(any(package_lqfp176))]
+                #[cfg(not(feature = "embassy"))]
+                p105: P105(()),
+                #[cfg(feature = "embassy")]
+                p400: embassy_hal_internal::Peri::new_unchecked(P400(())),
+                #[cfg(not(feature = "embassy"))]
+                p400: P400(()),
+                #[cfg(feature = "embassy")]
+                p401: embassy_hal_internal::Peri::new_unchecked(P401(())),
+                #[cfg(not(feature = "embassy"))]
+                p401: P401(()),
+                #[cfg(feature = "embassy")]
+                pa00: embassy_hal_internal::Peri::new_unchecked(PA00(())),
+                #[cfg(not(feature = "embassy"))]
+                pa00: PA00(()),
+            }
+        }
+    }
+    #[derive(Copy, Clone)]
+    pub struct IEL0(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL0 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL1(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL1 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL2(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL2 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL3(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL3 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL4(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL4 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL5(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL5 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL6(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL6 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL7(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL7 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL8(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL8 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL9(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL9 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL10(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL10 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL11(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL11 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL12(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL12 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL13(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL13 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL14(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL14 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL15(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL15 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL16(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL16 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL17(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL17 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL18(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL18 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL19(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL19 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL20(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL20 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL21(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL21 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL22(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL22 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL23(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL23 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL24(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL24 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL25(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL25 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL26(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL26 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL27(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL27 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL28(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL28 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL29(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL29 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL30(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL30 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL31(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL31 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL32(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL32 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL33(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL33 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL34(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL34 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL35(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL35 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL36(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL36 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL37(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL37 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL38(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL38 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL39(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL39 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL40(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL40 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL41(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL41 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL42(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL42 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL43(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL43 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL44(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL44 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL45(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL45 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL46(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL46 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL47(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL47 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL48(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL48 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL49(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL49 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL50(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL50 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL51(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL51 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL52(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL52 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL53(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL53 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL54(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL54 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL55(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL55 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL56(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL56 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL57(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL57 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL58(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL58 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL59(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL59 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL60(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL60 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL61(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL61 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL62(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL62 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL63(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL63 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL64(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL64 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL65(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL65 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL66(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL66 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL67(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL67 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL68(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL68 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL69(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL69 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL70(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL70 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL71(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL71 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL72(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL72 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL73(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL73 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL74(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL74 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL75(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL75 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL76(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL76 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL77(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL77 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL78(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL78 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL79(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL79 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL80(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL80 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL81(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL81 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL82(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL82 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL83(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL83 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL84(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL84 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL85(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL85 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL86(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL86 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL87(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL87 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL88(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL88 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL89(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL89 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL90(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL90 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL91(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL91 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL92(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL92 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL93(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL93 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL94(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL94 {}
+    #[derive(Copy, Clone)]
+    pub struct IEL95(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for IEL95 {}
+    #[derive(Copy, Clone)]
+    pub struct P101(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P101 {}
+    impl P101 {
+        pub const PORT: u8 = 1;
+        pub const NUMBER: u8 = 1;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40080844;
+        /// Register block of the pin's port.
+        pub const PORT_REGS: crate::_peripherals::port_v1::Port = PORT1::REGS;
+    }
+    #[cfg(any(package_lqfp176))]
+    #[derive(Copy, Clone)]
+    pub struct P105(());
+    #[cfg(any(package_lqfp176))]
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P105 {}
+    #[cfg(any(package_lqfp176))]
+    impl P105 {
+        pub const PORT: u8 = 1;
+        pub const NUMBER: u8 = 5;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40080854;
+        /// Register block of the pin's port.
+        pub const PORT_REGS: crate::_peripherals::port_v1::Port = PORT1::REGS;
+    }
+    #[derive(Copy, Clone)]
+    pub struct P400(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P400 {}
+    impl P400 {
+        pub const PORT: u8 = 4;
+        pub const NUMBER: u8 = 0;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40080900;
+    }
+    #[derive(Copy, Clone)]
+    pub struct P401(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for P401 {}
+    impl P401 {
+        pub const PORT: u8 = 4;
+        pub const NUMBER: u8 = 1;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40080904;
+    }
+    #[derive(Copy, Clone)]
+    pub struct PA00(());
+    #[cfg(feature = "embassy")]
+    impl embassy_hal_internal::PeripheralType for PA00 {}
+    impl PA00 {
+        pub const PORT: u8 = 10;
+        pub const NUMBER: u8 = 0;
+        /// Address of the pin's PmnPFS register.
+        pub const PFS_ADDRESS: usize = 0x40080a80;
+    }
+}
+#[macro_export]
+macro_rules! foreach_interrupt {
+    ($m:path, $($args:tt)*) => {
+        $m ! { $($args)* { IEL0 = 0, IEL1 = 1, IEL2 = 2, IEL3 = 3, IEL4 = 4, IEL5 = 5,
+        IEL6 = 6, IEL7 = 7, IEL8 = 8, IEL9 = 9, IEL10 = 10, IEL11 = 11, IEL12 = 12, IEL13
+        = 13, IEL14 = 14, IEL15 = 15, IEL16 = 16, IEL17 = 17, IEL18 = 18, IEL19 = 19,
+        IEL20 = 20, IEL21 = 21, IEL22 = 22, IEL23 = 23, IEL24 = 24, IEL25 = 25, IEL26 =
+        26, IEL27 = 27, IEL28 = 28, IEL29 = 29, IEL30 = 30, IEL31 = 31, IEL32 = 32, IEL33
+        = 33, IEL34 = 34, IEL35 = 35, IEL36 = 36, IEL37 = 37, IEL38 = 38, IEL39 = 39,
+        IEL40 = 40, IEL41 = 41, IEL42 = 42, IEL43 = 43, IEL44 = 44, IEL45 = 45, IEL46 =
+        46, IEL47 = 47, IEL48 = 48, IEL49 = 49, IEL50 = 50, IEL51 = 51, IEL52 = 52, IEL53
+        = 53, IEL54 = 54, IEL55 = 55, IEL56 = 56, IEL57 = 57, IEL58 = 58, IEL59 = 59,
+        IEL60 = 60, IEL61 = 61, IEL62 = 62, IEL63 = 63, IEL64 = 64, IEL65 = 65, IEL66 =
+        66, IEL67 = 67, IEL68 = 68, IEL69 = 69, IEL70 = 70, IEL71 = 71, IEL72 = 72, IEL73
+        = 73, IEL74 = 74, IEL75 = 75, IEL76 = 76, IEL77 = 77, IEL78 = 78, IEL79 = 79,
+        IEL80 = 80, IEL81 = 81, IEL82 = 82, IEL83 = 83, IEL84 = 84, IEL85 = 85, IEL86 =
+        86, IEL87 = 87, IEL88 = 88, IEL89 = 89, IEL90 = 90, IEL91 = 91, IEL92 = 92, IEL93
+        = 93, IEL94 = 94, IEL95 = 95, } }
+    };
+    ($($m:tt)*) => {
+        $($m)* ! { IEL0 = 0, IEL1 = 1, IEL2 = 2, IEL3 = 3, IEL4 = 4, IEL5 = 5, IEL6 = 6,
+        IEL7 = 7, IEL8 = 8, IEL9 = 9, IEL10 = 10, IEL11 = 11, IEL12 = 12, IEL13 = 13,
+        IEL14 = 14, IEL15 = 15, IEL16 = 16, IEL17 = 17, IEL18 = 18, IEL19 = 19, IEL20 =
+        20, IEL21 = 21, IEL22 = 22, IEL23 = 23, IEL24 = 24, IEL25 = 25, IEL26 = 26, IEL27
+        = 27, IEL28 = 28, IEL29 = 29, IEL30 = 30, IEL31 = 31, IEL32 = 32, IEL33 = 33,
+        IEL34 = 34, IEL35 = 35, IEL36 = 36, IEL37 = 37, IEL38 = 38, IEL39 = 39, IEL40 =
+        40, IEL41 = 41, IEL42 = 42, IEL43 = 43, IEL44 = 44, IEL45 = 45, IEL46 = 46, IEL47
+        = 47, IEL48 = 48, IEL49 = 49, IEL50 = 50, IEL51 = 51, IEL52 = 52, IEL53 = 53,
+        IEL54 = 54, IEL55 = 55, IEL56 = 56, IEL57 = 57, IEL58 = 58, IEL59 = 59, IEL60 =
+        60, IEL61 = 61, IEL62 = 62, IEL63 = 63, IEL64 = 64, IEL65 = 65, IEL66 = 66, IEL67
+        = 67, IEL68 = 68, IEL69 = 69, IEL70 = 70, IEL71 = 71, IEL72 = 72, IEL73 = 73,
+        IEL74 = 74, IEL75 = 75, IEL76 = 76, IEL77 = 77, IEL78 = 78, IEL79 = 79, IEL80 =
+        80, IEL81 = 81, IEL82 = 82, IEL83 = 83, IEL84 = 84, IEL85 = 85, IEL86 = 86, IEL87
+        = 87, IEL88 = 88, IEL89 = 89, IEL90 = 90, IEL91 = 91, IEL92 = 92, IEL93 = 93,
+        IEL94 = 94, IEL95 = 95, }
+    };
+}
+#[macro_export]
+macro_rules! foreach_event {
+    ($m:path, $($args:tt)*) => {
+        $m ! { $($args)* { (Dmac0Int, 32, ()), (Gpt0CaptureCompareA, 117, ()),
+        (Gpt0CounterOverflow, 123, ()), (IcuIrq0, 1, ()), (IcuIrq1, 2, ()), (Sci0Eri,
+        411, ()), (Sci0Rxi, 408, ()), (Sci0Tei, 410, ()), (Sci0Txi, 409, ()), } }
+    };
+    ($($m:tt)*) => {
+        $($m)* ! { (Dmac0Int, 32, ()), (Gpt0CaptureCompareA, 117, ()),
+        (Gpt0CounterOverflow, 123, ()), (IcuIrq0, 1, ()), (IcuIrq1, 2, ()), (Sci0Eri,
+        411, ()), (Sci0Rxi, 408, ()), (Sci0Tei, 410, ()), (Sci0Txi, 409, ()), }
+    };
+}
+#[macro_export]
+macro_rules! foreach_dma_trigger {
+    ($m:path) => {
+        $m ! { (dtc, Dmac0Int, 32), (dtc, Gpt0CaptureCompareA, 117), (dmac,
+        Gpt0CaptureCompareA, 117), (dtc, Gpt0CounterOverflow, 123), (dmac,
+        Gpt0CounterOverflow, 123), (dtc, IcuIrq0, 1), (dmac, IcuIrq0, 1), (dtc, IcuIrq1,
+        2), (dmac, IcuIrq1, 2), (dtc, Sci0Rxi, 408), (dmac, Sci0Rxi, 408), (dtc, Sci0Txi,
+        409), (dmac, Sci0Txi, 409), }
+    };
+}
+#[macro_export]
+macro_rules! foreach_peripheral {
+    ($m:path) => {
+        $m ! { (SYSC, sysc, ra6m5, 0, None, None), (MSTP, mstp, v2, 1, None, None), (ICU,
+        icu, ra6m5, 2, None, None), (ELC, elc, v2, 3, None, None), (PFS, pfs, v1, 4,
+        None, None), (PORT0, port, v1, 5, Some(PORT), Some(0)), (PORT1, port, v1, 6,
+        Some(PORT), Some(1)), (GPT0, timer, v1, 7, Some(GPT), Some(0)), (GPT1, timer, v1,
+        8, Some(GPT), Some(1)), (GPT4, timer, v1, 9, Some(GPT), Some(4)), (SCI0, sci, v1,
+        10, Some(SCI), Some(0)), }
+    };
+}
+#[cfg(package_bga176)]
+#[macro_export]
+macro_rules! foreach_pin {
+    ($m:path) => {
+        $m ! { (P101, 1, 1), (P400, 4, 0), (P401, 4, 1), (PA00, 10, 0), }
+    };
+}
+#[cfg(package_bga176)]
+#[macro_export]
+macro_rules! foreach_pin_function {
+    ($m:path) => {
+        $m ! { (P101, SCI0, MOSI0, 4), (P101, SCI0, SDA0, 4), (P101, SCI0, TXD0, 4), }
+    };
+}
+#[cfg(package_lqfp176)]
+#[macro_export]
+macro_rules! foreach_pin {
+    ($m:path) => {
+        $m ! { (P101, 1, 1), (P105, 1, 5), (P400, 4, 0), (P401, 4, 1), (PA00, 10, 0), }
+    };
+}
+#[cfg(package_lqfp176)]
+#[macro_export]
+macro_rules! foreach_pin_function {
+    ($m:path) => {
+        $m ! { (P101, SCI0, MOSI0, 4), (P101, SCI0, SDA0, 4), (P101, SCI0, TXD0, 4),
+        (P105, GPT1, GTIOC1A, 3), }
+    };
+}
+#[cfg(not(any(package_bga176, package_lqfp176)))]
+#[macro_export]
+macro_rules! foreach_pin {
+    ($m:path) => {
+        $m ! { (P101, 1, 1), (P400, 4, 0), (P401, 4, 1), (PA00, 10, 0), }
+    };
+}
+#[cfg(not(any(package_bga176, package_lqfp176)))]
+#[macro_export]
+macro_rules! foreach_pin_function {
+    ($m:path) => {
+        $m ! { (P101, SCI0, MOSI0, 4), (P101, SCI0, SDA0, 4), (P101, SCI0, TXD0, 4), }
+    };
+}
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Event {
+    /// DMAC0 transfer end
+    Dmac0Int = 32,
+    /// Capture/Compare match A
+    Gpt0CaptureCompareA = 117,
+    /// Overflow
+    Gpt0CounterOverflow = 123,
+    /// External pin interrupt 0
+    IcuIrq0 = 1,
+    /// External pin interrupt 1
+    IcuIrq1 = 2,
+    /// Receive error
+    Sci0Eri = 411,
+    /// Receive data full
+    Sci0Rxi = 408,
+    /// Transmit end
+    Sci0Tei = 410,
+    /// Transmit data empty
+    Sci0Txi = 409,
+}
+impl Event {
+    const ALL_SLOTS: &'static [u8] = &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+        23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42,
+        43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+        63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82,
+        83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95,
+    ];
+    /// The event's number, as written to `IELSR.IELS`.
+    #[inline(always)]
+    pub const fn number(self) -> u16 {
+        self as u16
+    }
+    /// The IEL slots the event can be linked in. Grouped events can only use the slots of
+    /// their groups; every other event can use any slot.
+    pub const fn allowed_slots(self) -> &'static [u8] {
+        #[allow(unreachable_patterns)]
+        match self {
+            _ => Self::ALL_SLOTS,
+        }
+    }
+    /// Whether the event can be linked in IEL slot `slot`, for use in const assertions.
+    pub const fn allows_slot(self, slot: usize) -> bool {
+        let slots = self.allowed_slots();
+        let mut i = 0;
+        while i < slots.len() {
+            if slots[i] as usize == slot {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+/// Event Link Controller: any `Event` can be linked to any `Destination` by writing it to
+/// `ELSR[destination]`.
+pub mod elc {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(u16)]
+    pub enum Event {
+        /// External pin interrupt 0
+        IcuIrq0 = 1,
+        /// External pin interrupt 1
+        IcuIrq1 = 2,
+        /// DMAC0 transfer end
+        Dmac0Int = 32,
+        /// Capture/Compare match A
+        Gpt0CaptureCompareA = 117,
+        /// Overflow
+        Gpt0CounterOverflow = 123,
+        /// Receive data full
+        Sci0Rxi = 408,
+        /// Transmit data empty
+        Sci0Txi = 409,
+        /// Transmit end
+        Sci0Tei = 410,
+        /// Receive error
+        Sci0Eri = 411,
+    }
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(u8)]
+    pub enum Destination {
+        GptA = 0,
+        GptB = 1,
+        Adc0 = 8,
+        Adc0B = 9,
+        Dac0 = 12,
+        Ioport1 = 14,
+        Ctsu = 18,
+    }
+}
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Interrupt {
+    IEL0 = 0,
+    IEL1 = 1,
+    IEL2 = 2,
+    IEL3 = 3,
+    IEL4 = 4,
+    IEL5 = 5,
+    IEL6 = 6,
+    IEL7 = 7,
+    IEL8 = 8,
+    IEL9 = 9,
+    IEL10 = 10,
+    IEL11 = 11,
+    IEL12 = 12,
+    IEL13 = 13,
+    IEL14 = 14,
+    IEL15 = 15,
+    IEL16 = 16,
+    IEL17 = 17,
+    IEL18 = 18,
+    IEL19 = 19,
+    IEL20 = 20,
+    IEL21 = 21,
+    IEL22 = 22,
+    IEL23 = 23,
+    IEL24 = 24,
+    IEL25 = 25,
+    IEL26 = 26,
+    IEL27 = 27,
+    IEL28 = 28,
+    IEL29 = 29,
+    IEL30 = 30,
+    IEL31 = 31,
+    IEL32 = 32,
+    IEL33 = 33,
+    IEL34 = 34,
+    IEL35 = 35,
+    IEL36 = 36,
+    IEL37 = 37,
+    IEL38 = 38,
+    IEL39 = 39,
+    IEL40 = 40,
+    IEL41 = 41,
+    IEL42 = 42,
+    IEL43 = 43,
+    IEL44 = 44,
+    IEL45 = 45,
+    IEL46 = 46,
+    IEL47 = 47,
+    IEL48 = 48,
+    IEL49 = 49,
+    IEL50 = 50,
+    IEL51 = 51,
+    IEL52 = 52,
+    IEL53 = 53,
+    IEL54 = 54,
+    IEL55 = 55,
+    IEL56 = 56,
+    IEL57 = 57,
+    IEL58 = 58,
+    IEL59 = 59,
+    IEL60 = 60,
+    IEL61 = 61,
+    IEL62 = 62,
+    IEL63 = 63,
+    IEL64 = 64,
+    IEL65 = 65,
+    IEL66 = 66,
+    IEL67 = 67,
+    IEL68 = 68,
+    IEL69 = 69,
+    IEL70 = 70,
+    IEL71 = 71,
+    IEL72 = 72,
+    IEL73 = 73,
+    IEL74 = 74,
+    IEL75 = 75,
+    IEL76 = 76,
+    IEL77 = 77,
+    IEL78 = 78,
+    IEL79 = 79,
+    IEL80 = 80,
+    IEL81 = 81,
+    IEL82 = 82,
+    IEL83 = 83,
+    IEL84 = 84,
+    IEL85 = 85,
+    IEL86 = 86,
+    IEL87 = 87,
+    IEL88 = 88,
+    IEL89 = 89,
+    IEL90 = 90,
+    IEL91 = 91,
+    IEL92 = 92,
+    IEL93 = 93,
+    IEL94 = 94,
+    IEL95 = 95,
+}
+unsafe impl cortex_m::interrupt::InterruptNumber for Interrupt {
+    #[inline(always)]
+    fn number(self) -> u16 {
+        self as u16
+    }
+}
+impl Interrupt {
+    /// Whether `event` can be linked in this IEL slot.
+    #[inline(always)]
+    pub const fn can_bind(self, event: Event) -> bool {
+        event.allows_slot(self as usize)
+    }
+}
+impl Interrupt {
+    /// Links `event` to this IEL slot by writing its number to `IELSR`, which also clears
+    /// the slot's IR flag and DTC enable.
+    ///
+    /// Panics if the event can't be linked in this slot; [`bind_events!`] checks that at
+    /// compile time instead.
+    #[inline(always)]
+    pub fn bind(self, event: Event) {
+        assert!(self.can_bind(event));
+        peripherals::ICU::REGS.ielsr(self as usize).write_value(event.number() as u32)
+    }
+    /// Unlinks the IEL slot from its event.
+    #[inline(always)]
+    pub fn unbind(self) {
+        peripherals::ICU::REGS.ielsr(self as usize).write_value(0)
+    }
+}
+/// Links events to IEL slots, checking at compile time that each event can be linked in its
+/// slot and that no slot is used twice:
+///
+/// ```ignore
+/// bind_events!(IEL0 => IcuIrq0, IEL4 => Sci0Rxi);
+/// ```
+#[macro_export]
+macro_rules! bind_events {
+    ($($iel:ident => $event:ident),* $(,)?) => {
+        { const _ : () = { let slots = [$($crate::Interrupt:: $iel as usize),*]; let mut
+        i = 0; while i < slots.len() { let mut j = i + 1; while j < slots.len() {
+        assert!(slots[i] != slots[j], "an IEL slot is bound twice"); j += 1; } i += 1; }
+        }; $(const _ : () = assert!($crate::Interrupt:: $iel .can_bind($crate::Event::
+        $event), concat!(stringify!($event), " can't be linked in ", stringify!($iel)),);
+        $crate::Interrupt:: $iel .bind($crate::Event:: $event);)* }
+    };
+}
+pub mod option_setting {
+    /// `OFS0` in `OPTION_SETTING`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Ofs0(pub u32);
+    impl Ofs0 {
+        pub const ADDRESS: usize = 0x0100a100;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting.ofs0";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+        /// IWDT Start Mode Select.
+        #[inline(always)]
+        pub const fn iwdtstrt(self) -> bool {
+            (self.0 >> 1) & 0x00000001 != 0
+        }
+        /// IWDT Start Mode Select.
+        #[inline(always)]
+        pub const fn with_iwdtstrt(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 1) | (value as u32) << 1)
+        }
+        /// IWDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn iwdttops(self) -> u8 {
+            ((self.0 >> 2) & 0x00000003) as u8
+        }
+        /// IWDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn with_iwdttops(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 2) | ((value as u32) & 0x00000003) << 2)
+        }
+        /// IWDT-Dedicated Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn iwdtcks(self) -> u8 {
+            ((self.0 >> 4) & 0x0000000f) as u8
+        }
+        /// IWDT-Dedicated Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn with_iwdtcks(self, value: u8) -> Self {
+            Self(self.0 & !(0x0000000f << 4) | ((value as u32) & 0x0000000f) << 4)
+        }
+        /// IWDT Window End Position Select.
+        #[inline(always)]
+        pub const fn iwdtrpes(self) -> u8 {
+            ((self.0 >> 8) & 0x00000003) as u8
+        }
+        /// IWDT Window End Position Select.
+        #[inline(always)]
+        pub const fn with_iwdtrpes(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 8) | ((value as u32) & 0x00000003) << 8)
+        }
+        /// IWDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn iwdtrpss(self) -> u8 {
+            ((self.0 >> 10) & 0x00000003) as u8
+        }
+        /// IWDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn with_iwdtrpss(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 10) | ((value as u32) & 0x00000003) << 10)
+        }
+        /// IWDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn iwdtrstirqs(self) -> bool {
+            (self.0 >> 12) & 0x00000001 != 0
+        }
+        /// IWDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn with_iwdtrstirqs(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 12) | (value as u32) << 12)
+        }
+        /// IWDT Stop Control.
+        #[inline(always)]
+        pub const fn iwdtstpctl(self) -> bool {
+            (self.0 >> 14) & 0x00000001 != 0
+        }
+        /// IWDT Stop Control.
+        #[inline(always)]
+        pub const fn with_iwdtstpctl(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 14) | (value as u32) << 14)
+        }
+        /// WDT Start Mode Select.
+        #[inline(always)]
+        pub const fn wdtstrt(self) -> bool {
+            (self.0 >> 17) & 0x00000001 != 0
+        }
+        /// WDT Start Mode Select.
+        #[inline(always)]
+        pub const fn with_wdtstrt(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 17) | (value as u32) << 17)
+        }
+        /// WDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn wdttops(self) -> u8 {
+            ((self.0 >> 18) & 0x00000003) as u8
+        }
+        /// WDT Timeout Period Select.
+        #[inline(always)]
+        pub const fn with_wdttops(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 18) | ((value as u32) & 0x00000003) << 18)
+        }
+        /// WDT Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn wdtcks(self) -> u8 {
+            ((self.0 >> 20) & 0x0000000f) as u8
+        }
+        /// WDT Clock Frequency Division Ratio Select.
+        #[inline(always)]
+        pub const fn with_wdtcks(self, value: u8) -> Self {
+            Self(self.0 & !(0x0000000f << 20) | ((value as u32) & 0x0000000f) << 20)
+        }
+        /// WDT Window End Position Select.
+        #[inline(always)]
+        pub const fn wdtrpes(self) -> u8 {
+            ((self.0 >> 24) & 0x00000003) as u8
+        }
+        /// WDT Window End Position Select.
+        #[inline(always)]
+        pub const fn with_wdtrpes(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 24) | ((value as u32) & 0x00000003) << 24)
+        }
+        /// WDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn wdtrpss(self) -> u8 {
+            ((self.0 >> 26) & 0x00000003) as u8
+        }
+        /// WDT Window Start Position Select.
+        #[inline(always)]
+        pub const fn with_wdtrpss(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000003 << 26) | ((value as u32) & 0x00000003) << 26)
+        }
+        /// WDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn wdtrstirqs(self) -> bool {
+            (self.0 >> 28) & 0x00000001 != 0
+        }
+        /// WDT Reset Interrupt Request Select.
+        #[inline(always)]
+        pub const fn with_wdtrstirqs(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 28) | (value as u32) << 28)
+        }
+        /// WDT Stop Control.
+        #[inline(always)]
+        pub const fn wdtstpctl(self) -> bool {
+            (self.0 >> 30) & 0x00000001 != 0
+        }
+        /// WDT Stop Control.
+        #[inline(always)]
+        pub const fn with_wdtstpctl(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 30) | (value as u32) << 30)
+        }
+    }
+    /// `OFS1_SEC` in `OPTION_SETTING_S`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Ofs1Sec(pub u32);
+    impl Ofs1Sec {
+        pub const ADDRESS: usize = 0x0100a200;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".option_setting_s.ofs1_sec";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self(u32::MAX);
+        /// Voltage Detection 0 Circuit Start.
+        #[inline(always)]
+        pub const fn lvdas(self) -> bool {
+            (self.0 >> 2) & 0x00000001 != 0
+        }
+        /// Voltage Detection 0 Circuit Start.
+        #[inline(always)]
+        pub const fn with_lvdas(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 2) | (value as u32) << 2)
+        }
+        /// Voltage Detection 0 Level Select.
+        #[inline(always)]
+        pub const fn vdsel0(self) -> u8 {
+            ((self.0 >> 3) & 0x00000007) as u8
+        }
+        /// Voltage Detection 0 Level Select.
+        #[inline(always)]
+        pub const fn with_vdsel0(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000007 << 3) | ((value as u32) & 0x00000007) << 3)
+        }
+        /// HOCO Oscillation Enable.
+        #[inline(always)]
+        pub const fn hocoen(self) -> bool {
+            (self.0 >> 8) & 0x00000001 != 0
+        }
+        /// HOCO Oscillation Enable.
+        #[inline(always)]
+        pub const fn with_hocoen(self, value: bool) -> Self {
+            Self(self.0 & !(0x00000001 << 8) | (value as u32) << 8)
+        }
+        /// HOCO Frequency Setting 0.
+        #[inline(always)]
+        pub const fn hocofrq0(self) -> u8 {
+            ((self.0 >> 9) & 0x00000007) as u8
+        }
+        /// HOCO Frequency Setting 0.
+        #[inline(always)]
+        pub const fn with_hocofrq0(self, value: u8) -> Self {
+            Self(self.0 & !(0x00000007 << 9) | ((value as u32) & 0x00000007) << 9)
+        }
+    }
+    /// `OSIS` in `ID_CODE`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct Osis(pub [u32; 4]);
+    impl Osis {
+        pub const ADDRESS: usize = 0x0100a150;
+        /// The link section `option_setting!` places the value in.
+        pub const SECTION: &'static str = ".id_code.osis";
+        /// Erased flash, which leaves every setting at its default.
+        pub const ERASED: Self = Self([u32::MAX; 4]);
+    }
+}
+/// Places values of the `option_setting` types in the link sections named by their
+/// `SECTION`, which the linker script has to put at their `ADDRESS`:
+///
+/// ```ignore
+/// option_setting!(OFS0 = Ofs0::ERASED.with_iwdtstrt(true));
+/// ```
+///
+/// Registers left out stay erased. A register given twice is a duplicate definition.
+#[macro_export]
+macro_rules! option_setting {
+    ($($register:ident = $value:expr),* $(,)?) => {
+        $($crate::__option_setting!($register = $value);)*
+    };
+}
+#[cfg(not(feature = "mock"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_setting {
+    (OFS0 = $value:expr) => {
+        #[used] #[link_section = ".option_setting.ofs0"] static __OPTION_SETTING_OFS0 :
+        $crate::option_setting::Ofs0 = $value;
+    };
+    (OFS1_SEC = $value:expr) => {
+        #[used] #[link_section = ".option_setting_s.ofs1_sec"] static
+        __OPTION_SETTING_S_OFS1_SEC : $crate::option_setting::Ofs1Sec = $value;
+    };
+    (OSIS = $value:expr) => {
+        #[used] #[link_section = ".id_code.osis"] static __ID_CODE_OSIS :
+        $crate::option_setting::Osis = $value;
+    };
+}
+#[cfg(feature = "mock")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __option_setting {
+    (OFS0 = $value:expr) => {
+        #[used] static __OPTION_SETTING_OFS0 : $crate::option_setting::Ofs0 = $value;
+    };
+    (OFS1_SEC = $value:expr) => {
+        #[used] static __OPTION_SETTING_S_OFS1_SEC : $crate::option_setting::Ofs1Sec =
+        $value;
+    };
+    (OSIS = $value:expr) => {
+        #[used] static __ID_CODE_OSIS : $crate::option_setting::Osis = $value;
+    };
+}
+#[cfg(feature = "embassy")]
+embassy_hal_internal::interrupt_mod!(
+    IEL0, IEL1, IEL2, IEL3, IEL4, IEL5, IEL6, IEL7, IEL8, IEL9, IEL10, IEL11, IEL12,
+    IEL13, IEL14, IEL15, IEL16, IEL17, IEL18, IEL19, IEL20, IEL21, IEL22, IEL23, IEL24,
+    IEL25, IEL26, IEL27, IEL28, IEL29, IEL30, IEL31, IEL32, IEL33, IEL34, IEL35, IEL36,
+    IEL37, IEL38, IEL39, IEL40, IEL41, IEL42, IEL43, IEL44, IEL45, IEL46, IEL47, IEL48,
+    IEL49, IEL50, IEL51, IEL52, IEL53, IEL54, IEL55, IEL56, IEL57, IEL58, IEL59, IEL60,
+    IEL61, IEL62, IEL63, IEL64, IEL65, IEL66, IEL67, IEL68, IEL69, IEL70, IEL71, IEL72,
+    IEL73, IEL74, IEL75, IEL76, IEL77, IEL78, IEL79, IEL80, IEL81, IEL82, IEL83, IEL84,
+    IEL85, IEL86, IEL87, IEL88, IEL89, IEL90, IEL91, IEL92, IEL93, IEL94, IEL95
+);
+#[cfg(not(feature = "mock"))]
+extern "C" {
+    pub fn IEL0();
+    pub fn IEL1();
+    pub fn IEL2();
+    pub fn IEL3();
+    pub fn IEL4();
+    pub fn IEL5();
+    pub fn IEL6();
+    pub fn IEL7();
+    pub fn IEL8();
+    pub fn IEL9();
+    pub fn IEL10();
+    pub fn IEL11();
+    pub fn IEL12();
+    pub fn IEL13();
+    pub fn IEL14();
+    pub fn IEL15();
+    pub fn IEL16();
+    pub fn IEL17();
+    pub fn IEL18();
+    pub fn IEL19();
+    pub fn IEL20();
+    pub fn IEL21();
+    pub fn IEL22();
+    pub fn IEL23();
+    pub fn IEL24();
+    pub fn IEL25();
+    pub fn IEL26();
+    pub fn IEL27();
+    pub fn IEL28();
+    pub fn IEL29();
+    pub fn IEL30();
+    pub fn IEL31();
+    pub fn IEL32();
+    pub fn IEL33();
+    pub fn IEL34();
+    pub fn IEL35();
+    pub fn IEL36();
+    pub fn IEL37();
+    pub fn IEL38();
+    pub fn IEL39();
+    pub fn IEL40();
+    pub fn IEL41();
+    pub fn IEL42();
+    pub fn IEL43();
+    pub fn IEL44();
+    pub fn IEL45();
+    pub fn IEL46();
+    pub fn IEL47();
+    pub fn IEL48();
+    pub fn IEL49();
+    pub fn IEL50();
+    pub fn IEL51();
+    pub fn IEL52();
+    pub fn IEL53();
+    pub fn IEL54();
+    pub fn IEL55();
+    pub fn IEL56();
+    pub fn IEL57();
+    pub fn IEL58();
+    pub fn IEL59();
+    pub fn IEL60();
+    pub fn IEL61();
+    pub fn IEL62();
+    pub fn IEL63();
+    pub fn IEL64();
+    pub fn IEL65();
+    pub fn IEL66();
+    pub fn IEL67();
+    pub fn IEL68();
+    pub fn IEL69();
+    pub fn IEL70();
+    pub fn IEL71();
+    pub fn IEL72();
+    pub fn IEL73();
+    pub fn IEL74();
+    pub fn IEL75();
+    pub fn IEL76();
+    pub fn IEL77();
+    pub fn IEL78();
+    pub fn IEL79();
+    pub fn IEL80();
+    pub fn IEL81();
+    pub fn IEL82();
+    pub fn IEL83();
+    pub fn IEL84();
+    pub fn IEL85();
+    pub fn IEL86();
+    pub fn IEL87();
+    pub fn IEL88();
+    pub fn IEL89();
+    pub fn IEL90();
+    pub fn IEL91();
+    pub fn IEL92();
+    pub fn IEL93();
+    pub fn IEL94();
+    pub fn IEL95();
+}
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union Vector {
+    pub _handler: unsafe extern "C" fn(),
+    pub _reserved: u32,
+}
+#[cfg(not(feature = "mock"))]
+#[link_section = ".vector_table.interrupts"]
+#[no_mangle]
+pub static __INTERRUPTS: [Vector; 96] = [
+    Vector { _handler: IEL0 },
+    Vector { _handler: IEL1 },
+    Vector { _handler: IEL2 },
+    Vector { _handler: IEL3 },
+    Vector { _handler: IEL4 },
+    Vector { _handler: IEL5 },
+    Vector { _handler: IEL6 },
+    Vector { _handler: IEL7 },
+    Vector { _handler: IEL8 },
+    Vector { _handler: IEL9 },
+    Vector { _handler: IEL10 },
+    Vector { _handler: IEL11 },
+    Vector { _handler: IEL12 },
+    Vector { _handler: IEL13 },
+    Vector { _handler: IEL14 },
+    Vector { _handler: IEL15 },
+    Vector { _handler: IEL16 },
+    Vector { _handler: IEL17 },
+    Vector { _handler: IEL18 },
+    Vector { _handler: IEL19 },
+    Vector { _handler: IEL20 },
+    Vector { _handler: IEL21 },
+    Vector { _handler: IEL22 },
+    Vector { _handler: IEL23 },
+    Vector { _handler: IEL24 },
+    Vector { _handler: IEL25 },
+    Vector { _handler: IEL26 },
+    Vector { _handler: IEL27 },
+    Vector { _handler: IEL28 },
+    Vector { _handler: IEL29 },
+    Vector { _handler: IEL30 },
+    Vector { _handler: IEL31 },
+    Vector { _handler: IEL32 },
+    Vector { _handler: IEL33 },
+    Vector { _handler: IEL34 },
+    Vector { _handler: IEL35 },
+    Vector { _handler: IEL36 },
+    Vector { _handler: IEL37 },
+    Vector { _handler: IEL38 },
+    Vector { _handler: IEL39 },
+    Vector { _handler: IEL40 },
+    Vector { _handler: IEL41 },
+    Vector { _handler: IEL42 },
+    Vector { _handler: IEL43 },
+    Vector { _handler: IEL44 },
+    Vector { _handler: IEL45 },
+    Vector { _handler: IEL46 },
+    Vector { _handler: IEL47 },
+    Vector { _handler: IEL48 },
+    Vector { _handler: IEL49 },
+    Vector { _handler: IEL50 },
+    Vector { _handler: IEL51 },
+    Vector { _handler: IEL52 },
+    Vector { _handler: IEL53 },
+    Vector { _handler: IEL54 },
+    Vector { _handler: IEL55 },
+    Vector { _handler: IEL56 },
+    Vector { _handler: IEL57 },
+    Vector { _handler: IEL58 },
+    Vector { _handler: IEL59 },
+    Vector { _handler: IEL60 },
+    Vector { _handler: IEL61 },
+    Vector { _handler: IEL62 },
+    Vector { _handler: IEL63 },
+    Vector { _handler: IEL64 },
+    Vector { _handler: IEL65 },
+    Vector { _handler: IEL66 },
+    Vector { _handler: IEL67 },
+    Vector { _handler: IEL68 },
+    Vector { _handler: IEL69 },
+    Vector { _handler: IEL70 },
+    Vector { _handler: IEL71 },
+    Vector { _handler: IEL72 },
+    Vector { _handler: IEL73 },
+    Vector { _handler: IEL74 },
+    Vector { _handler: IEL75 },
+    Vector { _handler: IEL76 },
+    Vector { _handler: IEL77 },
+    Vector { _handler: IEL78 },
+    Vector { _handler: IEL79 },
+    Vector { _handler: IEL80 },
+    Vector { _handler: IEL81 },
+    Vector { _handler: IEL82 },
+    Vector { _handler: IEL83 },
+    Vector { _handler: IEL84 },
+    Vector { _handler: IEL85 },
+    Vector { _handler: IEL86 },
+    Vector { _handler: IEL87 },
+    Vector { _handler: IEL88 },
+    Vector { _handler: IEL89 },
+    Vector { _handler: IEL90 },
+    Vector { _handler: IEL91 },
+    Vector { _handler: IEL92 },
+    Vector { _handler: IEL93 },
+    Vector { _handler: IEL94 },
+    Vector { _handler: IEL95 },
+];
+#[cfg(feature = "trace")]
+pub const TRACE_REGISTERS: &[crate::trace::Register] = &[
+    crate::trace::Register {
+        address: 0x40006000,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40006001,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40006002,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[2]",
+    },
+    crate::trace::Register {
+        address: 0x40006003,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40006004,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40006005,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40006006,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[6]",
+    },
+    crate::trace::Register {
+        address: 0x40006007,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40006008,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[8]",
+    },
+    crate::trace::Register {
+        address: 0x40006009,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[9]",
+    },
+    crate::trace::Register {
+        address: 0x4000600a,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[10]",
+    },
+    crate::trace::Register {
+        address: 0x4000600b,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[11]",
+    },
+    crate::trace::Register {
+        address: 0x4000600c,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[12]",
+    },
+    crate::trace::Register {
+        address: 0x4000600d,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[13]",
+    },
+    crate::trace::Register {
+        address: 0x4000600e,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[14]",
+    },
+    crate::trace::Register {
+        address: 0x4000600f,
+        size: 1,
+        peripheral: "ICU",
+        register: "IRQCR[15]",
+    },
+    crate::trace::Register {
+        address: 0x40006100,
+        size: 1,
+        peripheral: "ICU",
+        register: "NMICR",
+    },
+    crate::trace::Register {
+        address: 0x40006120,
+        size: 2,
+        peripheral: "ICU",
+        register: "NMIER",
+    },
+    crate::trace::Register {
+        address: 0x40006130,
+        size: 2,
+        peripheral: "ICU",
+        register: "NMICLR",
+    },
+    crate::trace::Register {
+        address: 0x40006140,
+        size: 2,
+        peripheral: "ICU",
+        register: "NMISR",
+    },
+    crate::trace::Register {
+        address: 0x400061a0,
+        size: 4,
+        peripheral: "ICU",
+        register: "WUPEN0",
+    },
+    crate::trace::Register {
+        address: 0x400061a4,
+        size: 4,
+        peripheral: "ICU",
+        register: "WUPEN1",
+    },
+    crate::trace::Register {
+        address: 0x40006200,
+        size: 2,
+        peripheral: "ICU",
+        register: "SELSR0",
+    },
+    crate::trace::Register {
+        address: 0x40006280,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40006284,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40006288,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4000628c,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40006290,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40006294,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40006298,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[6]",
+    },
+    crate::trace::Register {
+        address: 0x4000629c,
+        size: 4,
+        peripheral: "ICU",
+        register: "DELSR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40006300,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40006304,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40006308,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4000630c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40006310,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40006314,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40006318,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[6]",
+    },
+    crate::trace::Register {
+        address: 0x4000631c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40006320,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[8]",
+    },
+    crate::trace::Register {
+        address: 0x40006324,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[9]",
+    },
+    crate::trace::Register {
+        address: 0x40006328,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[10]",
+    },
+    crate::trace::Register {
+        address: 0x4000632c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[11]",
+    },
+    crate::trace::Register {
+        address: 0x40006330,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[12]",
+    },
+    crate::trace::Register {
+        address: 0x40006334,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[13]",
+    },
+    crate::trace::Register {
+        address: 0x40006338,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[14]",
+    },
+    crate::trace::Register {
+        address: 0x4000633c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[15]",
+    },
+    crate::trace::Register {
+        address: 0x40006340,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[16]",
+    },
+    crate::trace::Register {
+        address: 0x40006344,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[17]",
+    },
+    crate::trace::Register {
+        address: 0x40006348,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[18]",
+    },
+    crate::trace::Register {
+        address: 0x4000634c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[19]",
+    },
+    crate::trace::Register {
+        address: 0x40006350,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[20]",
+    },
+    crate::trace::Register {
+        address: 0x40006354,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[21]",
+    },
+    crate::trace::Register {
+        address: 0x40006358,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[22]",
+    },
+    crate::trace::Register {
+        address: 0x4000635c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[23]",
+    },
+    crate::trace::Register {
+        address: 0x40006360,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[24]",
+    },
+    crate::trace::Register {
+        address: 0x40006364,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[25]",
+    },
+    crate::trace::Register {
+        address: 0x40006368,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[26]",
+    },
+    crate::trace::Register {
+        address: 0x4000636c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[27]",
+    },
+    crate::trace::Register {
+        address: 0x40006370,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[28]",
+    },
+    crate::trace::Register {
+        address: 0x40006374,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[29]",
+    },
+    crate::trace::Register {
+        address: 0x40006378,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[30]",
+    },
+    crate::trace::Register {
+        address: 0x4000637c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[31]",
+    },
+    crate::trace::Register {
+        address: 0x40006380,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[32]",
+    },
+    crate::trace::Register {
+        address: 0x40006384,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[33]",
+    },
+    crate::trace::Register {
+        address: 0x40006388,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[34]",
+    },
+    crate::trace::Register {
+        address: 0x4000638c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[35]",
+    },
+    crate::trace::Register {
+        address: 0x40006390,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[36]",
+    },
+    crate::trace::Register {
+        address: 0x40006394,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[37]",
+    },
+    crate::trace::Register {
+        address: 0x40006398,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[38]",
+    },
+    crate::trace::Register {
+        address: 0x4000639c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[39]",
+    },
+    crate::trace::Register {
+        address: 0x400063a0,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[40]",
+    },
+    crate::trace::Register {
+        address: 0x400063a4,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[41]",
+    },
+    crate::trace::Register {
+        address: 0x400063a8,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[42]",
+    },
+    crate::trace::Register {
+        address: 0x400063ac,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[43]",
+    },
+    crate::trace::Register {
+        address: 0x400063b0,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[44]",
+    },
+    crate::trace::Register {
+        address: 0x400063b4,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[45]",
+    },
+    crate::trace::Register {
+        address: 0x400063b8,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[46]",
+    },
+    crate::trace::Register {
+        address: 0x400063bc,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[47]",
+    },
+    crate::trace::Register {
+        address: 0x400063c0,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[48]",
+    },
+    crate::trace::Register {
+        address: 0x400063c4,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[49]",
+    },
+    crate::trace::Register {
+        address: 0x400063c8,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[50]",
+    },
+    crate::trace::Register {
+        address: 0x400063cc,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[51]",
+    },
+    crate::trace::Register {
+        address: 0x400063d0,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[52]",
+    },
+    crate::trace::Register {
+        address: 0x400063d4,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[53]",
+    },
+    crate::trace::Register {
+        address: 0x400063d8,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[54]",
+    },
+    crate::trace::Register {
+        address: 0x400063dc,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[55]",
+    },
+    crate::trace::Register {
+        address: 0x400063e0,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[56]",
+    },
+    crate::trace::Register {
+        address: 0x400063e4,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[57]",
+    },
+    crate::trace::Register {
+        address: 0x400063e8,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[58]",
+    },
+    crate::trace::Register {
+        address: 0x400063ec,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[59]",
+    },
+    crate::trace::Register {
+        address: 0x400063f0,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[60]",
+    },
+    crate::trace::Register {
+        address: 0x400063f4,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[61]",
+    },
+    crate::trace::Register {
+        address: 0x400063f8,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[62]",
+    },
+    crate::trace::Register {
+        address: 0x400063fc,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[63]",
+    },
+    crate::trace::Register {
+        address: 0x40006400,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[64]",
+    },
+    crate::trace::Register {
+        address: 0x40006404,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[65]",
+    },
+    crate::trace::Register {
+        address: 0x40006408,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[66]",
+    },
+    crate::trace::Register {
+        address: 0x4000640c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[67]",
+    },
+    crate::trace::Register {
+        address: 0x40006410,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[68]",
+    },
+    crate::trace::Register {
+        address: 0x40006414,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[69]",
+    },
+    crate::trace::Register {
+        address: 0x40006418,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[70]",
+    },
+    crate::trace::Register {
+        address: 0x4000641c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[71]",
+    },
+    crate::trace::Register {
+        address: 0x40006420,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[72]",
+    },
+    crate::trace::Register {
+        address: 0x40006424,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[73]",
+    },
+    crate::trace::Register {
+        address: 0x40006428,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[74]",
+    },
+    crate::trace::Register {
+        address: 0x4000642c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[75]",
+    },
+    crate::trace::Register {
+        address: 0x40006430,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[76]",
+    },
+    crate::trace::Register {
+        address: 0x40006434,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[77]",
+    },
+    crate::trace::Register {
+        address: 0x40006438,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[78]",
+    },
+    crate::trace::Register {
+        address: 0x4000643c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[79]",
+    },
+    crate::trace::Register {
+        address: 0x40006440,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[80]",
+    },
+    crate::trace::Register {
+        address: 0x40006444,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[81]",
+    },
+    crate::trace::Register {
+        address: 0x40006448,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[82]",
+    },
+    crate::trace::Register {
+        address: 0x4000644c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[83]",
+    },
+    crate::trace::Register {
+        address: 0x40006450,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[84]",
+    },
+    crate::trace::Register {
+        address: 0x40006454,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[85]",
+    },
+    crate::trace::Register {
+        address: 0x40006458,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[86]",
+    },
+    crate::trace::Register {
+        address: 0x4000645c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[87]",
+    },
+    crate::trace::Register {
+        address: 0x40006460,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[88]",
+    },
+    crate::trace::Register {
+        address: 0x40006464,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[89]",
+    },
+    crate::trace::Register {
+        address: 0x40006468,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[90]",
+    },
+    crate::trace::Register {
+        address: 0x4000646c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[91]",
+    },
+    crate::trace::Register {
+        address: 0x40006470,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[92]",
+    },
+    crate::trace::Register {
+        address: 0x40006474,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[93]",
+    },
+    crate::trace::Register {
+        address: 0x40006478,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[94]",
+    },
+    crate::trace::Register {
+        address: 0x4000647c,
+        size: 4,
+        peripheral: "ICU",
+        register: "IELSR[95]",
+    },
+    crate::trace::Register {
+        address: 0x4001e00c,
+        size: 2,
+        peripheral: "SYSC",
+        register: "SBYCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e020,
+        size: 4,
+        peripheral: "SYSC",
+        register: "SCKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e026,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SCKSCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e028,
+        size: 2,
+        peripheral: "SYSC",
+        register: "PLLCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e02a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "PLLCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e030,
+        size: 1,
+        peripheral: "SYSC",
+        register: "BCKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e032,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOSCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e036,
+        size: 1,
+        peripheral: "SYSC",
+        register: "HOCOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e037,
+        size: 1,
+        peripheral: "SYSC",
+        register: "HOCOCR2",
+    },
+    crate::trace::Register {
+        address: 0x4001e038,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOCOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e039,
+        size: 1,
+        peripheral: "SYSC",
+        register: "FLLCR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e03a,
+        size: 2,
+        peripheral: "SYSC",
+        register: "FLLCR2",
+    },
+    crate::trace::Register {
+        address: 0x4001e03c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OSCSF",
+    },
+    crate::trace::Register {
+        address: 0x4001e03e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "CKOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e03f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "TRCKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e040,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OSTDCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e041,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OSTDSR",
+    },
+    crate::trace::Register {
+        address: 0x4001e048,
+        size: 2,
+        peripheral: "SYSC",
+        register: "PLL2CCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e04a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "PLL2CR",
+    },
+    crate::trace::Register {
+        address: 0x4001e052,
+        size: 1,
+        peripheral: "SYSC",
+        register: "EBCKOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e061,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOCOUTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e062,
+        size: 1,
+        peripheral: "SYSC",
+        register: "HOCOUTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e06c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "USBCKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e06d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OCTACKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e06e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "CANFDCKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e06f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "USB60CKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e070,
+        size: 1,
+        peripheral: "SYSC",
+        register: "CECCKDIVCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e074,
+        size: 1,
+        peripheral: "SYSC",
+        register: "USBCKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e075,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OCTACKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e076,
+        size: 1,
+        peripheral: "SYSC",
+        register: "CANFDCKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e077,
+        size: 1,
+        peripheral: "SYSC",
+        register: "USB60CKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e078,
+        size: 1,
+        peripheral: "SYSC",
+        register: "CECCKCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e088,
+        size: 4,
+        peripheral: "SYSC",
+        register: "SNZREQCR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e092,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SNZCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e094,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SNZEDCR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e095,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SNZEDCR[1]",
+    },
+    crate::trace::Register {
+        address: 0x4001e098,
+        size: 4,
+        peripheral: "SYSC",
+        register: "SNZREQCR[1]",
+    },
+    crate::trace::Register {
+        address: 0x4001e0a0,
+        size: 1,
+        peripheral: "SYSC",
+        register: "OPCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0a2,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOSCWTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0aa,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOPCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0c0,
+        size: 2,
+        peripheral: "SYSC",
+        register: "RSTSR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e0,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1CR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e1,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1SR",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e2,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2CR1",
+    },
+    crate::trace::Register {
+        address: 0x4001e0e3,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2SR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3c0,
+        size: 4,
+        peripheral: "SYSC",
+        register: "CGFSAR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3c4,
+        size: 4,
+        peripheral: "SYSC",
+        register: "RSTSAR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3c8,
+        size: 4,
+        peripheral: "SYSC",
+        register: "LPMSAR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3cc,
+        size: 4,
+        peripheral: "SYSC",
+        register: "LVDSAR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3d0,
+        size: 4,
+        peripheral: "SYSC",
+        register: "BBFSAR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3e0,
+        size: 4,
+        peripheral: "SYSC",
+        register: "DPFSAR",
+    },
+    crate::trace::Register {
+        address: 0x4001e3fe,
+        size: 2,
+        peripheral: "SYSC",
+        register: "PRCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e400,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSBYCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e401,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSWCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e402,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIER[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e403,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIER[1]",
+    },
+    crate::trace::Register {
+        address: 0x4001e404,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIER[2]",
+    },
+    crate::trace::Register {
+        address: 0x4001e405,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIER[3]",
+    },
+    crate::trace::Register {
+        address: 0x4001e406,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIFR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e407,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIFR[1]",
+    },
+    crate::trace::Register {
+        address: 0x4001e408,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIFR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4001e409,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIFR[3]",
+    },
+    crate::trace::Register {
+        address: 0x4001e40a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIEGR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e40b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIEGR[1]",
+    },
+    crate::trace::Register {
+        address: 0x4001e40c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "DPSIEGR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4001e40e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SYOCDCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e410,
+        size: 1,
+        peripheral: "SYSC",
+        register: "RSTSR0",
+    },
+    crate::trace::Register {
+        address: 0x4001e411,
+        size: 1,
+        peripheral: "SYSC",
+        register: "RSTSR2",
+    },
+    crate::trace::Register {
+        address: 0x4001e413,
+        size: 1,
+        peripheral: "SYSC",
+        register: "MOMCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e416,
+        size: 1,
+        peripheral: "SYSC",
+        register: "FWEPROR",
+    },
+    crate::trace::Register {
+        address: 0x4001e417,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1CMPCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e418,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2CMPCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e41a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD1CR0",
+    },
+    crate::trace::Register {
+        address: 0x4001e41b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LVD2CR0",
+    },
+    crate::trace::Register {
+        address: 0x4001e41d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBATTMNSELR",
+    },
+    crate::trace::Register {
+        address: 0x4001e41e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBATTMONR",
+    },
+    crate::trace::Register {
+        address: 0x4001e480,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOSCCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e481,
+        size: 1,
+        peripheral: "SYSC",
+        register: "SOMCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e490,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LOCOCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e492,
+        size: 1,
+        peripheral: "SYSC",
+        register: "LOCOUTCR",
+    },
+    crate::trace::Register {
+        address: 0x4001e4bb,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTICTLR",
+    },
+    crate::trace::Register {
+        address: 0x4001e4c0,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBER",
+    },
+    crate::trace::Register {
+        address: 0x4001e500,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[0]",
+    },
+    crate::trace::Register {
+        address: 0x4001e501,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[1]",
+    },
+    crate::trace::Register {
+        address: 0x4001e502,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4001e503,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[3]",
+    },
+    crate::trace::Register {
+        address: 0x4001e504,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[4]",
+    },
+    crate::trace::Register {
+        address: 0x4001e505,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[5]",
+    },
+    crate::trace::Register {
+        address: 0x4001e506,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[6]",
+    },
+    crate::trace::Register {
+        address: 0x4001e507,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[7]",
+    },
+    crate::trace::Register {
+        address: 0x4001e508,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[8]",
+    },
+    crate::trace::Register {
+        address: 0x4001e509,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[9]",
+    },
+    crate::trace::Register {
+        address: 0x4001e50a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[10]",
+    },
+    crate::trace::Register {
+        address: 0x4001e50b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[11]",
+    },
+    crate::trace::Register {
+        address: 0x4001e50c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[12]",
+    },
+    crate::trace::Register {
+        address: 0x4001e50d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[13]",
+    },
+    crate::trace::Register {
+        address: 0x4001e50e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[14]",
+    },
+    crate::trace::Register {
+        address: 0x4001e50f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[15]",
+    },
+    crate::trace::Register {
+        address: 0x4001e510,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[16]",
+    },
+    crate::trace::Register {
+        address: 0x4001e511,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[17]",
+    },
+    crate::trace::Register {
+        address: 0x4001e512,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[18]",
+    },
+    crate::trace::Register {
+        address: 0x4001e513,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[19]",
+    },
+    crate::trace::Register {
+        address: 0x4001e514,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[20]",
+    },
+    crate::trace::Register {
+        address: 0x4001e515,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[21]",
+    },
+    crate::trace::Register {
+        address: 0x4001e516,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[22]",
+    },
+    crate::trace::Register {
+        address: 0x4001e517,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[23]",
+    },
+    crate::trace::Register {
+        address: 0x4001e518,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[24]",
+    },
+    crate::trace::Register {
+        address: 0x4001e519,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[25]",
+    },
+    crate::trace::Register {
+        address: 0x4001e51a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[26]",
+    },
+    crate::trace::Register {
+        address: 0x4001e51b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[27]",
+    },
+    crate::trace::Register {
+        address: 0x4001e51c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[28]",
+    },
+    crate::trace::Register {
+        address: 0x4001e51d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[29]",
+    },
+    crate::trace::Register {
+        address: 0x4001e51e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[30]",
+    },
+    crate::trace::Register {
+        address: 0x4001e51f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[31]",
+    },
+    crate::trace::Register {
+        address: 0x4001e520,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[32]",
+    },
+    crate::trace::Register {
+        address: 0x4001e521,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[33]",
+    },
+    crate::trace::Register {
+        address: 0x4001e522,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[34]",
+    },
+    crate::trace::Register {
+        address: 0x4001e523,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[35]",
+    },
+    crate::trace::Register {
+        address: 0x4001e524,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[36]",
+    },
+    crate::trace::Register {
+        address: 0x4001e525,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[37]",
+    },
+    crate::trace::Register {
+        address: 0x4001e526,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[38]",
+    },
+    crate::trace::Register {
+        address: 0x4001e527,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[39]",
+    },
+    crate::trace::Register {
+        address: 0x4001e528,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[40]",
+    },
+    crate::trace::Register {
+        address: 0x4001e529,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[41]",
+    },
+    crate::trace::Register {
+        address: 0x4001e52a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[42]",
+    },
+    crate::trace::Register {
+        address: 0x4001e52b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[43]",
+    },
+    crate::trace::Register {
+        address: 0x4001e52c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[44]",
+    },
+    crate::trace::Register {
+        address: 0x4001e52d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[45]",
+    },
+    crate::trace::Register {
+        address: 0x4001e52e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[46]",
+    },
+    crate::trace::Register {
+        address: 0x4001e52f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[47]",
+    },
+    crate::trace::Register {
+        address: 0x4001e530,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[48]",
+    },
+    crate::trace::Register {
+        address: 0x4001e531,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[49]",
+    },
+    crate::trace::Register {
+        address: 0x4001e532,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[50]",
+    },
+    crate::trace::Register {
+        address: 0x4001e533,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[51]",
+    },
+    crate::trace::Register {
+        address: 0x4001e534,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[52]",
+    },
+    crate::trace::Register {
+        address: 0x4001e535,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[53]",
+    },
+    crate::trace::Register {
+        address: 0x4001e536,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[54]",
+    },
+    crate::trace::Register {
+        address: 0x4001e537,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[55]",
+    },
+    crate::trace::Register {
+        address: 0x4001e538,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[56]",
+    },
+    crate::trace::Register {
+        address: 0x4001e539,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[57]",
+    },
+    crate::trace::Register {
+        address: 0x4001e53a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[58]",
+    },
+    crate::trace::Register {
+        address: 0x4001e53b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[59]",
+    },
+    crate::trace::Register {
+        address: 0x4001e53c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[60]",
+    },
+    crate::trace::Register {
+        address: 0x4001e53d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[61]",
+    },
+    crate::trace::Register {
+        address: 0x4001e53e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[62]",
+    },
+    crate::trace::Register {
+        address: 0x4001e53f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[63]",
+    },
+    crate::trace::Register {
+        address: 0x4001e540,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[64]",
+    },
+    crate::trace::Register {
+        address: 0x4001e541,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[65]",
+    },
+    crate::trace::Register {
+        address: 0x4001e542,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[66]",
+    },
+    crate::trace::Register {
+        address: 0x4001e543,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[67]",
+    },
+    crate::trace::Register {
+        address: 0x4001e544,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[68]",
+    },
+    crate::trace::Register {
+        address: 0x4001e545,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[69]",
+    },
+    crate::trace::Register {
+        address: 0x4001e546,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[70]",
+    },
+    crate::trace::Register {
+        address: 0x4001e547,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[71]",
+    },
+    crate::trace::Register {
+        address: 0x4001e548,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[72]",
+    },
+    crate::trace::Register {
+        address: 0x4001e549,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[73]",
+    },
+    crate::trace::Register {
+        address: 0x4001e54a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[74]",
+    },
+    crate::trace::Register {
+        address: 0x4001e54b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[75]",
+    },
+    crate::trace::Register {
+        address: 0x4001e54c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[76]",
+    },
+    crate::trace::Register {
+        address: 0x4001e54d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[77]",
+    },
+    crate::trace::Register {
+        address: 0x4001e54e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[78]",
+    },
+    crate::trace::Register {
+        address: 0x4001e54f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[79]",
+    },
+    crate::trace::Register {
+        address: 0x4001e550,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[80]",
+    },
+    crate::trace::Register {
+        address: 0x4001e551,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[81]",
+    },
+    crate::trace::Register {
+        address: 0x4001e552,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[82]",
+    },
+    crate::trace::Register {
+        address: 0x4001e553,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[83]",
+    },
+    crate::trace::Register {
+        address: 0x4001e554,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[84]",
+    },
+    crate::trace::Register {
+        address: 0x4001e555,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[85]",
+    },
+    crate::trace::Register {
+        address: 0x4001e556,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[86]",
+    },
+    crate::trace::Register {
+        address: 0x4001e557,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[87]",
+    },
+    crate::trace::Register {
+        address: 0x4001e558,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[88]",
+    },
+    crate::trace::Register {
+        address: 0x4001e559,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[89]",
+    },
+    crate::trace::Register {
+        address: 0x4001e55a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[90]",
+    },
+    crate::trace::Register {
+        address: 0x4001e55b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[91]",
+    },
+    crate::trace::Register {
+        address: 0x4001e55c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[92]",
+    },
+    crate::trace::Register {
+        address: 0x4001e55d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[93]",
+    },
+    crate::trace::Register {
+        address: 0x4001e55e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[94]",
+    },
+    crate::trace::Register {
+        address: 0x4001e55f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[95]",
+    },
+    crate::trace::Register {
+        address: 0x4001e560,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[96]",
+    },
+    crate::trace::Register {
+        address: 0x4001e561,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[97]",
+    },
+    crate::trace::Register {
+        address: 0x4001e562,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[98]",
+    },
+    crate::trace::Register {
+        address: 0x4001e563,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[99]",
+    },
+    crate::trace::Register {
+        address: 0x4001e564,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[100]",
+    },
+    crate::trace::Register {
+        address: 0x4001e565,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[101]",
+    },
+    crate::trace::Register {
+        address: 0x4001e566,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[102]",
+    },
+    crate::trace::Register {
+        address: 0x4001e567,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[103]",
+    },
+    crate::trace::Register {
+        address: 0x4001e568,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[104]",
+    },
+    crate::trace::Register {
+        address: 0x4001e569,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[105]",
+    },
+    crate::trace::Register {
+        address: 0x4001e56a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[106]",
+    },
+    crate::trace::Register {
+        address: 0x4001e56b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[107]",
+    },
+    crate::trace::Register {
+        address: 0x4001e56c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[108]",
+    },
+    crate::trace::Register {
+        address: 0x4001e56d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[109]",
+    },
+    crate::trace::Register {
+        address: 0x4001e56e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[110]",
+    },
+    crate::trace::Register {
+        address: 0x4001e56f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[111]",
+    },
+    crate::trace::Register {
+        address: 0x4001e570,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[112]",
+    },
+    crate::trace::Register {
+        address: 0x4001e571,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[113]",
+    },
+    crate::trace::Register {
+        address: 0x4001e572,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[114]",
+    },
+    crate::trace::Register {
+        address: 0x4001e573,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[115]",
+    },
+    crate::trace::Register {
+        address: 0x4001e574,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[116]",
+    },
+    crate::trace::Register {
+        address: 0x4001e575,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[117]",
+    },
+    crate::trace::Register {
+        address: 0x4001e576,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[118]",
+    },
+    crate::trace::Register {
+        address: 0x4001e577,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[119]",
+    },
+    crate::trace::Register {
+        address: 0x4001e578,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[120]",
+    },
+    crate::trace::Register {
+        address: 0x4001e579,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[121]",
+    },
+    crate::trace::Register {
+        address: 0x4001e57a,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[122]",
+    },
+    crate::trace::Register {
+        address: 0x4001e57b,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[123]",
+    },
+    crate::trace::Register {
+        address: 0x4001e57c,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[124]",
+    },
+    crate::trace::Register {
+        address: 0x4001e57d,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[125]",
+    },
+    crate::trace::Register {
+        address: 0x4001e57e,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[126]",
+    },
+    crate::trace::Register {
+        address: 0x4001e57f,
+        size: 1,
+        peripheral: "SYSC",
+        register: "VBTBKR[127]",
+    },
+    crate::trace::Register {
+        address: 0x40041000,
+        size: 1,
+        peripheral: "ELC",
+        register: "ELCR",
+    },
+    crate::trace::Register {
+        address: 0x40041002,
+        size: 1,
+        peripheral: "ELC",
+        register: "ELSEGR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40041004,
+        size: 1,
+        peripheral: "ELC",
+        register: "ELSEGR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40041010,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[0]",
+    },
+    crate::trace::Register {
+        address: 0x40041014,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[1]",
+    },
+    crate::trace::Register {
+        address: 0x40041018,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[2]",
+    },
+    crate::trace::Register {
+        address: 0x4004101c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[3]",
+    },
+    crate::trace::Register {
+        address: 0x40041020,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[4]",
+    },
+    crate::trace::Register {
+        address: 0x40041024,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[5]",
+    },
+    crate::trace::Register {
+        address: 0x40041028,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[6]",
+    },
+    crate::trace::Register {
+        address: 0x4004102c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[7]",
+    },
+    crate::trace::Register {
+        address: 0x40041030,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[8]",
+    },
+    crate::trace::Register {
+        address: 0x40041034,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[9]",
+    },
+    crate::trace::Register {
+        address: 0x40041038,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[10]",
+    },
+    crate::trace::Register {
+        address: 0x4004103c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[11]",
+    },
+    crate::trace::Register {
+        address: 0x40041040,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[12]",
+    },
+    crate::trace::Register {
+        address: 0x40041044,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[13]",
+    },
+    crate::trace::Register {
+        address: 0x40041048,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[14]",
+    },
+    crate::trace::Register {
+        address: 0x4004104c,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[15]",
+    },
+    crate::trace::Register {
+        address: 0x40041050,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[16]",
+    },
+    crate::trace::Register {
+        address: 0x40041054,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[17]",
+    },
+    crate::trace::Register {
+        address: 0x40041058,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELSR[18]",
+    },
+    crate::trace::Register {
+        address: 0x40041074,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELCSARA",
+    },
+    crate::trace::Register {
+        address: 0x40041078,
+        size: 2,
+        peripheral: "ELC",
+        register: "ELCSARB",
+    },
+    crate::trace::Register {
+        address: 0x40080000,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PODR",
+    },
+    crate::trace::Register {
+        address: 0x40080000,
+        size: 4,
+        peripheral: "PORT0",
+        register: "PCNTR1",
+    },
+    crate::trace::Register {
+        address: 0x40080002,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PDR",
+    },
+    crate::trace::Register {
+        address: 0x40080004,
+        size: 4,
+        peripheral: "PORT0",
+        register: "PCNTR2",
+    },
+    crate::trace::Register {
+        address: 0x40080006,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PIDR",
+    },
+    crate::trace::Register {
+        address: 0x40080008,
+        size: 2,
+        peripheral: "PORT0",
+        register: "PORR",
+    },
+    crate::trace::Register {
+        address: 0x40080008,
+        size: 4,
+        peripheral: "PORT0",
+        register: "PCNTR3",
+    },
+    crate::trace::Register {
+        address: 0x4008000a,
+        size: 2,
+        peripheral: "PORT0",
+        register: "POSR",
+    },
+    crate::trace::Register {
+        address: 0x40080020,
+        size: 2,
+        peripheral: "PORT1",
+        register: "PODR",
+    },
+    crate::trace::Register {
+        address: 0x40080020,
+        size: 4,
+        peripheral: "PORT1",
+        register: "PCNTR1",
+    },
+    crate::trace::Register {
+        address: 0x40080022,
+        size: 2,
+        peripheral: "PORT1",
+        register: "PDR",
+    },
+    crate::trace::Register {
+        address: 0x40080024,
+        size: 4,
+        peripheral: "PORT1",
+        register: "PCNTR2",
+    },
+    crate::trace::Register {
+        address: 0x40080026,
+        size: 2,
+        peripheral: "PORT1",
+        register: "PIDR",
+    },
+    crate::trace::Register {
+        address: 0x40080028,
+        size: 2,
+        peripheral: "PORT1",
+        register: "PORR",
+    },
+    crate::trace::Register {
+        address: 0x40080028,
+        size: 4,
+        peripheral: "PORT1",
+        register: "PCNTR3",
+    },
+    crate::trace::Register {
+        address: 0x4008002a,
+        size: 2,
+        peripheral: "PORT1",
+        register: "POSR",
+    },
+    crate::trace::Register {
+        address: 0x40080800,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[0]",
+    },
+    crate::trace::Register {
+        address: 0x40080804,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[1]",
+    },
+    crate::trace::Register {
+        address: 0x40080808,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[2]",
+    },
+    crate::trace::Register {
+        address: 0x4008080c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[3]",
+    },
+    crate::trace::Register {
+        address: 0x40080810,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[4]",
+    },
+    crate::trace::Register {
+        address: 0x40080814,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[5]",
+    },
+    crate::trace::Register {
+        address: 0x40080818,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[6]",
+    },
+    crate::trace::Register {
+        address: 0x4008081c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[7]",
+    },
+    crate::trace::Register {
+        address: 0x40080820,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[8]",
+    },
+    crate::trace::Register {
+        address: 0x40080824,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[9]",
+    },
+    crate::trace::Register {
+        address: 0x40080828,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[10]",
+    },
+    crate::trace::Register {
+        address: 0x4008082c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[11]",
+    },
+    crate::trace::Register {
+        address: 0x40080830,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[12]",
+    },
+    crate::trace::Register {
+        address: 0x40080834,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[13]",
+    },
+    crate::trace::Register {
+        address: 0x40080838,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[14]",
+    },
+    crate::trace::Register {
+        address: 0x4008083c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[15]",
+    },
+    crate::trace::Register {
+        address: 0x40080840,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[16]",
+    },
+    crate::trace::Register {
+        address: 0x40080844,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[17]",
+    },
+    crate::trace::Register {
+        address: 0x40080848,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[18]",
+    },
+    crate::trace::Register {
+        address: 0x4008084c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[19]",
+    },
+    crate::trace::Register {
+        address: 0x40080850,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[20]",
+    },
+    crate::trace::Register {
+        address: 0x40080854,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[21]",
+    },
+    crate::trace::Register {
+        address: 0x40080858,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[22]",
+    },
+    crate::trace::Register {
+        address: 0x4008085c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[23]",
+    },
+    crate::trace::Register {
+        address: 0x40080860,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[24]",
+    },
+    crate::trace::Register {
+        address: 0x40080864,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[25]",
+    },
+    crate::trace::Register {
+        address: 0x40080868,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[26]",
+    },
+    crate::trace::Register {
+        address: 0x4008086c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[27]",
+    },
+    crate::trace::Register {
+        address: 0x40080870,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[28]",
+    },
+    crate::trace::Register {
+        address: 0x40080874,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[29]",
+    },
+    crate::trace::Register {
+        address: 0x40080878,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[30]",
+    },
+    crate::trace::Register {
+        address: 0x4008087c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[31]",
+    },
+    crate::trace::Register {
+        address: 0x40080880,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[32]",
+    },
+    crate::trace::Register {
+        address: 0x40080884,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[33]",
+    },
+    crate::trace::Register {
+        address: 0x40080888,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[34]",
+    },
+    crate::trace::Register {
+        address: 0x4008088c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[35]",
+    },
+    crate::trace::Register {
+        address: 0x40080890,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[36]",
+    },
+    crate::trace::Register {
+        address: 0x40080894,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[37]",
+    },
+    crate::trace::Register {
+        address: 0x40080898,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[38]",
+    },
+    crate::trace::Register {
+        address: 0x4008089c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[39]",
+    },
+    crate::trace::Register {
+        address: 0x400808a0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[40]",
+    },
+    crate::trace::Register {
+        address: 0x400808a4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[41]",
+    },
+    crate::trace::Register {
+        address: 0x400808a8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[42]",
+    },
+    crate::trace::Register {
+        address: 0x400808ac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[43]",
+    },
+    crate::trace::Register {
+        address: 0x400808b0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[44]",
+    },
+    crate::trace::Register {
+        address: 0x400808b4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[45]",
+    },
+    crate::trace::Register {
+        address: 0x400808b8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[46]",
+    },
+    crate::trace::Register {
+        address: 0x400808bc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[47]",
+    },
+    crate::trace::Register {
+        address: 0x400808c0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[48]",
+    },
+    crate::trace::Register {
+        address: 0x400808c4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[49]",
+    },
+    crate::trace::Register {
+        address: 0x400808c8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[50]",
+    },
+    crate::trace::Register {
+        address: 0x400808cc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[51]",
+    },
+    crate::trace::Register {
+        address: 0x400808d0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[52]",
+    },
+    crate::trace::Register {
+        address: 0x400808d4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[53]",
+    },
+    crate::trace::Register {
+        address: 0x400808d8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[54]",
+    },
+    crate::trace::Register {
+        address: 0x400808dc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[55]",
+    },
+    crate::trace::Register {
+        address: 0x400808e0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[56]",
+    },
+    crate::trace::Register {
+        address: 0x400808e4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[57]",
+    },
+    crate::trace::Register {
+        address: 0x400808e8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[58]",
+    },
+    crate::trace::Register {
+        address: 0x400808ec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[59]",
+    },
+    crate::trace::Register {
+        address: 0x400808f0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[60]",
+    },
+    crate::trace::Register {
+        address: 0x400808f4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[61]",
+    },
+    crate::trace::Register {
+        address: 0x400808f8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[62]",
+    },
+    crate::trace::Register {
+        address: 0x400808fc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[63]",
+    },
+    crate::trace::Register {
+        address: 0x40080900,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[64]",
+    },
+    crate::trace::Register {
+        address: 0x40080904,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[65]",
+    },
+    crate::trace::Register {
+        address: 0x40080908,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[66]",
+    },
+    crate::trace::Register {
+        address: 0x4008090c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[67]",
+    },
+    crate::trace::Register {
+        address: 0x40080910,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[68]",
+    },
+    crate::trace::Register {
+        address: 0x40080914,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[69]",
+    },
+    crate::trace::Register {
+        address: 0x40080918,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[70]",
+    },
+    crate::trace::Register {
+        address: 0x4008091c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[71]",
+    },
+    crate::trace::Register {
+        address: 0x40080920,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[72]",
+    },
+    crate::trace::Register {
+        address: 0x40080924,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[73]",
+    },
+    crate::trace::Register {
+        address: 0x40080928,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[74]",
+    },
+    crate::trace::Register {
+        address: 0x4008092c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[75]",
+    },
+    crate::trace::Register {
+        address: 0x40080930,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[76]",
+    },
+    crate::trace::Register {
+        address: 0x40080934,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[77]",
+    },
+    crate::trace::Register {
+        address: 0x40080938,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[78]",
+    },
+    crate::trace::Register {
+        address: 0x4008093c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[79]",
+    },
+    crate::trace::Register {
+        address: 0x40080940,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[80]",
+    },
+    crate::trace::Register {
+        address: 0x40080944,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[81]",
+    },
+    crate::trace::Register {
+        address: 0x40080948,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[82]",
+    },
+    crate::trace::Register {
+        address: 0x4008094c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[83]",
+    },
+    crate::trace::Register {
+        address: 0x40080950,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[84]",
+    },
+    crate::trace::Register {
+        address: 0x40080954,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[85]",
+    },
+    crate::trace::Register {
+        address: 0x40080958,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[86]",
+    },
+    crate::trace::Register {
+        address: 0x4008095c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[87]",
+    },
+    crate::trace::Register {
+        address: 0x40080960,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[88]",
+    },
+    crate::trace::Register {
+        address: 0x40080964,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[89]",
+    },
+    crate::trace::Register {
+        address: 0x40080968,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[90]",
+    },
+    crate::trace::Register {
+        address: 0x4008096c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[91]",
+    },
+    crate::trace::Register {
+        address: 0x40080970,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[92]",
+    },
+    crate::trace::Register {
+        address: 0x40080974,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[93]",
+    },
+    crate::trace::Register {
+        address: 0x40080978,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[94]",
+    },
+    crate::trace::Register {
+        address: 0x4008097c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[95]",
+    },
+    crate::trace::Register {
+        address: 0x40080980,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[96]",
+    },
+    crate::trace::Register {
+        address: 0x40080984,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[97]",
+    },
+    crate::trace::Register {
+        address: 0x40080988,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[98]",
+    },
+    crate::trace::Register {
+        address: 0x4008098c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[99]",
+    },
+    crate::trace::Register {
+        address: 0x40080990,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[100]",
+    },
+    crate::trace::Register {
+        address: 0x40080994,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[101]",
+    },
+    crate::trace::Register {
+        address: 0x40080998,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[102]",
+    },
+    crate::trace::Register {
+        address: 0x4008099c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[103]",
+    },
+    crate::trace::Register {
+        address: 0x400809a0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[104]",
+    },
+    crate::trace::Register {
+        address: 0x400809a4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[105]",
+    },
+    crate::trace::Register {
+        address: 0x400809a8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[106]",
+    },
+    crate::trace::Register {
+        address: 0x400809ac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[107]",
+    },
+    crate::trace::Register {
+        address: 0x400809b0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[108]",
+    },
+    crate::trace::Register {
+        address: 0x400809b4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[109]",
+    },
+    crate::trace::Register {
+        address: 0x400809b8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[110]",
+    },
+    crate::trace::Register {
+        address: 0x400809bc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[111]",
+    },
+    crate::trace::Register {
+        address: 0x400809c0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[112]",
+    },
+    crate::trace::Register {
+        address: 0x400809c4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[113]",
+    },
+    crate::trace::Register {
+        address: 0x400809c8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[114]",
+    },
+    crate::trace::Register {
+        address: 0x400809cc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[115]",
+    },
+    crate::trace::Register {
+        address: 0x400809d0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[116]",
+    },
+    crate::trace::Register {
+        address: 0x400809d4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[117]",
+    },
+    crate::trace::Register {
+        address: 0x400809d8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[118]",
+    },
+    crate::trace::Register {
+        address: 0x400809dc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[119]",
+    },
+    crate::trace::Register {
+        address: 0x400809e0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[120]",
+    },
+    crate::trace::Register {
+        address: 0x400809e4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[121]",
+    },
+    crate::trace::Register {
+        address: 0x400809e8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[122]",
+    },
+    crate::trace::Register {
+        address: 0x400809ec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[123]",
+    },
+    crate::trace::Register {
+        address: 0x400809f0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[124]",
+    },
+    crate::trace::Register {
+        address: 0x400809f4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[125]",
+    },
+    crate::trace::Register {
+        address: 0x400809f8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[126]",
+    },
+    crate::trace::Register {
+        address: 0x400809fc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[127]",
+    },
+    crate::trace::Register {
+        address: 0x40080a00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[128]",
+    },
+    crate::trace::Register {
+        address: 0x40080a04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[129]",
+    },
+    crate::trace::Register {
+        address: 0x40080a08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[130]",
+    },
+    crate::trace::Register {
+        address: 0x40080a0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[131]",
+    },
+    crate::trace::Register {
+        address: 0x40080a10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[132]",
+    },
+    crate::trace::Register {
+        address: 0x40080a14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[133]",
+    },
+    crate::trace::Register {
+        address: 0x40080a18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[134]",
+    },
+    crate::trace::Register {
+        address: 0x40080a1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[135]",
+    },
+    crate::trace::Register {
+        address: 0x40080a20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[136]",
+    },
+    crate::trace::Register {
+        address: 0x40080a24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[137]",
+    },
+    crate::trace::Register {
+        address: 0x40080a28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[138]",
+    },
+    crate::trace::Register {
+        address: 0x40080a2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[139]",
+    },
+    crate::trace::Register {
+        address: 0x40080a30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[140]",
+    },
+    crate::trace::Register {
+        address: 0x40080a34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[141]",
+    },
+    crate::trace::Register {
+        address: 0x40080a38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[142]",
+    },
+    crate::trace::Register {
+        address: 0x40080a3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[143]",
+    },
+    crate::trace::Register {
+        address: 0x40080a40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[144]",
+    },
+    crate::trace::Register {
+        address: 0x40080a44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[145]",
+    },
+    crate::trace::Register {
+        address: 0x40080a48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[146]",
+    },
+    crate::trace::Register {
+        address: 0x40080a4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[147]",
+    },
+    crate::trace::Register {
+        address: 0x40080a50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[148]",
+    },
+    crate::trace::Register {
+        address: 0x40080a54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[149]",
+    },
+    crate::trace::Register {
+        address: 0x40080a58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[150]",
+    },
+    crate::trace::Register {
+        address: 0x40080a5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[151]",
+    },
+    crate::trace::Register {
+        address: 0x40080a60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[152]",
+    },
+    crate::trace::Register {
+        address: 0x40080a64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[153]",
+    },
+    crate::trace::Register {
+        address: 0x40080a68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[154]",
+    },
+    crate::trace::Register {
+        address: 0x40080a6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[155]",
+    },
+    crate::trace::Register {
+        address: 0x40080a70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[156]",
+    },
+    crate::trace::Register {
+        address: 0x40080a74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[157]",
+    },
+    crate::trace::Register {
+        address: 0x40080a78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[158]",
+    },
+    crate::trace::Register {
+        address: 0x40080a7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[159]",
+    },
+    crate::trace::Register {
+        address: 0x40080a80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[160]",
+    },
+    crate::trace::Register {
+        address: 0x40080a84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[161]",
+    },
+    crate::trace::Register {
+        address: 0x40080a88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[162]",
+    },
+    crate::trace::Register {
+        address: 0x40080a8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[163]",
+    },
+    crate::trace::Register {
+        address: 0x40080a90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[164]",
+    },
+    crate::trace::Register {
+        address: 0x40080a94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[165]",
+    },
+    crate::trace::Register {
+        address: 0x40080a98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[166]",
+    },
+    crate::trace::Register {
+        address: 0x40080a9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[167]",
+    },
+    crate::trace::Register {
+        address: 0x40080aa0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[168]",
+    },
+    crate::trace::Register {
+        address: 0x40080aa4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[169]",
+    },
+    crate::trace::Register {
+        address: 0x40080aa8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[170]",
+    },
+    crate::trace::Register {
+        address: 0x40080aac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[171]",
+    },
+    crate::trace::Register {
+        address: 0x40080ab0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[172]",
+    },
+    crate::trace::Register {
+        address: 0x40080ab4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[173]",
+    },
+    crate::trace::Register {
+        address: 0x40080ab8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[174]",
+    },
+    crate::trace::Register {
+        address: 0x40080abc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[175]",
+    },
+    crate::trace::Register {
+        address: 0x40080ac0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[176]",
+    },
+    crate::trace::Register {
+        address: 0x40080ac4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[177]",
+    },
+    crate::trace::Register {
+        address: 0x40080ac8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[178]",
+    },
+    crate::trace::Register {
+        address: 0x40080acc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[179]",
+    },
+    crate::trace::Register {
+        address: 0x40080ad0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[180]",
+    },
+    crate::trace::Register {
+        address: 0x40080ad4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[181]",
+    },
+    crate::trace::Register {
+        address: 0x40080ad8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[182]",
+    },
+    crate::trace::Register {
+        address: 0x40080adc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[183]",
+    },
+    crate::trace::Register {
+        address: 0x40080ae0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[184]",
+    },
+    crate::trace::Register {
+        address: 0x40080ae4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[185]",
+    },
+    crate::trace::Register {
+        address: 0x40080ae8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[186]",
+    },
+    crate::trace::Register {
+        address: 0x40080aec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[187]",
+    },
+    crate::trace::Register {
+        address: 0x40080af0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[188]",
+    },
+    crate::trace::Register {
+        address: 0x40080af4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[189]",
+    },
+    crate::trace::Register {
+        address: 0x40080af8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[190]",
+    },
+    crate::trace::Register {
+        address: 0x40080afc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[191]",
+    },
+    crate::trace::Register {
+        address: 0x40080b00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[192]",
+    },
+    crate::trace::Register {
+        address: 0x40080b04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[193]",
+    },
+    crate::trace::Register {
+        address: 0x40080b08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[194]",
+    },
+    crate::trace::Register {
+        address: 0x40080b0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[195]",
+    },
+    crate::trace::Register {
+        address: 0x40080b10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[196]",
+    },
+    crate::trace::Register {
+        address: 0x40080b14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[197]",
+    },
+    crate::trace::Register {
+        address: 0x40080b18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[198]",
+    },
+    crate::trace::Register {
+        address: 0x40080b1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[199]",
+    },
+    crate::trace::Register {
+        address: 0x40080b20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[200]",
+    },
+    crate::trace::Register {
+        address: 0x40080b24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[201]",
+    },
+    crate::trace::Register {
+        address: 0x40080b28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[202]",
+    },
+    crate::trace::Register {
+        address: 0x40080b2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[203]",
+    },
+    crate::trace::Register {
+        address: 0x40080b30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[204]",
+    },
+    crate::trace::Register {
+        address: 0x40080b34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[205]",
+    },
+    crate::trace::Register {
+        address: 0x40080b38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[206]",
+    },
+    crate::trace::Register {
+        address: 0x40080b3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[207]",
+    },
+    crate::trace::Register {
+        address: 0x40080b40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[208]",
+    },
+    crate::trace::Register {
+        address: 0x40080b44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[209]",
+    },
+    crate::trace::Register {
+        address: 0x40080b48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[210]",
+    },
+    crate::trace::Register {
+        address: 0x40080b4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[211]",
+    },
+    crate::trace::Register {
+        address: 0x40080b50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[212]",
+    },
+    crate::trace::Register {
+        address: 0x40080b54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[213]",
+    },
+    crate::trace::Register {
+        address: 0x40080b58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[214]",
+    },
+    crate::trace::Register {
+        address: 0x40080b5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[215]",
+    },
+    crate::trace::Register {
+        address: 0x40080b60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[216]",
+    },
+    crate::trace::Register {
+        address: 0x40080b64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[217]",
+    },
+    crate::trace::Register {
+        address: 0x40080b68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[218]",
+    },
+    crate::trace::Register {
+        address: 0x40080b6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[219]",
+    },
+    crate::trace::Register {
+        address: 0x40080b70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[220]",
+    },
+    crate::trace::Register {
+        address: 0x40080b74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[221]",
+    },
+    crate::trace::Register {
+        address: 0x40080b78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[222]",
+    },
+    crate::trace::Register {
+        address: 0x40080b7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[223]",
+    },
+    crate::trace::Register {
+        address: 0x40080b80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[224]",
+    },
+    crate::trace::Register {
+        address: 0x40080b84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[225]",
+    },
+    crate::trace::Register {
+        address: 0x40080b88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[226]",
+    },
+    crate::trace::Register {
+        address: 0x40080b8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[227]",
+    },
+    crate::trace::Register {
+        address: 0x40080b90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[228]",
+    },
+    crate::trace::Register {
+        address: 0x40080b94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[229]",
+    },
+    crate::trace::Register {
+        address: 0x40080b98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[230]",
+    },
+    crate::trace::Register {
+        address: 0x40080b9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[231]",
+    },
+    crate::trace::Register {
+        address: 0x40080ba0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[232]",
+    },
+    crate::trace::Register {
+        address: 0x40080ba4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[233]",
+    },
+    crate::trace::Register {
+        address: 0x40080ba8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[234]",
+    },
+    crate::trace::Register {
+        address: 0x40080bac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[235]",
+    },
+    crate::trace::Register {
+        address: 0x40080bb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[236]",
+    },
+    crate::trace::Register {
+        address: 0x40080bb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[237]",
+    },
+    crate::trace::Register {
+        address: 0x40080bb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[238]",
+    },
+    crate::trace::Register {
+        address: 0x40080bbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[239]",
+    },
+    crate::trace::Register {
+        address: 0x40080bc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[240]",
+    },
+    crate::trace::Register {
+        address: 0x40080bc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[241]",
+    },
+    crate::trace::Register {
+        address: 0x40080bc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[242]",
+    },
+    crate::trace::Register {
+        address: 0x40080bcc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[243]",
+    },
+    crate::trace::Register {
+        address: 0x40080bd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[244]",
+    },
+    crate::trace::Register {
+        address: 0x40080bd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[245]",
+    },
+    crate::trace::Register {
+        address: 0x40080bd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[246]",
+    },
+    crate::trace::Register {
+        address: 0x40080bdc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[247]",
+    },
+    crate::trace::Register {
+        address: 0x40080be0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[248]",
+    },
+    crate::trace::Register {
+        address: 0x40080be4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[249]",
+    },
+    crate::trace::Register {
+        address: 0x40080be8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[250]",
+    },
+    crate::trace::Register {
+        address: 0x40080bec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[251]",
+    },
+    crate::trace::Register {
+        address: 0x40080bf0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[252]",
+    },
+    crate::trace::Register {
+        address: 0x40080bf4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[253]",
+    },
+    crate::trace::Register {
+        address: 0x40080bf8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[254]",
+    },
+    crate::trace::Register {
+        address: 0x40080bfc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[255]",
+    },
+    crate::trace::Register {
+        address: 0x40080c00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[256]",
+    },
+    crate::trace::Register {
+        address: 0x40080c04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[257]",
+    },
+    crate::trace::Register {
+        address: 0x40080c08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[258]",
+    },
+    crate::trace::Register {
+        address: 0x40080c0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[259]",
+    },
+    crate::trace::Register {
+        address: 0x40080c10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[260]",
+    },
+    crate::trace::Register {
+        address: 0x40080c14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[261]",
+    },
+    crate::trace::Register {
+        address: 0x40080c18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[262]",
+    },
+    crate::trace::Register {
+        address: 0x40080c1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[263]",
+    },
+    crate::trace::Register {
+        address: 0x40080c20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[264]",
+    },
+    crate::trace::Register {
+        address: 0x40080c24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[265]",
+    },
+    crate::trace::Register {
+        address: 0x40080c28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[266]",
+    },
+    crate::trace::Register {
+        address: 0x40080c2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[267]",
+    },
+    crate::trace::Register {
+        address: 0x40080c30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[268]",
+    },
+    crate::trace::Register {
+        address: 0x40080c34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[269]",
+    },
+    crate::trace::Register {
+        address: 0x40080c38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[270]",
+    },
+    crate::trace::Register {
+        address: 0x40080c3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[271]",
+    },
+    crate::trace::Register {
+        address: 0x40080c40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[272]",
+    },
+    crate::trace::Register {
+        address: 0x40080c44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[273]",
+    },
+    crate::trace::Register {
+        address: 0x40080c48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[274]",
+    },
+    crate::trace::Register {
+        address: 0x40080c4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[275]",
+    },
+    crate::trace::Register {
+        address: 0x40080c50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[276]",
+    },
+    crate::trace::Register {
+        address: 0x40080c54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[277]",
+    },
+    crate::trace::Register {
+        address: 0x40080c58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[278]",
+    },
+    crate::trace::Register {
+        address: 0x40080c5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[279]",
+    },
+    crate::trace::Register {
+        address: 0x40080c60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[280]",
+    },
+    crate::trace::Register {
+        address: 0x40080c64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[281]",
+    },
+    crate::trace::Register {
+        address: 0x40080c68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[282]",
+    },
+    crate::trace::Register {
+        address: 0x40080c6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[283]",
+    },
+    crate::trace::Register {
+        address: 0x40080c70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[284]",
+    },
+    crate::trace::Register {
+        address: 0x40080c74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[285]",
+    },
+    crate::trace::Register {
+        address: 0x40080c78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[286]",
+    },
+    crate::trace::Register {
+        address: 0x40080c7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[287]",
+    },
+    crate::trace::Register {
+        address: 0x40080c80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[288]",
+    },
+    crate::trace::Register {
+        address: 0x40080c84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[289]",
+    },
+    crate::trace::Register {
+        address: 0x40080c88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[290]",
+    },
+    crate::trace::Register {
+        address: 0x40080c8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[291]",
+    },
+    crate::trace::Register {
+        address: 0x40080c90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[292]",
+    },
+    crate::trace::Register {
+        address: 0x40080c94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[293]",
+    },
+    crate::trace::Register {
+        address: 0x40080c98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[294]",
+    },
+    crate::trace::Register {
+        address: 0x40080c9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[295]",
+    },
+    crate::trace::Register {
+        address: 0x40080ca0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[296]",
+    },
+    crate::trace::Register {
+        address: 0x40080ca4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[297]",
+    },
+    crate::trace::Register {
+        address: 0x40080ca8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[298]",
+    },
+    crate::trace::Register {
+        address: 0x40080cac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[299]",
+    },
+    crate::trace::Register {
+        address: 0x40080cb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[300]",
+    },
+    crate::trace::Register {
+        address: 0x40080cb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[301]",
+    },
+    crate::trace::Register {
+        address: 0x40080cb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[302]",
+    },
+    crate::trace::Register {
+        address: 0x40080cbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[303]",
+    },
+    crate::trace::Register {
+        address: 0x40080cc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[304]",
+    },
+    crate::trace::Register {
+        address: 0x40080cc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[305]",
+    },
+    crate::trace::Register {
+        address: 0x40080cc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[306]",
+    },
+    crate::trace::Register {
+        address: 0x40080ccc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[307]",
+    },
+    crate::trace::Register {
+        address: 0x40080cd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[308]",
+    },
+    crate::trace::Register {
+        address: 0x40080cd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[309]",
+    },
+    crate::trace::Register {
+        address: 0x40080cd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[310]",
+    },
+    crate::trace::Register {
+        address: 0x40080cdc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[311]",
+    },
+    crate::trace::Register {
+        address: 0x40080ce0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[312]",
+    },
+    crate::trace::Register {
+        address: 0x40080ce4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[313]",
+    },
+    crate::trace::Register {
+        address: 0x40080ce8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[314]",
+    },
+    crate::trace::Register {
+        address: 0x40080cec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[315]",
+    },
+    crate::trace::Register {
+        address: 0x40080cf0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[316]",
+    },
+    crate::trace::Register {
+        address: 0x40080cf4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[317]",
+    },
+    crate::trace::Register {
+        address: 0x40080cf8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[318]",
+    },
+    crate::trace::Register {
+        address: 0x40080cfc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[319]",
+    },
+    crate::trace::Register {
+        address: 0x40080d00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[320]",
+    },
+    crate::trace::Register {
+        address: 0x40080d03,
+        size: 1,
+        peripheral: "PFS",
+        register: "PWPR",
+    },
+    crate::trace::Register {
+        address: 0x40080d04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[321]",
+    },
+    crate::trace::Register {
+        address: 0x40080d08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[322]",
+    },
+    crate::trace::Register {
+        address: 0x40080d0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[323]",
+    },
+    crate::trace::Register {
+        address: 0x40080d10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[324]",
+    },
+    crate::trace::Register {
+        address: 0x40080d14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[325]",
+    },
+    crate::trace::Register {
+        address: 0x40080d18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[326]",
+    },
+    crate::trace::Register {
+        address: 0x40080d1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[327]",
+    },
+    crate::trace::Register {
+        address: 0x40080d20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[328]",
+    },
+    crate::trace::Register {
+        address: 0x40080d24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[329]",
+    },
+    crate::trace::Register {
+        address: 0x40080d28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[330]",
+    },
+    crate::trace::Register {
+        address: 0x40080d2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[331]",
+    },
+    crate::trace::Register {
+        address: 0x40080d30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[332]",
+    },
+    crate::trace::Register {
+        address: 0x40080d34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[333]",
+    },
+    crate::trace::Register {
+        address: 0x40080d38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[334]",
+    },
+    crate::trace::Register {
+        address: 0x40080d3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[335]",
+    },
+    crate::trace::Register {
+        address: 0x40080d40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[336]",
+    },
+    crate::trace::Register {
+        address: 0x40080d44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[337]",
+    },
+    crate::trace::Register {
+        address: 0x40080d48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[338]",
+    },
+    crate::trace::Register {
+        address: 0x40080d4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[339]",
+    },
+    crate::trace::Register {
+        address: 0x40080d50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[340]",
+    },
+    crate::trace::Register {
+        address: 0x40080d54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[341]",
+    },
+    crate::trace::Register {
+        address: 0x40080d58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[342]",
+    },
+    crate::trace::Register {
+        address: 0x40080d5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[343]",
+    },
+    crate::trace::Register {
+        address: 0x40080d60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[344]",
+    },
+    crate::trace::Register {
+        address: 0x40080d64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[345]",
+    },
+    crate::trace::Register {
+        address: 0x40080d68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[346]",
+    },
+    crate::trace::Register {
+        address: 0x40080d6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[347]",
+    },
+    crate::trace::Register {
+        address: 0x40080d70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[348]",
+    },
+    crate::trace::Register {
+        address: 0x40080d74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[349]",
+    },
+    crate::trace::Register {
+        address: 0x40080d78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[350]",
+    },
+    crate::trace::Register {
+        address: 0x40080d7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[351]",
+    },
+    crate::trace::Register {
+        address: 0x40080d80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[352]",
+    },
+    crate::trace::Register {
+        address: 0x40080d84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[353]",
+    },
+    crate::trace::Register {
+        address: 0x40080d88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[354]",
+    },
+    crate::trace::Register {
+        address: 0x40080d8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[355]",
+    },
+    crate::trace::Register {
+        address: 0x40080d90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[356]",
+    },
+    crate::trace::Register {
+        address: 0x40080d94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[357]",
+    },
+    crate::trace::Register {
+        address: 0x40080d98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[358]",
+    },
+    crate::trace::Register {
+        address: 0x40080d9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[359]",
+    },
+    crate::trace::Register {
+        address: 0x40080da0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[360]",
+    },
+    crate::trace::Register {
+        address: 0x40080da4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[361]",
+    },
+    crate::trace::Register {
+        address: 0x40080da8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[362]",
+    },
+    crate::trace::Register {
+        address: 0x40080dac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[363]",
+    },
+    crate::trace::Register {
+        address: 0x40080db0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[364]",
+    },
+    crate::trace::Register {
+        address: 0x40080db4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[365]",
+    },
+    crate::trace::Register {
+        address: 0x40080db8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[366]",
+    },
+    crate::trace::Register {
+        address: 0x40080dbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[367]",
+    },
+    crate::trace::Register {
+        address: 0x40080dc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[368]",
+    },
+    crate::trace::Register {
+        address: 0x40080dc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[369]",
+    },
+    crate::trace::Register {
+        address: 0x40080dc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[370]",
+    },
+    crate::trace::Register {
+        address: 0x40080dcc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[371]",
+    },
+    crate::trace::Register {
+        address: 0x40080dd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[372]",
+    },
+    crate::trace::Register {
+        address: 0x40080dd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[373]",
+    },
+    crate::trace::Register {
+        address: 0x40080dd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[374]",
+    },
+    crate::trace::Register {
+        address: 0x40080ddc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[375]",
+    },
+    crate::trace::Register {
+        address: 0x40080de0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[376]",
+    },
+    crate::trace::Register {
+        address: 0x40080de4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[377]",
+    },
+    crate::trace::Register {
+        address: 0x40080de8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[378]",
+    },
+    crate::trace::Register {
+        address: 0x40080dec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[379]",
+    },
+    crate::trace::Register {
+        address: 0x40080df0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[380]",
+    },
+    crate::trace::Register {
+        address: 0x40080df4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[381]",
+    },
+    crate::trace::Register {
+        address: 0x40080df8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[382]",
+    },
+    crate::trace::Register {
+        address: 0x40080dfc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[383]",
+    },
+    crate::trace::Register {
+        address: 0x40080e00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[384]",
+    },
+    crate::trace::Register {
+        address: 0x40080e04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[385]",
+    },
+    crate::trace::Register {
+        address: 0x40080e08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[386]",
+    },
+    crate::trace::Register {
+        address: 0x40080e0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[387]",
+    },
+    crate::trace::Register {
+        address: 0x40080e10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[388]",
+    },
+    crate::trace::Register {
+        address: 0x40080e14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[389]",
+    },
+    crate::trace::Register {
+        address: 0x40080e18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[390]",
+    },
+    crate::trace::Register {
+        address: 0x40080e1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[391]",
+    },
+    crate::trace::Register {
+        address: 0x40080e20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[392]",
+    },
+    crate::trace::Register {
+        address: 0x40080e24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[393]",
+    },
+    crate::trace::Register {
+        address: 0x40080e28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[394]",
+    },
+    crate::trace::Register {
+        address: 0x40080e2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[395]",
+    },
+    crate::trace::Register {
+        address: 0x40080e30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[396]",
+    },
+    crate::trace::Register {
+        address: 0x40080e34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[397]",
+    },
+    crate::trace::Register {
+        address: 0x40080e38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[398]",
+    },
+    crate::trace::Register {
+        address: 0x40080e3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[399]",
+    },
+    crate::trace::Register {
+        address: 0x40080e40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[400]",
+    },
+    crate::trace::Register {
+        address: 0x40080e44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[401]",
+    },
+    crate::trace::Register {
+        address: 0x40080e48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[402]",
+    },
+    crate::trace::Register {
+        address: 0x40080e4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[403]",
+    },
+    crate::trace::Register {
+        address: 0x40080e50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[404]",
+    },
+    crate::trace::Register {
+        address: 0x40080e54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[405]",
+    },
+    crate::trace::Register {
+        address: 0x40080e58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[406]",
+    },
+    crate::trace::Register {
+        address: 0x40080e5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[407]",
+    },
+    crate::trace::Register {
+        address: 0x40080e60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[408]",
+    },
+    crate::trace::Register {
+        address: 0x40080e64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[409]",
+    },
+    crate::trace::Register {
+        address: 0x40080e68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[410]",
+    },
+    crate::trace::Register {
+        address: 0x40080e6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[411]",
+    },
+    crate::trace::Register {
+        address: 0x40080e70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[412]",
+    },
+    crate::trace::Register {
+        address: 0x40080e74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[413]",
+    },
+    crate::trace::Register {
+        address: 0x40080e78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[414]",
+    },
+    crate::trace::Register {
+        address: 0x40080e7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[415]",
+    },
+    crate::trace::Register {
+        address: 0x40080e80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[416]",
+    },
+    crate::trace::Register {
+        address: 0x40080e84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[417]",
+    },
+    crate::trace::Register {
+        address: 0x40080e88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[418]",
+    },
+    crate::trace::Register {
+        address: 0x40080e8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[419]",
+    },
+    crate::trace::Register {
+        address: 0x40080e90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[420]",
+    },
+    crate::trace::Register {
+        address: 0x40080e94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[421]",
+    },
+    crate::trace::Register {
+        address: 0x40080e98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[422]",
+    },
+    crate::trace::Register {
+        address: 0x40080e9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[423]",
+    },
+    crate::trace::Register {
+        address: 0x40080ea0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[424]",
+    },
+    crate::trace::Register {
+        address: 0x40080ea4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[425]",
+    },
+    crate::trace::Register {
+        address: 0x40080ea8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[426]",
+    },
+    crate::trace::Register {
+        address: 0x40080eac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[427]",
+    },
+    crate::trace::Register {
+        address: 0x40080eb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[428]",
+    },
+    crate::trace::Register {
+        address: 0x40080eb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[429]",
+    },
+    crate::trace::Register {
+        address: 0x40080eb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[430]",
+    },
+    crate::trace::Register {
+        address: 0x40080ebc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[431]",
+    },
+    crate::trace::Register {
+        address: 0x40080ec0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[432]",
+    },
+    crate::trace::Register {
+        address: 0x40080ec4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[433]",
+    },
+    crate::trace::Register {
+        address: 0x40080ec8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[434]",
+    },
+    crate::trace::Register {
+        address: 0x40080ecc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[435]",
+    },
+    crate::trace::Register {
+        address: 0x40080ed0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[436]",
+    },
+    crate::trace::Register {
+        address: 0x40080ed4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[437]",
+    },
+    crate::trace::Register {
+        address: 0x40080ed8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[438]",
+    },
+    crate::trace::Register {
+        address: 0x40080edc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[439]",
+    },
+    crate::trace::Register {
+        address: 0x40080ee0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[440]",
+    },
+    crate::trace::Register {
+        address: 0x40080ee4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[441]",
+    },
+    crate::trace::Register {
+        address: 0x40080ee8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[442]",
+    },
+    crate::trace::Register {
+        address: 0x40080eec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[443]",
+    },
+    crate::trace::Register {
+        address: 0x40080ef0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[444]",
+    },
+    crate::trace::Register {
+        address: 0x40080ef4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[445]",
+    },
+    crate::trace::Register {
+        address: 0x40080ef8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[446]",
+    },
+    crate::trace::Register {
+        address: 0x40080efc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[447]",
+    },
+    crate::trace::Register {
+        address: 0x40080f00,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[448]",
+    },
+    crate::trace::Register {
+        address: 0x40080f04,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[449]",
+    },
+    crate::trace::Register {
+        address: 0x40080f08,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[450]",
+    },
+    crate::trace::Register {
+        address: 0x40080f0c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[451]",
+    },
+    crate::trace::Register {
+        address: 0x40080f10,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[452]",
+    },
+    crate::trace::Register {
+        address: 0x40080f14,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[453]",
+    },
+    crate::trace::Register {
+        address: 0x40080f18,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[454]",
+    },
+    crate::trace::Register {
+        address: 0x40080f1c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[455]",
+    },
+    crate::trace::Register {
+        address: 0x40080f20,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[456]",
+    },
+    crate::trace::Register {
+        address: 0x40080f24,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[457]",
+    },
+    crate::trace::Register {
+        address: 0x40080f28,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[458]",
+    },
+    crate::trace::Register {
+        address: 0x40080f2c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[459]",
+    },
+    crate::trace::Register {
+        address: 0x40080f30,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[460]",
+    },
+    crate::trace::Register {
+        address: 0x40080f34,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[461]",
+    },
+    crate::trace::Register {
+        address: 0x40080f38,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[462]",
+    },
+    crate::trace::Register {
+        address: 0x40080f3c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[463]",
+    },
+    crate::trace::Register {
+        address: 0x40080f40,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[464]",
+    },
+    crate::trace::Register {
+        address: 0x40080f44,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[465]",
+    },
+    crate::trace::Register {
+        address: 0x40080f48,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[466]",
+    },
+    crate::trace::Register {
+        address: 0x40080f4c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[467]",
+    },
+    crate::trace::Register {
+        address: 0x40080f50,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[468]",
+    },
+    crate::trace::Register {
+        address: 0x40080f54,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[469]",
+    },
+    crate::trace::Register {
+        address: 0x40080f58,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[470]",
+    },
+    crate::trace::Register {
+        address: 0x40080f5c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[471]",
+    },
+    crate::trace::Register {
+        address: 0x40080f60,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[472]",
+    },
+    crate::trace::Register {
+        address: 0x40080f64,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[473]",
+    },
+    crate::trace::Register {
+        address: 0x40080f68,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[474]",
+    },
+    crate::trace::Register {
+        address: 0x40080f6c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[475]",
+    },
+    crate::trace::Register {
+        address: 0x40080f70,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[476]",
+    },
+    crate::trace::Register {
+        address: 0x40080f74,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[477]",
+    },
+    crate::trace::Register {
+        address: 0x40080f78,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[478]",
+    },
+    crate::trace::Register {
+        address: 0x40080f7c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[479]",
+    },
+    crate::trace::Register {
+        address: 0x40080f80,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[480]",
+    },
+    crate::trace::Register {
+        address: 0x40080f84,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[481]",
+    },
+    crate::trace::Register {
+        address: 0x40080f88,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[482]",
+    },
+    crate::trace::Register {
+        address: 0x40080f8c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[483]",
+    },
+    crate::trace::Register {
+        address: 0x40080f90,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[484]",
+    },
+    crate::trace::Register {
+        address: 0x40080f94,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[485]",
+    },
+    crate::trace::Register {
+        address: 0x40080f98,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[486]",
+    },
+    crate::trace::Register {
+        address: 0x40080f9c,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[487]",
+    },
+    crate::trace::Register {
+        address: 0x40080fa0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[488]",
+    },
+    crate::trace::Register {
+        address: 0x40080fa4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[489]",
+    },
+    crate::trace::Register {
+        address: 0x40080fa8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[490]",
+    },
+    crate::trace::Register {
+        address: 0x40080fac,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[491]",
+    },
+    crate::trace::Register {
+        address: 0x40080fb0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[492]",
+    },
+    crate::trace::Register {
+        address: 0x40080fb4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[493]",
+    },
+    crate::trace::Register {
+        address: 0x40080fb8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[494]",
+    },
+    crate::trace::Register {
+        address: 0x40080fbc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[495]",
+    },
+    crate::trace::Register {
+        address: 0x40080fc0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[496]",
+    },
+    crate::trace::Register {
+        address: 0x40080fc4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[497]",
+    },
+    crate::trace::Register {
+        address: 0x40080fc8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[498]",
+    },
+    crate::trace::Register {
+        address: 0x40080fcc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[499]",
+    },
+    crate::trace::Register {
+        address: 0x40080fd0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[500]",
+    },
+    crate::trace::Register {
+        address: 0x40080fd4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[501]",
+    },
+    crate::trace::Register {
+        address: 0x40080fd8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[502]",
+    },
+    crate::trace::Register {
+        address: 0x40080fdc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[503]",
+    },
+    crate::trace::Register {
+        address: 0x40080fe0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[504]",
+    },
+    crate::trace::Register {
+        address: 0x40080fe4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[505]",
+    },
+    crate::trace::Register {
+        address: 0x40080fe8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[506]",
+    },
+    crate::trace::Register {
+        address: 0x40080fec,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[507]",
+    },
+    crate::trace::Register {
+        address: 0x40080ff0,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[508]",
+    },
+    crate::trace::Register {
+        address: 0x40080ff4,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[509]",
+    },
+    crate::trace::Register {
+        address: 0x40080ff8,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[510]",
+    },
+    crate::trace::Register {
+        address: 0x40080ffc,
+        size: 4,
+        peripheral: "PFS",
+        register: "PmnPFS[511]",
+    },
+    crate::trace::Register {
+        address: 0x40084000,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRA",
+    },
+    crate::trace::Register {
+        address: 0x40084004,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRB",
+    },
+    crate::trace::Register {
+        address: 0x40084008,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRC",
+    },
+    crate::trace::Register {
+        address: 0x4008400c,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRD",
+    },
+    crate::trace::Register {
+        address: 0x40084010,
+        size: 4,
+        peripheral: "MSTP",
+        register: "MSTPCRE",
+    },
+    crate::trace::Register {
+        address: 0x40118000,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SMR",
+    },
+    crate::trace::Register {
+        address: 0x40118001,
+        size: 1,
+        peripheral: "SCI0",
+        register: "BRR",
+    },
+    crate::trace::Register {
+        address: 0x40118002,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SCR",
+    },
+    crate::trace::Register {
+        address: 0x40118003,
+        size: 1,
+        peripheral: "SCI0",
+        register: "TDR",
+    },
+    crate::trace::Register {
+        address: 0x40118004,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SSR",
+    },
+    crate::trace::Register {
+        address: 0x40118005,
+        size: 1,
+        peripheral: "SCI0",
+        register: "RDR",
+    },
+    crate::trace::Register {
+        address: 0x40118006,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SCMR",
+    },
+    crate::trace::Register {
+        address: 0x40118007,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SEMR",
+    },
+    crate::trace::Register {
+        address: 0x40118008,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SNFR",
+    },
+    crate::trace::Register {
+        address: 0x40118009,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SIMR1",
+    },
+    crate::trace::Register {
+        address: 0x4011800a,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SIMR2",
+    },
+    crate::trace::Register {
+        address: 0x4011800b,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SIMR3",
+    },
+    crate::trace::Register {
+        address: 0x4011800c,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SISR",
+    },
+    crate::trace::Register {
+        address: 0x4011800d,
+        size: 1,
+        peripheral: "SCI0",
+        register: "SPMR",
+    },
+    crate::trace::Register {
+        address: 0x4011800e,
+        size: 2,
+        peripheral: "SCI0",
+        register: "TDRHL",
+    },
+    crate::trace::Register {
+        address: 0x40118010,
+        size: 2,
+        peripheral: "SCI0",
+        register: "RDRHL",
+    },
+    crate::trace::Register {
+        address: 0x40118012,
+        size: 1,
+        peripheral: "SCI0",
+        register: "MDDR",
+    },
+    crate::trace::Register {
+        address: 0x40169000,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTWP",
+    },
+    crate::trace::Register {
+        address: 0x40169004,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTSTR",
+    },
+    crate::trace::Register {
+        address: 0x40169008,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTSTP",
+    },
+    crate::trace::Register {
+        address: 0x4016900c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCLR",
+    },
+    crate::trace::Register {
+        address: 0x40169010,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTSSR",
+    },
+    crate::trace::Register {
+        address: 0x40169014,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTPSR",
+    },
+    crate::trace::Register {
+        address: 0x40169018,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCSR",
+    },
+    crate::trace::Register {
+        address: 0x4016901c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTUPSR",
+    },
+    crate::trace::Register {
+        address: 0x40169020,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTDNSR",
+    },
+    crate::trace::Register {
+        address: 0x40169024,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTICASR",
+    },
+    crate::trace::Register {
+        address: 0x40169028,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTICBSR",
+    },
+    crate::trace::Register {
+        address: 0x4016902c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCR",
+    },
+    crate::trace::Register {
+        address: 0x40169030,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTUDDTYC",
+    },
+    crate::trace::Register {
+        address: 0x40169034,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTIOR",
+    },
+    crate::trace::Register {
+        address: 0x40169038,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTINTAD",
+    },
+    crate::trace::Register {
+        address: 0x4016903c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTST",
+    },
+    crate::trace::Register {
+        address: 0x40169040,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTBER",
+    },
+    crate::trace::Register {
+        address: 0x40169048,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCNT",
+    },
+    crate::trace::Register {
+        address: 0x4016904c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCCRA",
+    },
+    crate::trace::Register {
+        address: 0x40169050,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCCRB",
+    },
+    crate::trace::Register {
+        address: 0x40169054,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCCRC",
+    },
+    crate::trace::Register {
+        address: 0x40169058,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCCRE",
+    },
+    crate::trace::Register {
+        address: 0x4016905c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCCRD",
+    },
+    crate::trace::Register {
+        address: 0x40169060,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTCCRF",
+    },
+    crate::trace::Register {
+        address: 0x40169064,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTPR",
+    },
+    crate::trace::Register {
+        address: 0x40169068,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTPBR",
+    },
+    crate::trace::Register {
+        address: 0x40169088,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTDTCR",
+    },
+    crate::trace::Register {
+        address: 0x4016908c,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTDVU",
+    },
+    crate::trace::Register {
+        address: 0x401690b8,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTICLF",
+    },
+    crate::trace::Register {
+        address: 0x401690bc,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTPC",
+    },
+    crate::trace::Register {
+        address: 0x401690d0,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTSECSR",
+    },
+    crate::trace::Register {
+        address: 0x401690d4,
+        size: 4,
+        peripheral: "GPT0",
+        register: "GTSECR",
+    },
+    crate::trace::Register {
+        address: 0x40169100,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTWP",
+    },
+    crate::trace::Register {
+        address: 0x40169104,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTSTR",
+    },
+    crate::trace::Register {
+        address: 0x40169108,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTSTP",
+    },
+    crate::trace::Register {
+        address: 0x4016910c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCLR",
+    },
+    crate::trace::Register {
+        address: 0x40169110,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTSSR",
+    },
+    crate::trace::Register {
+        address: 0x40169114,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTPSR",
+    },
+    crate::trace::Register {
+        address: 0x40169118,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCSR",
+    },
+    crate::trace::Register {
+        address: 0x4016911c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTUPSR",
+    },
+    crate::trace::Register {
+        address: 0x40169120,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTDNSR",
+    },
+    crate::trace::Register {
+        address: 0x40169124,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTICASR",
+    },
+    crate::trace::Register {
+        address: 0x40169128,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTICBSR",
+    },
+    crate::trace::Register {
+        address: 0x4016912c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCR",
+    },
+    crate::trace::Register {
+        address: 0x40169130,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTUDDTYC",
+    },
+    crate::trace::Register {
+        address: 0x40169134,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTIOR",
+    },
+    crate::trace::Register {
+        address: 0x40169138,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTINTAD",
+    },
+    crate::trace::Register {
+        address: 0x4016913c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTST",
+    },
+    crate::trace::Register {
+        address: 0x40169140,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTBER",
+    },
+    crate::trace::Register {
+        address: 0x40169148,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCNT",
+    },
+    crate::trace::Register {
+        address: 0x4016914c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCCRA",
+    },
+    crate::trace::Register {
+        address: 0x40169150,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCCRB",
+    },
+    crate::trace::Register {
+        address: 0x40169154,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCCRC",
+    },
+    crate::trace::Register {
+        address: 0x40169158,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCCRE",
+    },
+    crate::trace::Register {
+        address: 0x4016915c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCCRD",
+    },
+    crate::trace::Register {
+        address: 0x40169160,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTCCRF",
+    },
+    crate::trace::Register {
+        address: 0x40169164,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTPR",
+    },
+    crate::trace::Register {
+        address: 0x40169168,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTPBR",
+    },
+    crate::trace::Register {
+        address: 0x40169188,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTDTCR",
+    },
+    crate::trace::Register {
+        address: 0x4016918c,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTDVU",
+    },
+    crate::trace::Register {
+        address: 0x401691b8,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTICLF",
+    },
+    crate::trace::Register {
+        address: 0x401691bc,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTPC",
+    },
+    crate::trace::Register {
+        address: 0x401691d0,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTSECSR",
+    },
+    crate::trace::Register {
+        address: 0x401691d4,
+        size: 4,
+        peripheral: "GPT1",
+        register: "GTSECR",
+    },
+    crate::trace::Register {
+        address: 0x40169400,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTWP",
+    },
+    crate::trace::Register {
+        address: 0x40169404,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTSTR",
+    },
+    crate::trace::Register {
+        address: 0x40169408,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTSTP",
+    },
+    crate::trace::Register {
+        address: 0x4016940c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCLR",
+    },
+    crate::trace::Register {
+        address: 0x40169410,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTSSR",
+    },
+    crate::trace::Register {
+        address: 0x40169414,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTPSR",
+    },
+    crate::trace::Register {
+        address: 0x40169418,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCSR",
+    },
+    crate::trace::Register {
+        address: 0x4016941c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTUPSR",
+    },
+    crate::trace::Register {
+        address: 0x40169420,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTDNSR",
+    },
+    crate::trace::Register {
+        address: 0x40169424,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTICASR",
+    },
+    crate::trace::Register {
+        address: 0x40169428,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTICBSR",
+    },
+    crate::trace::Register {
+        address: 0x4016942c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCR",
+    },
+    crate::trace::Register {
+        address: 0x40169430,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTUDDTYC",
+    },
+    crate::trace::Register {
+        address: 0x40169434,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTIOR",
+    },
+    crate::trace::Register {
+        address: 0x40169438,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTINTAD",
+    },
+    crate::trace::Register {
+        address: 0x4016943c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTST",
+    },
+    crate::trace::Register {
+        address: 0x40169440,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTBER",
+    },
+    crate::trace::Register {
+        address: 0x40169448,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCNT",
+    },
+    crate::trace::Register {
+        address: 0x4016944c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCCRA",
+    },
+    crate::trace::Register {
+        address: 0x40169450,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCCRB",
+    },
+    crate::trace::Register {
+        address: 0x40169454,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCCRC",
+    },
+    crate::trace::Register {
+        address: 0x40169458,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCCRE",
+    },
+    crate::trace::Register {
+        address: 0x4016945c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCCRD",
+    },
+    crate::trace::Register {
+        address: 0x40169460,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTCCRF",
+    },
+    crate::trace::Register {
+        address: 0x40169464,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTPR",
+    },
+    crate::trace::Register {
+        address: 0x40169468,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTPBR",
+    },
+    crate::trace::Register {
+        address: 0x40169488,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTDTCR",
+    },
+    crate::trace::Register {
+        address: 0x4016948c,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTDVU",
+    },
+    crate::trace::Register {
+        address: 0x401694b8,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTICLF",
+    },
+    crate::trace::Register {
+        address: 0x401694bc,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTPC",
+    },
+    crate::trace::Register {
+        address: 0x401694d0,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTSECSR",
+    },
+    crate::trace::Register {
+        address: 0x401694d4,
+        size: 4,
+        peripheral: "GPT4",
+        register: "GTSECR",
+    },
+];
+#[cfg(feature = "mock")]
+pub const MOCK_RESETS: &[crate::mock::Reset] = &[
+    crate::mock::Reset {
+        address: 0x4008400c,
+        size: 4,
+        value: 0xffffffff,
+    },
+    crate::mock::Reset {
+        address: 0x40169048,
+        size: 4,
+        value: 0x00000000,
+    },
+    crate::mock::Reset {
+        address: 0x40169148,
+        size: 4,
+        value: 0x00000000,
+    },
+    crate::mock::Reset {
+        address: 0x40169448,
+        size: 4,
+        value: 0x00000000,
+    },
+];